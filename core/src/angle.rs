@@ -22,6 +22,12 @@ impl From<Degrees> for Radians {
     }
 }
 
+impl From<Radians> for Degrees {
+    fn from(radians: Radians) -> Self {
+        Self(radians.0 * 180.0 / PI)
+    }
+}
+
 impl From<f32> for Radians {
     fn from(radians: f32) -> Self {
         Self(radians)
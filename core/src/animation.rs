@@ -0,0 +1,455 @@
+//! Animate values over time.
+use crate::border::{self, Border};
+use crate::time::{Duration, Instant};
+use crate::{Background, Color, Point, Shadow, Size, Vector};
+
+/// A value that transitions towards a target over time.
+///
+/// An [`Animation`] does not keep track of time by itself. Instead, every
+/// query takes the current [`Instant`], which is normally the one carried by
+/// the last [`window::Event::RedrawRequested`].
+///
+/// While [`Animation::is_animating`], widgets should keep requesting redraws
+/// with [`Shell::request_redraw`].
+///
+/// [`window::Event::RedrawRequested`]: crate::window::Event::RedrawRequested
+/// [`Shell::request_redraw`]: crate::Shell::request_redraw
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    started_at: Option<Instant>,
+    motion: Motion,
+}
+
+impl<T> Animation<T>
+where
+    T: Interpolable + Clone,
+{
+    /// Creates a new [`Animation`] resting at the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value.clone(),
+            to: value,
+            started_at: None,
+            motion: Motion::default(),
+        }
+    }
+
+    /// Sets the [`Duration`] of the [`Animation`], easing it with its
+    /// current [`Easing`] curve.
+    pub fn duration(self, duration: Duration) -> Self {
+        let easing = match self.motion {
+            Motion::Tween { easing, .. } => easing,
+            Motion::Spring(_) => Easing::default(),
+        };
+
+        Self {
+            motion: Motion::Tween { duration, easing },
+            ..self
+        }
+    }
+
+    /// Sets the [`Easing`] curve of the [`Animation`].
+    pub fn easing(self, easing: Easing) -> Self {
+        let duration = match self.motion {
+            Motion::Tween { duration, .. } => duration,
+            Motion::Spring(_) => Motion::DEFAULT_DURATION,
+        };
+
+        Self {
+            motion: Motion::Tween { duration, easing },
+            ..self
+        }
+    }
+
+    /// Makes the [`Animation`] follow the given [`Spring`] instead of an
+    /// [`Easing`] curve.
+    pub fn spring(self, spring: Spring) -> Self {
+        Self {
+            motion: Motion::Spring(spring),
+            ..self
+        }
+    }
+
+    /// Starts transitioning towards the given target at the given
+    /// [`Instant`].
+    ///
+    /// If the [`Animation`] is already running, the new transition starts
+    /// from its current value.
+    pub fn go(&mut self, target: T, at: Instant) {
+        self.from = self.value(at);
+        self.to = target;
+        self.started_at = Some(at);
+    }
+
+    /// Returns the value of the [`Animation`] at the given [`Instant`].
+    pub fn value(&self, at: Instant) -> T {
+        match self.progress(at) {
+            Some(progress) => self.from.interpolate(&self.to, progress),
+            None => self.to.clone(),
+        }
+    }
+
+    /// Returns the value the [`Animation`] is transitioning towards.
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Returns whether the [`Animation`] is still running at the given
+    /// [`Instant`].
+    pub fn is_animating(&self, at: Instant) -> bool {
+        self.progress(at).is_some()
+    }
+
+    fn progress(&self, at: Instant) -> Option<f32> {
+        let elapsed = at.saturating_duration_since(self.started_at?);
+
+        self.motion.progress(elapsed)
+    }
+}
+
+/// The way an [`Animation`] progresses over time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Motion {
+    Tween { duration: Duration, easing: Easing },
+    Spring(Spring),
+}
+
+impl Motion {
+    const DEFAULT_DURATION: Duration = Duration::from_millis(150);
+
+    fn progress(self, elapsed: Duration) -> Option<f32> {
+        match self {
+            Motion::Tween { duration, easing } => {
+                if elapsed >= duration {
+                    return None;
+                }
+
+                Some(
+                    easing
+                        .apply(elapsed.as_secs_f32() / duration.as_secs_f32()),
+                )
+            }
+            Motion::Spring(spring) => spring.progress(elapsed.as_secs_f32()),
+        }
+    }
+}
+
+impl Default for Motion {
+    fn default() -> Self {
+        Self::Tween {
+            duration: Self::DEFAULT_DURATION,
+            easing: Easing::default(),
+        }
+    }
+}
+
+/// A curve that maps the linear progress of an [`Animation`] to its actual
+/// progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Starts slowly and accelerates.
+    EaseIn,
+
+    /// Starts quickly and decelerates.
+    #[default]
+    EaseOut,
+
+    /// Starts and ends slowly, accelerating in the middle.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies the [`Easing`] curve to the given linear progress, between
+    /// `0.0` and `1.0`.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// A physically-based motion that follows a damped spring.
+///
+/// Unlike an [`Easing`] curve, a [`Spring`] has no fixed duration. It runs
+/// until it settles, and it may overshoot its target if it is underdamped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// The stiffness of the [`Spring`]. Stiffer springs move faster.
+    pub stiffness: f32,
+
+    /// The damping of the [`Spring`]. Less damped springs bounce more.
+    pub damping: f32,
+
+    /// The mass attached to the [`Spring`]. Heavier masses move slower.
+    pub mass: f32,
+}
+
+impl Spring {
+    /// A smooth [`Spring`] that settles without bouncing.
+    ///
+    /// It is slightly overdamped, since its damping is above the critical
+    /// `2 * sqrt(170)`.
+    pub const SMOOTH: Self = Self::new(170.0, 27.0);
+
+    /// A [`Spring`] that overshoots its target and bounces back.
+    pub const BOUNCY: Self = Self::new(180.0, 12.0);
+
+    /// The distance to the target under which a [`Spring`] is settled.
+    const EPSILON: f32 = 0.001;
+
+    /// Creates a new [`Spring`] with the given stiffness and damping, and a
+    /// mass of `1.0`.
+    pub const fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: 1.0,
+        }
+    }
+
+    /// Returns the progress of the [`Spring`] after the given seconds, or
+    /// `None` if it has already settled.
+    fn progress(self, t: f32) -> Option<f32> {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping / (2.0 * (self.stiffness * self.mass).sqrt());
+
+        // The distance to the target after `t` seconds, and an upper bound
+        // of any distance from then on
+        let (distance, amplitude) = if zeta < 1.0 {
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let decay = (-zeta * omega * t).exp();
+
+            (
+                decay
+                    * ((omega_d * t).cos()
+                        + zeta * omega / omega_d * (omega_d * t).sin()),
+                decay * (1.0 + zeta * omega / omega_d),
+            )
+        } else if zeta == 1.0 {
+            let distance = (-omega * t).exp() * (1.0 + omega * t);
+
+            (distance, distance)
+        } else {
+            let root = (zeta * zeta - 1.0).sqrt();
+            let slow = -omega * (zeta - root);
+            let fast = -omega * (zeta + root);
+
+            let distance = (fast * (slow * t).exp() - slow * (fast * t).exp())
+                / (fast - slow);
+
+            (distance, distance)
+        };
+
+        (amplitude >= Self::EPSILON).then_some(1.0 - distance)
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::SMOOTH
+    }
+}
+
+/// A value that can be interpolated.
+pub trait Interpolable {
+    /// Interpolates between `self` and `other` by the given ratio.
+    ///
+    /// A ratio of `0.0` produces `self` and a ratio of `1.0` produces
+    /// `other`. Springs may produce ratios outside of this range.
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self;
+}
+
+impl Interpolable for f32 {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        self + (other - self) * ratio
+    }
+}
+
+impl Interpolable for Color {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Color {
+            r: self.r.interpolate(&other.r, ratio).clamp(0.0, 1.0),
+            g: self.g.interpolate(&other.g, ratio).clamp(0.0, 1.0),
+            b: self.b.interpolate(&other.b, ratio).clamp(0.0, 1.0),
+            a: self.a.interpolate(&other.a, ratio).clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Interpolable for Vector {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, ratio),
+            self.y.interpolate(&other.y, ratio),
+        )
+    }
+}
+
+impl Interpolable for Point {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, ratio),
+            self.y.interpolate(&other.y, ratio),
+        )
+    }
+}
+
+impl Interpolable for Size {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, ratio),
+            self.height.interpolate(&other.height, ratio),
+        )
+    }
+}
+
+impl Interpolable for Background {
+    /// Only solid colors are interpolated. Gradients switch halfway.
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        match (self, other) {
+            (Background::Color(a), Background::Color(b)) => {
+                Background::Color(a.interpolate(b, ratio))
+            }
+            _ if ratio < 0.5 => *self,
+            _ => *other,
+        }
+    }
+}
+
+impl Interpolable for Option<Color> {
+    /// A missing [`Color`] fades in and out as if it were transparent.
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, ratio)),
+            (Some(a), None) => {
+                Some(a.interpolate(&Color { a: 0.0, ..*a }, ratio))
+            }
+            (None, Some(b)) => {
+                Some(Color { a: 0.0, ..*b }.interpolate(b, ratio))
+            }
+            (None, None) => None,
+        }
+    }
+}
+
+impl Interpolable for Option<Background> {
+    /// A missing [`Background`] fades in and out as if it were transparent.
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, ratio)),
+            (Some(Background::Color(a)), None) => {
+                Some(Background::Color(Some(*a).interpolate(&None, ratio)?))
+            }
+            (None, Some(Background::Color(b))) => {
+                Some(Background::Color(None.interpolate(&Some(*b), ratio)?))
+            }
+            _ if ratio < 0.5 => *self,
+            _ => *other,
+        }
+    }
+}
+
+impl Interpolable for Border {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        let a: [f32; 4] = self.radius.into();
+        let b: [f32; 4] = other.radius.into();
+
+        Border {
+            color: self.color.interpolate(&other.color, ratio),
+            width: self.width.interpolate(&other.width, ratio).max(0.0),
+            radius: border::Radius::from(std::array::from_fn(|i| {
+                a[i].interpolate(&b[i], ratio).max(0.0)
+            })),
+        }
+    }
+}
+
+impl Interpolable for Shadow {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Shadow {
+            color: self.color.interpolate(&other.color, ratio),
+            offset: self.offset.interpolate(&other.offset, ratio),
+            blur_radius: self
+                .blur_radius
+                .interpolate(&other.blur_radius, ratio)
+                .max(0.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tween_reaches_target_after_duration() {
+        let start = Instant::now();
+        let mut animation = Animation::new(0.0)
+            .duration(Duration::from_millis(100))
+            .easing(Easing::Linear);
+
+        animation.go(10.0, start);
+
+        assert!(animation.is_animating(start));
+        assert_eq!(animation.value(start + Duration::from_millis(50)), 5.0);
+        assert!(!animation.is_animating(start + Duration::from_millis(100)));
+        assert_eq!(animation.value(start + Duration::from_millis(100)), 10.0);
+    }
+
+    #[test]
+    fn interrupted_animation_starts_from_current_value() {
+        let start = Instant::now();
+        let middle = start + Duration::from_millis(50);
+
+        let mut animation = Animation::new(0.0)
+            .duration(Duration::from_millis(100))
+            .easing(Easing::Linear);
+
+        animation.go(10.0, start);
+        animation.go(0.0, middle);
+
+        assert_eq!(animation.value(middle), 5.0);
+        assert_eq!(*animation.target(), 0.0);
+    }
+
+    #[test]
+    fn springs_overshoot_and_settle() {
+        let start = Instant::now();
+        let mut animation = Animation::new(0.0).spring(Spring::BOUNCY);
+
+        animation.go(1.0, start);
+
+        let overshoots = (1..100).any(|frame| {
+            animation.value(start + Duration::from_millis(frame * 16)) > 1.0
+        });
+
+        assert!(overshoots);
+        assert!(!animation.is_animating(start + Duration::from_secs(5)));
+
+        let mut smooth = Animation::new(0.0).spring(Spring::SMOOTH);
+
+        assert!(!smooth.is_animating(start));
+
+        smooth.go(1.0, start);
+
+        assert!((1..100).all(|frame| {
+            smooth.value(start + Duration::from_millis(frame * 16)) <= 1.0
+        }));
+    }
+}
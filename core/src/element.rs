@@ -324,21 +324,27 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation<B>,
     ) {
-        struct MapOperation<'a, B> {
+        struct MapOperation<'a, A, B> {
             operation: &'a mut dyn widget::Operation<B>,
+            mapper: &'a dyn Fn(A) -> B,
         }
 
-        impl<'a, T, B> widget::Operation<T> for MapOperation<'a, B> {
+        impl<'a, A, B> widget::Operation<A> for MapOperation<'a, A, B> {
             fn container(
                 &mut self,
                 id: Option<&widget::Id>,
                 bounds: Rectangle,
                 operate_on_children: &mut dyn FnMut(
-                    &mut dyn widget::Operation<T>,
+                    &mut dyn widget::Operation<A>,
                 ),
             ) {
+                let mapper = self.mapper;
+
                 self.operation.container(id, bounds, &mut |operation| {
-                    operate_on_children(&mut MapOperation { operation });
+                    operate_on_children(&mut MapOperation {
+                        operation,
+                        mapper,
+                    });
                 });
             }
 
@@ -350,6 +356,16 @@ where
                 self.operation.focusable(state, id);
             }
 
+            fn activatable(
+                &mut self,
+                id: Option<&widget::Id>,
+                activate: &dyn Fn() -> A,
+            ) {
+                let mapper = self.mapper;
+
+                self.operation.activatable(id, &|| mapper(activate()));
+            }
+
             fn scrollable(
                 &mut self,
                 state: &mut dyn widget::operation::Scrollable,
@@ -368,6 +384,24 @@ where
                 self.operation.text_input(state, id);
             }
 
+            fn text(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                text: &str,
+            ) {
+                self.operation.text(id, bounds, text);
+            }
+
+            fn accessible(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                accessible: widget::operation::Accessible<'_>,
+            ) {
+                self.operation.accessible(id, bounds, accessible);
+            }
+
             fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
                 self.operation.custom(state, id);
             }
@@ -377,7 +411,10 @@ where
             tree,
             layout,
             renderer,
-            &mut MapOperation { operation },
+            &mut MapOperation {
+                operation,
+                mapper: &self.mapper,
+            },
         );
     }
 
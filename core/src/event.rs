@@ -1,4 +1,5 @@
 //! Handle events of a user interface.
+use crate::input_method;
 use crate::keyboard;
 use crate::mouse;
 use crate::touch;
@@ -15,6 +16,9 @@ pub enum Event {
     /// A keyboard event
     Keyboard(keyboard::Event),
 
+    /// An input method event
+    InputMethod(input_method::Event),
+
     /// A mouse event
     Mouse(mouse::Event),
 
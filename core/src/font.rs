@@ -12,6 +12,9 @@ pub struct Font {
     pub stretch: Stretch,
     /// The [`Style`] of the [`Font`].
     pub style: Style,
+    /// The ordered list of [`Family`] choices used for the glyphs that are
+    /// missing from the main [`Family`] of the [`Font`].
+    pub fallback: &'static [Family],
 }
 
 impl Font {
@@ -21,6 +24,7 @@ impl Font {
         weight: Weight::Normal,
         stretch: Stretch::Normal,
         style: Style::Normal,
+        fallback: &[],
     };
 
     /// A monospaced font with normal [`Weight`].
@@ -37,6 +41,15 @@ impl Font {
             ..Self::DEFAULT
         }
     }
+
+    /// Sets the fallback chain of the [`Font`].
+    ///
+    /// Glyphs missing from the main [`Family`] of the [`Font`] will be looked
+    /// up in each [`Family`] of the chain, in order, before resorting to the
+    /// fallback fonts of the system.
+    pub const fn with_fallback(self, fallback: &'static [Family]) -> Self {
+        Font { fallback, ..self }
+    }
 }
 
 /// A font family.
@@ -109,3 +122,26 @@ pub enum Style {
     Italic,
     Oblique,
 }
+
+/// A font face available to the renderer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Face {
+    /// The name of the family of the [`Face`].
+    pub family: String,
+    /// The [`Weight`] of the [`Face`].
+    pub weight: Weight,
+    /// The [`Stretch`] of the [`Face`].
+    pub stretch: Stretch,
+    /// The [`Style`] of the [`Face`].
+    pub style: Style,
+    /// Whether all the glyphs of the [`Face`] have the same width.
+    pub monospaced: bool,
+}
+
+/// An error while loading a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The bytes did not contain any valid font face.
+    #[error("the font data could not be parsed")]
+    Invalid,
+}
@@ -1,25 +1,82 @@
 //! Colors that transition progressively.
-use crate::{Color, Radians};
+use crate::{Color, Point, Radians, Rectangle};
 
 use std::cmp::Ordering;
 
+/// The maximum amount of [`ColorStop`]s a [`Gradient`] can have.
+pub const MAX_STOPS: usize = 16;
+
+/// Implements the `add_stop` and `add_stops` builder methods of a gradient
+/// with a `stops` field.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! color_stops {
+    () => {
+        /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+        ///
+        /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+        ///
+        /// Any stop added after the first [`MAX_STOPS`] will be silently ignored.
+        ///
+        /// [`ColorStop`]: $crate::gradient::ColorStop
+        /// [`MAX_STOPS`]: $crate::gradient::MAX_STOPS
+        pub fn add_stop(mut self, offset: f32, color: $crate::Color) -> Self {
+            $crate::gradient::add_stop(&mut self.stops, offset, color);
+
+            self
+        }
+
+        /// Adds multiple [`ColorStop`]s to the gradient.
+        ///
+        /// Any stop added after the first [`MAX_STOPS`] will be silently ignored.
+        ///
+        /// [`ColorStop`]: $crate::gradient::ColorStop
+        /// [`MAX_STOPS`]: $crate::gradient::MAX_STOPS
+        pub fn add_stops(
+            mut self,
+            stops: impl IntoIterator<Item = $crate::gradient::ColorStop>,
+        ) -> Self {
+            for stop in stops {
+                self = self.add_stop(stop.offset, stop.color);
+            }
+
+            self
+        }
+    };
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which transitions colors progressively along a direction, either linearly, radially (TBD),
-/// or conically (TBD).
+/// A fill which transitions colors progressively along a direction, either linearly, radially,
+/// or conically.
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction at a specific angle.
     Linear(Linear),
+    /// A radial gradient interpolates colors outwards from its center.
+    Radial(Radial),
+    /// A conic gradient interpolates colors around its center.
+    Conic(Conic),
 }
 
 impl Gradient {
+    /// Returns the [`ColorStop`]s of the [`Gradient`].
+    pub fn stops(&self) -> &[Option<ColorStop>; MAX_STOPS] {
+        match self {
+            Gradient::Linear(linear) => &linear.stops,
+            Gradient::Radial(radial) => &radial.stops,
+            Gradient::Conic(conic) => &conic.stops,
+        }
+    }
+
     /// Adjust the opacity of the gradient by a multiplier applied to each color stop.
     pub fn mul_alpha(mut self, alpha_multiplier: f32) -> Self {
-        match &mut self {
-            Gradient::Linear(linear) => {
-                for stop in linear.stops.iter_mut().flatten() {
-                    stop.color.a *= alpha_multiplier;
-                }
-            }
+        let stops = match &mut self {
+            Gradient::Linear(linear) => &mut linear.stops,
+            Gradient::Radial(radial) => &mut radial.stops,
+            Gradient::Conic(conic) => &mut conic.stops,
+        };
+
+        for stop in stops.iter_mut().flatten() {
+            stop.color.a *= alpha_multiplier;
         }
 
         self
@@ -32,6 +89,18 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 /// A point along the gradient vector where the specified [`color`] is unmixed.
 ///
@@ -52,7 +121,7 @@ pub struct Linear {
     /// How the [`Gradient`] is angled within its bounds.
     pub angle: Radians,
     /// [`ColorStop`]s along the linear gradient path.
-    pub stops: [Option<ColorStop>; 8],
+    pub stops: [Option<ColorStop>; MAX_STOPS],
 }
 
 impl Linear {
@@ -60,44 +129,127 @@ impl Linear {
     pub fn new(angle: impl Into<Radians>) -> Self {
         Self {
             angle: angle.into(),
-            stops: [None; 8],
+            stops: [None; MAX_STOPS],
         }
     }
 
-    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
-    ///
-    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    crate::color_stops!();
+}
+
+/// A radial gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The center of the [`Gradient`], relative to its bounds.
     ///
-    /// Any stop added after the 8th will be silently ignored.
-    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) =
-                self.stops.binary_search_by(|stop| match stop {
-                    None => Ordering::Greater,
-                    Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-                });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
-        };
+    /// `(0.0, 0.0)` is the top-left corner and `(1.0, 1.0)` the bottom-right one.
+    pub center: Point,
+    /// The radius of the [`Gradient`], relative to the distance between its
+    /// center and the farthest corner of its bounds.
+    pub radius: f32,
+    /// [`ColorStop`]s along the radius of the gradient.
+    pub stops: [Option<ColorStop>; MAX_STOPS],
+}
 
+impl Radial {
+    /// Creates a new [`Radial`] gradient with the given relative radius,
+    /// centered within its bounds.
+    pub fn new(radius: f32) -> Self {
+        Self {
+            center: Point::new(0.5, 0.5),
+            radius,
+            stops: [None; MAX_STOPS],
+        }
+    }
+
+    /// Sets the relative center of the [`Radial`] gradient.
+    pub fn center(mut self, center: Point) -> Self {
+        self.center = center;
         self
     }
 
-    /// Adds multiple [`ColorStop`]s to the gradient.
+    crate::color_stops!();
+
+    /// Calculates the absolute center and radius of the [`Radial`] gradient
+    /// within the given `bounds`.
+    pub fn to_absolute(&self, bounds: &Rectangle) -> (Point, f32) {
+        let center = to_absolute(self.center, bounds);
+
+        let farthest_x =
+            f32::max(center.x - bounds.x, bounds.x + bounds.width - center.x);
+        let farthest_y =
+            f32::max(center.y - bounds.y, bounds.y + bounds.height - center.y);
+
+        (center, self.radius * farthest_x.hypot(farthest_y))
+    }
+}
+
+/// A conic gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The center of the [`Gradient`], relative to its bounds.
     ///
-    /// Any stop added after the 8th will be silently ignored.
-    pub fn add_stops(
-        mut self,
-        stops: impl IntoIterator<Item = ColorStop>,
-    ) -> Self {
-        for stop in stops {
-            self = self.add_stop(stop.offset, stop.color);
+    /// `(0.0, 0.0)` is the top-left corner and `(1.0, 1.0)` the bottom-right one.
+    pub center: Point,
+    /// The angle where the [`Gradient`] starts, measured clockwise from the top.
+    pub angle: Radians,
+    /// [`ColorStop`]s around the center of the gradient.
+    pub stops: [Option<ColorStop>; MAX_STOPS],
+}
+
+impl Conic {
+    /// Creates a new [`Conic`] gradient starting at the given angle in
+    /// [`Radians`], centered within its bounds.
+    pub fn new(angle: impl Into<Radians>) -> Self {
+        Self {
+            center: Point::new(0.5, 0.5),
+            angle: angle.into(),
+            stops: [None; MAX_STOPS],
         }
+    }
 
+    /// Sets the relative center of the [`Conic`] gradient.
+    pub fn center(mut self, center: Point) -> Self {
+        self.center = center;
         self
     }
+
+    crate::color_stops!();
+
+    /// Calculates the absolute center of the [`Conic`] gradient within the
+    /// given `bounds`.
+    pub fn to_absolute(&self, bounds: &Rectangle) -> Point {
+        to_absolute(self.center, bounds)
+    }
+}
+
+fn to_absolute(center: Point, bounds: &Rectangle) -> Point {
+    Point::new(
+        bounds.x + center.x * bounds.width,
+        bounds.y + center.y * bounds.height,
+    )
+}
+
+/// Adds a new [`ColorStop`], defined by an offset and a color, to the sorted
+/// `stops` of a gradient.
+///
+/// Any `offset` that is not within `0.0..=1.0` will be ignored with a warning.
+#[doc(hidden)]
+pub fn add_stop(
+    stops: &mut [Option<ColorStop>; MAX_STOPS],
+    offset: f32,
+    color: Color,
+) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) =
+            stops.binary_search_by(|stop| match stop {
+                None => Ordering::Greater,
+                Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
+            });
+
+        if index < MAX_STOPS {
+            stops[index] = Some(ColorStop { offset, color });
+        }
+    } else {
+        log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
+    };
 }
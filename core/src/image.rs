@@ -1,5 +1,7 @@
 //! Load and draw raster graphics.
-use crate::{Hasher, Rectangle, Size};
+use crate::border;
+use crate::time::Duration;
+use crate::{Color, Hasher, Radians, Rectangle, Size};
 
 use std::hash::{Hash, Hasher as _};
 use std::path::PathBuf;
@@ -164,6 +166,62 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// A single frame of an [`Animation`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Frame {
+    /// The image of the frame.
+    pub handle: Handle,
+    /// How long the frame is displayed before the next one.
+    pub delay: Duration,
+}
+
+/// The decoded frames of an animated image, like an animated GIF, APNG, or
+/// WebP.
+///
+/// Every frame is an independent [`Handle`], so renderers cache and draw
+/// them like any other image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    id: u64,
+    frames: Arc<[Frame]>,
+}
+
+impl Animation {
+    /// Creates a new [`Animation`] from the given frames.
+    pub fn new(frames: impl IntoIterator<Item = Frame>) -> Self {
+        let frames: Arc<[Frame]> = frames.into_iter().collect();
+
+        let mut hasher = Hasher::default();
+        frames.hash(&mut hasher);
+
+        Self {
+            id: hasher.finish(),
+            frames,
+        }
+    }
+
+    /// Returns the unique identifier of the [`Animation`].
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the frames of the [`Animation`].
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Returns the total duration of a single loop of the [`Animation`].
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+}
+
+impl Hash for Animation {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
 /// Image filtering strategy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum FilterMethod {
@@ -186,6 +244,14 @@ pub trait Renderer: crate::Renderer {
     /// Returns the dimensions of an image for the given [`Handle`].
     fn dimensions(&self, handle: &Self::Handle) -> Size<u32>;
 
+    /// Returns whether the image of the given [`Handle`] is still being
+    /// loaded.
+    ///
+    /// Widgets may draw a placeholder until the image is ready.
+    fn is_loading(&self, _handle: &Self::Handle) -> bool {
+        false
+    }
+
     /// Draws an image with the given [`Handle`] and inside the provided
     /// `bounds`.
     fn draw(
@@ -194,4 +260,28 @@ pub trait Renderer: crate::Renderer {
         filter_method: FilterMethod,
         bounds: Rectangle,
     );
+
+    /// Draws an image with the given [`Handle`] and inside the provided
+    /// `bounds`, rotated and styled.
+    ///
+    /// The image is rotated around its center by the given `rotation`, its
+    /// colors are multiplied by the `tint`, and its corners are clipped by
+    /// the `border_radius` before rotating.
+    ///
+    /// By default, the image is drawn with [`Renderer::draw`], ignoring the
+    /// rest of the arguments.
+    fn draw_image(
+        &mut self,
+        handle: Self::Handle,
+        filter_method: FilterMethod,
+        bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
+        tint: Option<Color>,
+        border_radius: border::Radius,
+    ) {
+        let _ = (rotation, opacity, tint, border_radius);
+
+        self.draw(handle, filter_method, bounds);
+    }
 }
@@ -0,0 +1,106 @@
+//! Listen to input method events and request input methods.
+use crate::Rectangle;
+
+use std::ops::Range;
+
+/// An input method event.
+///
+/// Input methods are used to compose text that cannot be typed directly
+/// with a keyboard; like CJK scripts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The input method was opened.
+    ///
+    /// [`Event::Preedit`] and [`Event::Commit`] may follow.
+    Opened,
+
+    /// The text being composed has changed.
+    ///
+    /// An empty preedit string means the composition was cleared.
+    Preedit(String, Option<Range<usize>>),
+
+    /// Some text was composed and should be inserted.
+    Commit(String),
+
+    /// The input method was closed.
+    ///
+    /// Any ongoing [`Preedit`] should be discarded.
+    Closed,
+}
+
+/// Some text being composed by an input method, before it is committed.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Preedit {
+    /// The text being composed.
+    pub content: String,
+
+    /// The byte range of the `content` that is currently selected by the
+    /// input method, if any.
+    pub selection: Option<Range<usize>>,
+}
+
+impl Preedit {
+    /// Creates a new [`Preedit`] from the contents of an [`Event::Preedit`].
+    ///
+    /// Returns `None` if the `content` is empty.
+    pub fn new(
+        content: String,
+        selection: Option<Range<usize>>,
+    ) -> Option<Self> {
+        if content.is_empty() {
+            None
+        } else {
+            Some(Self { content, selection })
+        }
+    }
+}
+
+/// The input method requested by a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum InputMethod {
+    /// No input method is allowed.
+    #[default]
+    Disabled,
+
+    /// An input method is allowed.
+    Enabled {
+        /// The area of the caret where text is being composed, in logical
+        /// pixels.
+        ///
+        /// The windowing system will use it to position any candidate window.
+        cursor: Rectangle,
+
+        /// The [`Purpose`] of the input.
+        purpose: Purpose,
+    },
+}
+
+impl InputMethod {
+    /// Returns whether the [`InputMethod`] is enabled.
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, Self::Enabled { .. })
+    }
+
+    /// Merges two [`InputMethod`] requests into one.
+    ///
+    /// The first [`InputMethod::Enabled`] request takes precedence.
+    pub fn merge(self, other: Self) -> Self {
+        match self {
+            Self::Disabled => other,
+            Self::Enabled { .. } => self,
+        }
+    }
+}
+
+/// The purpose of an [`InputMethod`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Purpose {
+    /// Regular text input.
+    #[default]
+    Normal,
+
+    /// Secure input, like passwords.
+    ///
+    /// Input methods should avoid showing or remembering the composed text.
+    Secure,
+}
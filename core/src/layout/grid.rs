@@ -0,0 +1,400 @@
+//! Distribute elements in a grid of rows and columns.
+use crate::Element;
+
+use crate::layout::{Limits, Node};
+use crate::widget;
+use crate::{Alignment, Length, Padding, Point, Size};
+
+/// The placement of an element in a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    /// The index of the first row of the [`Cell`].
+    pub row: usize,
+
+    /// The index of the first column of the [`Cell`].
+    pub column: usize,
+
+    /// The amount of rows the [`Cell`] spans.
+    pub row_span: usize,
+
+    /// The amount of columns the [`Cell`] spans.
+    pub column_span: usize,
+
+    /// The horizontal alignment of the element inside the [`Cell`].
+    pub align_x: Alignment,
+
+    /// The vertical alignment of the element inside the [`Cell`].
+    pub align_y: Alignment,
+}
+
+impl Cell {
+    /// Creates a new [`Cell`] at the given row and column.
+    pub fn new(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            align_x: Alignment::Start,
+            align_y: Alignment::Start,
+        }
+    }
+
+    /// Sets the amount of rows and columns the [`Cell`] spans.
+    pub fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.row_span = rows.max(1);
+        self.column_span = columns.max(1);
+        self
+    }
+
+    /// Sets the horizontal alignment of the element inside the [`Cell`].
+    pub fn align_x(mut self, alignment: Alignment) -> Self {
+        self.align_x = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the element inside the [`Cell`].
+    pub fn align_y(mut self, alignment: Alignment) -> Self {
+        self.align_y = alignment;
+        self
+    }
+}
+
+impl From<(usize, usize)> for Cell {
+    fn from((row, column): (usize, usize)) -> Self {
+        Self::new(row, column)
+    }
+}
+
+/// Computes the grid layout of the given items placed in the given cells,
+/// applying spacing and padding as needed.
+///
+/// Each track of the grid is sized according to its [`Length`]:
+///
+/// - [`Length::Fixed`] tracks have an exact size.
+/// - [`Length::Shrink`] tracks fit the largest item that spans only them,
+///   and then grow evenly to fit the items spanning them as well.
+/// - [`Length::Fill`] and [`Length::FillPortion`] tracks share the remaining
+///   space proportionally.
+///
+/// Cells outside of the provided tracks create new [`Length::Shrink`] tracks.
+///
+/// It returns a new layout [`Node`].
+pub fn resolve<Message, Theme, Renderer>(
+    renderer: &Renderer,
+    limits: &Limits,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    row_spacing: f32,
+    column_spacing: f32,
+    rows: &[Length],
+    columns: &[Length],
+    cells: &[Cell],
+    items: &[Element<'_, Message, Theme, Renderer>],
+    trees: &mut [widget::Tree],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.width(width).height(height).shrink(padding);
+    let max = limits.max();
+
+    let row_count = cells
+        .iter()
+        .map(|cell| cell.row + cell.row_span)
+        .chain([rows.len()])
+        .max()
+        .unwrap_or(0);
+
+    let column_count = cells
+        .iter()
+        .map(|cell| cell.column + cell.column_span)
+        .chain([columns.len()])
+        .max()
+        .unwrap_or(0);
+
+    // Items are measured at most once per axis, and their nodes are reused
+    // whenever they already fit their cells
+    let mut measured: Vec<Option<Node>> = vec![None; items.len()];
+
+    // Columns are resolved first, measuring items with the maximum height
+    let column_widths = {
+        let mut widths: Vec<f32> = (0..column_count)
+            .map(|i| match track(columns, i) {
+                Length::Fixed(amount) => amount,
+                _ => 0.0,
+            })
+            .collect();
+
+        let mut spanning = Vec::new();
+
+        for (i, ((cell, item), tree)) in
+            cells.iter().zip(items).zip(trees.iter_mut()).enumerate()
+        {
+            if !is_shrink(columns, cell.column, cell.column_span)
+                || item.as_widget().size().width.is_fill()
+            {
+                continue;
+            }
+
+            let node = item.as_widget().layout(
+                tree,
+                renderer,
+                &Limits::new(Size::ZERO, max),
+            );
+
+            let width = node.size().width;
+            measured[i] = Some(node);
+
+            if cell.column_span == 1 {
+                widths[cell.column] = widths[cell.column].max(width);
+            } else {
+                spanning.push((cell.column, cell.column_span, width));
+            }
+        }
+
+        grow(&mut widths, columns, spanning, column_spacing);
+        distribute(&mut widths, columns, width, max.width, column_spacing);
+
+        widths
+    };
+
+    // Rows are resolved next, measuring items with their final widths
+    let row_heights = {
+        let mut heights: Vec<f32> = (0..row_count)
+            .map(|i| match track(rows, i) {
+                Length::Fixed(amount) => amount,
+                _ => 0.0,
+            })
+            .collect();
+
+        let mut spanning = Vec::new();
+
+        for (i, ((cell, item), tree)) in
+            cells.iter().zip(items).zip(trees.iter_mut()).enumerate()
+        {
+            if !is_shrink(rows, cell.row, cell.row_span)
+                || item.as_widget().size().height.is_fill()
+            {
+                continue;
+            }
+
+            let cell_width = span(
+                &column_widths,
+                cell.column,
+                cell.column_span,
+                column_spacing,
+            );
+
+            let height = match &measured[i] {
+                Some(node) if node.size().width <= cell_width => {
+                    node.size().height
+                }
+                _ => {
+                    let node = item.as_widget().layout(
+                        tree,
+                        renderer,
+                        &Limits::new(
+                            Size::ZERO,
+                            Size::new(cell_width, max.height),
+                        ),
+                    );
+
+                    let height = node.size().height;
+                    measured[i] = Some(node);
+
+                    height
+                }
+            };
+
+            if cell.row_span == 1 {
+                heights[cell.row] = heights[cell.row].max(height);
+            } else {
+                spanning.push((cell.row, cell.row_span, height));
+            }
+        }
+
+        grow(&mut heights, rows, spanning, row_spacing);
+        distribute(&mut heights, rows, height, max.height, row_spacing);
+
+        heights
+    };
+
+    let nodes = cells
+        .iter()
+        .zip(items)
+        .zip(trees)
+        .zip(measured)
+        .map(|(((cell, item), tree), measured)| {
+            let x = padding.left
+                + span(&column_widths, 0, cell.column, column_spacing)
+                + if cell.column > 0 { column_spacing } else { 0.0 };
+
+            let y = padding.top
+                + span(&row_heights, 0, cell.row, row_spacing)
+                + if cell.row > 0 { row_spacing } else { 0.0 };
+
+            let cell_size = Size::new(
+                span(
+                    &column_widths,
+                    cell.column,
+                    cell.column_span,
+                    column_spacing,
+                ),
+                span(&row_heights, cell.row, cell.row_span, row_spacing),
+            );
+
+            let size = item.as_widget().size();
+
+            let mut node = match measured {
+                Some(node)
+                    if !size.width.is_fill()
+                        && !size.height.is_fill()
+                        && node.size().width <= cell_size.width
+                        && node.size().height <= cell_size.height =>
+                {
+                    node
+                }
+                _ => item.as_widget().layout(
+                    tree,
+                    renderer,
+                    &Limits::new(Size::ZERO, cell_size),
+                ),
+            };
+
+            node.move_to_mut(Point::new(x, y));
+            node.align_mut(cell.align_x, cell.align_y, cell_size);
+
+            node
+        })
+        .collect();
+
+    let intrinsic_size = Size::new(
+        span(&column_widths, 0, column_count, column_spacing),
+        span(&row_heights, 0, row_count, row_spacing),
+    );
+
+    let size = limits.resolve(width, height, intrinsic_size);
+
+    Node::with_children(size.expand(padding), nodes)
+}
+
+/// Returns the [`Length`] of the track at the given index.
+fn track(tracks: &[Length], index: usize) -> Length {
+    tracks.get(index).copied().unwrap_or(Length::Shrink)
+}
+
+/// Returns whether any of the `count` tracks starting at `start` is a
+/// [`Length::Shrink`] track, sized by its items.
+fn is_shrink(tracks: &[Length], start: usize, count: usize) -> bool {
+    (start..start + count).any(|i| track(tracks, i) == Length::Shrink)
+}
+
+/// Grows the [`Length::Shrink`] tracks spanned by items placed in multiple
+/// tracks until each item fits, splitting any missing space evenly.
+///
+/// Items spanning fewer tracks are fitted first.
+fn grow(
+    sizes: &mut [f32],
+    tracks: &[Length],
+    mut spanning: Vec<(usize, usize, f32)>,
+    spacing: f32,
+) {
+    spanning.sort_by_key(|(_, count, _)| *count);
+
+    for (start, count, size) in spanning {
+        let missing = size - span(sizes, start, count, spacing);
+
+        let shrink: Vec<usize> = (start..start + count)
+            .filter(|i| track(tracks, *i) == Length::Shrink)
+            .collect();
+
+        if missing <= 0.0 || shrink.is_empty() {
+            continue;
+        }
+
+        let extra = missing / shrink.len() as f32;
+
+        for i in shrink {
+            sizes[i] += extra;
+        }
+    }
+}
+
+/// Shares the remaining space among the fill tracks.
+fn distribute(
+    sizes: &mut [f32],
+    tracks: &[Length],
+    length: Length,
+    max: f32,
+    spacing: f32,
+) {
+    let fill_sum: u16 = (0..sizes.len())
+        .map(|i| tracks.get(i).map(Length::fill_factor).unwrap_or(0))
+        .sum();
+
+    if fill_sum == 0 {
+        return;
+    }
+
+    let used = span(sizes, 0, sizes.len(), spacing);
+
+    let remaining = match length {
+        Length::Shrink => 0.0,
+        _ if max.is_finite() => (max - used).max(0.0),
+        _ => 0.0,
+    };
+
+    for (size, track) in sizes.iter_mut().zip(tracks) {
+        let factor = track.fill_factor();
+
+        if factor != 0 {
+            *size = remaining * f32::from(factor) / f32::from(fill_sum);
+        }
+    }
+}
+
+/// Returns the total size of `count` tracks starting at `start`, including
+/// the spacing between them.
+fn span(sizes: &[f32], start: usize, count: usize, spacing: f32) -> f32 {
+    let tracks =
+        &sizes[start.min(sizes.len())..(start + count).min(sizes.len())];
+
+    tracks.iter().sum::<f32>() + spacing * tracks.len().saturating_sub(1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_tracks_share_remaining_space() {
+        let tracks =
+            [Length::Fixed(10.0), Length::Fill, Length::FillPortion(3)];
+        let mut sizes = [10.0, 0.0, 0.0];
+
+        distribute(&mut sizes, &tracks, Length::Fill, 110.0, 0.0);
+
+        assert_eq!(sizes, [10.0, 25.0, 75.0]);
+    }
+
+    #[test]
+    fn spanning_items_grow_their_shrink_tracks() {
+        let tracks = [Length::Shrink, Length::Fixed(20.0), Length::Shrink];
+        let mut sizes = [10.0, 20.0, 0.0];
+
+        grow(&mut sizes, &tracks, vec![(0, 3, 70.0), (0, 2, 40.0)], 5.0);
+
+        assert_eq!(sizes, [27.5, 20.0, 12.5]);
+    }
+
+    #[test]
+    fn spans_include_spacing() {
+        let sizes = [10.0, 20.0, 30.0];
+
+        assert_eq!(span(&sizes, 0, 0, 5.0), 0.0);
+        assert_eq!(span(&sizes, 1, 2, 5.0), 55.0);
+        assert_eq!(span(&sizes, 0, 3, 5.0), 70.0);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Point, Size, Vector};
+use crate::{Point, Radians, Size, Vector};
 
 /// A rectangle.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -157,6 +157,20 @@ impl Rectangle<f32> {
             height: self.height + amount * 2.0,
         }
     }
+
+    /// Rotates the [`Rectangle`] around its center and returns its
+    /// axis-aligned bounding box.
+    pub fn rotate(self, rotation: Radians) -> Self {
+        let size = self.size().rotate(rotation);
+        let center = self.center();
+
+        Self {
+            x: center.x - size.width / 2.0,
+            y: center.y - size.height / 2.0,
+            width: size.width,
+            height: size.height,
+        }
+    }
 }
 
 impl std::ops::Mul<f32> for Rectangle<f32> {
@@ -1,4 +1,5 @@
 use crate::alignment;
+use crate::font;
 use crate::renderer::{self, Renderer};
 use crate::text::{self, Text};
 use crate::{Background, Color, Font, Pixels, Point, Rectangle, Size, Vector};
@@ -55,7 +56,12 @@ impl text::Renderer for Null {
         Pixels(16.0)
     }
 
-    fn load_font(&mut self, _font: Cow<'static, [u8]>) {}
+    fn load_font(
+        &mut self,
+        _font: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        Ok(())
+    }
 
     fn fill_paragraph(
         &mut self,
@@ -90,6 +96,12 @@ impl text::Paragraph for () {
 
     fn with_text(_text: Text<'_, Self::Font>) -> Self {}
 
+    fn with_spans<Link>(
+        _text: Text<'_, Self::Font>,
+        _spans: &[text::Span<'_, Link, Self::Font>],
+    ) -> Self {
+    }
+
     fn resize(&mut self, _new_bounds: Size) {}
 
     fn compare(&self, _text: Text<'_, Self::Font>) -> text::Difference {
@@ -115,6 +127,25 @@ impl text::Paragraph for () {
     fn hit_test(&self, _point: Point) -> Option<text::Hit> {
         None
     }
+
+    fn selection_bounds(
+        &self,
+        _range: std::ops::Range<usize>,
+    ) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
+    fn hit_span(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
+    fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
+    fn span_baselines(&self, _index: usize) -> Vec<f32> {
+        Vec::new()
+    }
 }
 
 impl text::Editor for () {
@@ -154,6 +185,7 @@ impl text::Editor for () {
         _new_font: Self::Font,
         _new_size: Pixels,
         _new_line_height: text::LineHeight,
+        _new_wrapping: text::Wrapping,
         _new_highlighter: &mut impl text::Highlighter,
     ) {
     }
@@ -1,5 +1,6 @@
 //! Load and draw vector graphics.
-use crate::{Color, Hasher, Rectangle, Size};
+use crate::border;
+use crate::{Color, Hasher, Radians, Rectangle, Size};
 
 use std::borrow::Cow;
 use std::hash::{Hash, Hasher as _};
@@ -86,4 +87,28 @@ pub trait Renderer: crate::Renderer {
 
     /// Draws an SVG with the given [`Handle`], an optional [`Color`] filter, and inside the provided `bounds`.
     fn draw(&mut self, handle: Handle, color: Option<Color>, bounds: Rectangle);
+
+    /// Draws an SVG with the given [`Handle`], an optional [`Color`] filter,
+    /// and inside the provided `bounds`, rotated and styled.
+    ///
+    /// The SVG is rotated around its center by the given `rotation`, its
+    /// colors are multiplied by the `tint`, and its corners are clipped by
+    /// the `border_radius` before rotating.
+    ///
+    /// By default, the SVG is drawn with [`Renderer::draw`], ignoring the
+    /// rest of the arguments.
+    fn draw_svg(
+        &mut self,
+        handle: Handle,
+        color: Option<Color>,
+        bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
+        tint: Option<Color>,
+        border_radius: border::Radius,
+    ) {
+        let _ = (rotation, opacity, tint, border_radius);
+
+        self.draw(handle, color, bounds);
+    }
 }
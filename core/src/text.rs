@@ -1,5 +1,6 @@
 //! Draw and interact with text.
 mod paragraph;
+mod span;
 
 pub mod editor;
 pub mod highlighter;
@@ -7,8 +8,10 @@ pub mod highlighter;
 pub use editor::Editor;
 pub use highlighter::Highlighter;
 pub use paragraph::Paragraph;
+pub use span::Span;
 
 use crate::alignment;
+use crate::font;
 use crate::{Color, Pixels, Point, Rectangle, Size};
 
 use std::borrow::Cow;
@@ -40,6 +43,33 @@ pub struct Text<'a, Font> {
 
     /// The [`Shaping`] strategy of the [`Text`].
     pub shaping: Shaping,
+
+    /// The [`Wrapping`] strategy of the [`Text`].
+    pub wrapping: Wrapping,
+
+    /// The extra space between the letters of the [`Text`] in logical pixels.
+    pub letter_spacing: Pixels,
+
+    /// The [`Ellipsis`] strategy of the [`Text`].
+    pub ellipsis: Ellipsis,
+
+    /// The maximum amount of lines of the [`Text`], if any.
+    pub max_lines: Option<usize>,
+}
+
+/// The truncation strategy of some text exceeding its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Ellipsis {
+    /// The text is not truncated.
+    ///
+    /// This is the default.
+    #[default]
+    None,
+    /// The end of the text is replaced with an ellipsis.
+    End,
+    /// The middle of the text is replaced with an ellipsis, keeping the text
+    /// in a single line.
+    Middle,
 }
 
 /// The shaping strategy of some text.
@@ -66,6 +96,23 @@ pub enum Shaping {
     Advanced,
 }
 
+/// The wrapping strategy of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Wrapping {
+    /// No wrapping.
+    None,
+    /// Wraps at the word level.
+    ///
+    /// This is the default.
+    #[default]
+    Word,
+    /// Wraps at the glyph level.
+    Glyph,
+    /// Wraps at the word level, or at the glyph level if a word does not fit
+    /// in a line by itself.
+    WordOrGlyph,
+}
+
 /// The height of a line of text in a paragraph.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LineHeight {
@@ -193,7 +240,10 @@ pub trait Renderer: crate::Renderer {
     fn default_size(&self) -> Pixels;
 
     /// Loads a [`Self::Font`] from its bytes.
-    fn load_font(&mut self, font: Cow<'static, [u8]>);
+    fn load_font(
+        &mut self,
+        font: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error>;
 
     /// Draws the given [`Paragraph`] at the given position and with the given
     /// [`Color`].
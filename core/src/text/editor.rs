@@ -1,6 +1,6 @@
 //! Edit text.
 use crate::text::highlighter::{self, Highlighter};
-use crate::text::LineHeight;
+use crate::text::{LineHeight, Wrapping};
 use crate::{Pixels, Point, Rectangle, Size};
 
 use std::sync::Arc;
@@ -24,6 +24,33 @@ pub trait Editor: Sized + Default {
     /// Returns the current selected text of the [`Editor`].
     fn selection(&self) -> Option<String>;
 
+    /// Returns the position where the current selection of the [`Editor`]
+    /// started, if any.
+    ///
+    /// Line and column, respectively.
+    ///
+    /// By default, no anchor is reported.
+    fn selection_anchor(&self) -> Option<(usize, usize)> {
+        None
+    }
+
+    /// Selects the text between the given positions of the [`Editor`],
+    /// leaving the cursor at `to`.
+    ///
+    /// If both positions are equal, the selection is cleared and the
+    /// cursor is simply moved to `to`.
+    ///
+    /// By default, this performs a [`Motion`] for every line and character
+    /// up to each position. Implementors should override it with a direct
+    /// approach when possible.
+    fn select_range(&mut self, from: (usize, usize), to: (usize, usize)) {
+        move_to(self, Action::Move, from);
+
+        if from != to {
+            move_to(self, Action::Select, to);
+        }
+    }
+
     /// Returns the text of the given line in the [`Editor`], if it exists.
     fn line(&self, index: usize) -> Option<&str>;
 
@@ -33,6 +60,15 @@ pub trait Editor: Sized + Default {
     /// Performs an [`Action`] on the [`Editor`].
     fn perform(&mut self, action: Action);
 
+    /// Sets the text being composed by an input method at the cursor of the
+    /// [`Editor`], if any.
+    ///
+    /// The composed text is laid out inline, shifting the text after the
+    /// cursor, but it is not part of the contents of the [`Editor`].
+    ///
+    /// By default, the composed text is not displayed.
+    fn set_preedit(&mut self, _preedit: Option<&str>) {}
+
     /// Returns the current boundaries of the [`Editor`].
     fn bounds(&self) -> Size;
 
@@ -43,6 +79,7 @@ pub trait Editor: Sized + Default {
         new_font: Self::Font,
         new_size: Pixels,
         new_line_height: LineHeight,
+        new_wrapping: Wrapping,
         new_highlighter: &mut impl Highlighter,
     );
 
@@ -55,6 +92,33 @@ pub trait Editor: Sized + Default {
     );
 }
 
+/// Moves the cursor of an [`Editor`] to the given line and column by
+/// performing the [`Motion`]s produced by the given `action`.
+fn move_to<E: Editor>(
+    editor: &mut E,
+    action: fn(Motion) -> Action,
+    (line, column): (usize, usize),
+) {
+    let line = line.min(editor.line_count().saturating_sub(1));
+
+    editor.perform(action(Motion::DocumentStart));
+
+    for _ in 0..line {
+        editor.perform(action(Motion::End));
+        editor.perform(action(Motion::Right));
+    }
+
+    let characters = editor.line(line).map_or(0, |text| {
+        text.char_indices()
+            .take_while(|(index, _)| *index < column)
+            .count()
+    });
+
+    for _ in 0..characters {
+        editor.perform(action(Motion::Right));
+    }
+}
+
 /// An interaction with an [`Editor`].
 #[derive(Debug, Clone, PartialEq)]
 pub enum Action {
@@ -77,12 +141,19 @@ pub enum Action {
         /// The amount of lines to scroll.
         lines: i32,
     },
+    /// Revert the last group of edits.
+    Undo,
+    /// Reapply the last group of reverted edits.
+    Redo,
 }
 
 impl Action {
     /// Returns whether the [`Action`] is an editing action.
+    ///
+    /// [`Action::Undo`] and [`Action::Redo`] are considered editing actions,
+    /// since they may change the contents of an [`Editor`].
     pub fn is_edit(&self) -> bool {
-        matches!(self, Self::Edit(_))
+        matches!(self, Self::Edit(_) | Self::Undo | Self::Redo)
     }
 }
 
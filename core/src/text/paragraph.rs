@@ -1,6 +1,8 @@
 use crate::alignment;
-use crate::text::{Difference, Hit, Text};
-use crate::{Point, Size};
+use crate::text::{Difference, Hit, Span, Text};
+use crate::{Point, Rectangle, Size};
+
+use std::ops::Range;
 
 /// A text paragraph.
 pub trait Paragraph: Sized + Default {
@@ -10,11 +12,23 @@ pub trait Paragraph: Sized + Default {
     /// Creates a new [`Paragraph`] laid out with the given [`Text`].
     fn with_text(text: Text<'_, Self::Font>) -> Self;
 
+    /// Creates a new [`Paragraph`] laid out with the given [`Span`]s.
+    ///
+    /// The `content` of the [`Text`] is ignored; the rest of its attributes
+    /// are used for any [`Span`] that does not override them.
+    fn with_spans<Link>(
+        text: Text<'_, Self::Font>,
+        spans: &[Span<'_, Link, Self::Font>],
+    ) -> Self;
+
     /// Lays out the [`Paragraph`] with some new boundaries.
     fn resize(&mut self, new_bounds: Size);
 
     /// Compares the [`Paragraph`] with some desired [`Text`] and returns the
     /// [`Difference`].
+    ///
+    /// The `content` of the [`Text`] is ignored if the [`Paragraph`] was
+    /// created with [`Paragraph::with_spans`].
     fn compare(&self, text: Text<'_, Self::Font>) -> Difference;
 
     /// Returns the horizontal alignment of the [`Paragraph`].
@@ -29,8 +43,30 @@ pub trait Paragraph: Sized + Default {
 
     /// Tests whether the provided point is within the boundaries of the
     /// [`Paragraph`], returning information about the nearest character.
+    ///
+    /// The character offset of the [`Hit`] is a byte offset in the whole
+    /// contents of the [`Paragraph`].
     fn hit_test(&self, point: Point) -> Option<Hit>;
 
+    /// Returns the bounds of the given byte range of the contents of the
+    /// [`Paragraph`].
+    ///
+    /// A range that spans multiple lines will produce multiple bounds.
+    fn selection_bounds(&self, range: Range<usize>) -> Vec<Rectangle>;
+
+    /// Tests whether the provided point is within the boundaries of a
+    /// [`Span`] in the [`Paragraph`], returning its index.
+    fn hit_span(&self, point: Point) -> Option<usize>;
+
+    /// Returns all the bounds of the [`Span`] with the given index.
+    ///
+    /// A [`Span`] that wraps across lines will produce multiple bounds.
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
+
+    /// Returns the baselines of the [`Span`] with the given index, one for
+    /// each of its [`Paragraph::span_bounds`].
+    fn span_baselines(&self, index: usize) -> Vec<f32>;
+
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
@@ -0,0 +1,106 @@
+use crate::{Color, Pixels};
+
+use std::borrow::Cow;
+
+/// A span of text in a rich paragraph.
+///
+/// Any attribute that is not set is inherited from the paragraph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span<'a, Link = (), Font = crate::Font> {
+    /// The text of the [`Span`].
+    pub text: Cow<'a, str>,
+
+    /// The size of the [`Span`] in [`Pixels`].
+    pub size: Option<Pixels>,
+
+    /// The font of the [`Span`].
+    pub font: Option<Font>,
+
+    /// The [`Color`] of the [`Span`].
+    pub color: Option<Color>,
+
+    /// The link of the [`Span`].
+    pub link: Option<Link>,
+
+    /// Whether the [`Span`] should be underlined.
+    pub underline: bool,
+
+    /// Whether the [`Span`] should be struck through.
+    pub strikethrough: bool,
+}
+
+impl<'a, Link, Font> Span<'a, Link, Font> {
+    /// Creates a new [`Span`] of text with the given contents.
+    pub fn new(text: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            text: text.into(),
+            size: None,
+            font: None,
+            color: None,
+            link: None,
+            underline: false,
+            strikethrough: false,
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the font of the [`Span`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the link of the [`Span`].
+    pub fn link(mut self, link: impl Into<Link>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    /// Sets whether the [`Span`] should be underlined.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets whether the [`Span`] should be struck through.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    /// Turns the [`Span`] into a static one.
+    pub fn to_static(self) -> Span<'static, Link, Font> {
+        Span {
+            text: Cow::Owned(self.text.into_owned()),
+            size: self.size,
+            font: self.font,
+            color: self.color,
+            link: self.link,
+            underline: self.underline,
+            strikethrough: self.strikethrough,
+        }
+    }
+}
+
+impl<'a, Link, Font> From<&'a str> for Span<'a, Link, Font> {
+    fn from(text: &'a str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl<'a, Link, Font> From<String> for Span<'a, Link, Font> {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
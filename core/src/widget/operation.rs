@@ -1,8 +1,11 @@
 //! Query or update internal widget state.
+pub mod accessible;
+pub mod activatable;
 pub mod focusable;
 pub mod scrollable;
 pub mod text_input;
 
+pub use accessible::Accessible;
 pub use focusable::Focusable;
 pub use scrollable::Scrollable;
 pub use text_input::TextInput;
@@ -31,6 +34,12 @@ pub trait Operation<T> {
     /// Operates on a widget that can be focused.
     fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
 
+    /// Operates on a widget that can be activated, like a button.
+    ///
+    /// The `activate` function produces the message that the widget publishes
+    /// when activated.
+    fn activatable(&mut self, _id: Option<&Id>, _activate: &dyn Fn() -> T) {}
+
     /// Operates on a widget that can be scrolled.
     fn scrollable(
         &mut self,
@@ -44,6 +53,18 @@ pub trait Operation<T> {
     /// Operates on a widget that has text input.
     fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
 
+    /// Operates on a widget that displays some text.
+    fn text(&mut self, _id: Option<&Id>, _bounds: Rectangle, _text: &str) {}
+
+    /// Operates on a widget that can be described to assistive technology.
+    fn accessible(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _accessible: Accessible<'_>,
+    ) {
+    }
+
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
 
@@ -148,6 +169,24 @@ where
                     self.operation.text_input(state, id);
                 }
 
+                fn text(
+                    &mut self,
+                    id: Option<&Id>,
+                    bounds: Rectangle,
+                    text: &str,
+                ) {
+                    self.operation.text(id, bounds, text);
+                }
+
+                fn accessible(
+                    &mut self,
+                    id: Option<&Id>,
+                    bounds: Rectangle,
+                    accessible: Accessible<'_>,
+                ) {
+                    self.operation.accessible(id, bounds, accessible);
+                }
+
                 fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
                     self.operation.custom(state, id);
                 }
@@ -179,6 +218,19 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            accessible: Accessible<'_>,
+        ) {
+            self.operation.accessible(id, bounds, accessible);
+        }
+
         fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
@@ -0,0 +1,115 @@
+//! Describe widgets to assistive technology.
+
+/// The description of a widget that assistive technology can read and
+/// interact with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Accessible<'a> {
+    /// The [`Role`] of the widget.
+    pub role: Role,
+
+    /// The label of the widget, if any.
+    ///
+    /// If `None`, the label is computed from the text of its children.
+    pub label: Option<&'a str>,
+
+    /// The current [`Value`] of the widget, if any.
+    pub value: Option<Value<'a>>,
+
+    /// Whether the widget is focused or not.
+    pub is_focused: bool,
+
+    /// Whether the widget is disabled or not.
+    pub is_disabled: bool,
+}
+
+impl<'a> Accessible<'a> {
+    /// Creates a new [`Accessible`] with the given [`Role`].
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            label: None,
+            value: None,
+            is_focused: false,
+            is_disabled: false,
+        }
+    }
+
+    /// Sets the label of the [`Accessible`].
+    pub fn label(self, label: &'a str) -> Self {
+        Self {
+            label: Some(label),
+            ..self
+        }
+    }
+
+    /// Sets the [`Value`] of the [`Accessible`].
+    pub fn value(self, value: Value<'a>) -> Self {
+        Self {
+            value: Some(value),
+            ..self
+        }
+    }
+
+    /// Sets whether the [`Accessible`] is focused or not.
+    pub fn focused(self, is_focused: bool) -> Self {
+        Self { is_focused, ..self }
+    }
+
+    /// Sets whether the [`Accessible`] is disabled or not.
+    pub fn disabled(self, is_disabled: bool) -> Self {
+        Self {
+            is_disabled,
+            ..self
+        }
+    }
+}
+
+/// The role of an accessible widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// A button that can be pressed.
+    Button,
+
+    /// A box that can be checked or unchecked.
+    CheckBox,
+
+    /// A switch that can be turned on or off.
+    Switch,
+
+    /// A button that selects one option out of many.
+    RadioButton,
+
+    /// A field where text can be typed.
+    TextInput,
+
+    /// A control that selects a value out of a range.
+    Slider,
+
+    /// An indicator of the progress of a task.
+    ProgressBar,
+
+    /// A control that selects one option out of a list.
+    ComboBox,
+}
+
+/// The value of an accessible widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value<'a> {
+    /// Some text.
+    Text(&'a str),
+
+    /// Whether the widget is checked or not.
+    Checked(bool),
+
+    /// A number in a range.
+    Numeric {
+        /// The current number.
+        value: f64,
+
+        /// The minimum number of the range.
+        min: f64,
+
+        /// The maximum number of the range.
+        max: f64,
+    },
+}
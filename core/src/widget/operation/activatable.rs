@@ -0,0 +1,46 @@
+//! Operate on widgets that can be activated.
+use crate::widget::operation::{Operation, Outcome};
+use crate::widget::Id;
+use crate::Rectangle;
+
+use std::cell::Cell;
+
+/// Produces an [`Operation`] that activates the widget with the given [`Id`].
+///
+/// The [`Outcome`] of the [`Operation`] is the message published by the
+/// widget, if found.
+pub fn activate<T>(target: Id) -> impl Operation<T> {
+    struct Activate<T> {
+        target: Id,
+        message: Cell<Option<T>>,
+    }
+
+    impl<T> Operation<T> for Activate<T> {
+        fn activatable(&mut self, id: Option<&Id>, activate: &dyn Fn() -> T) {
+            if id == Some(&self.target) && self.message.get_mut().is_none() {
+                self.message.set(Some(activate()));
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            match self.message.take() {
+                Some(message) => Outcome::Some(message),
+                None => Outcome::None,
+            }
+        }
+    }
+
+    Activate {
+        target,
+        message: Cell::new(None),
+    }
+}
@@ -1,17 +1,22 @@
 //! Write some text for your users to read.
 use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
-use crate::mouse;
+use crate::mouse::{self, click};
 use crate::renderer;
 use crate::text::{self, Paragraph};
+use crate::touch;
+use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
 use crate::{
-    Color, Element, Layout, Length, Pixels, Point, Rectangle, Size, Widget,
+    Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Vector, Widget,
 };
 
 use std::borrow::Cow;
 
-pub use text::{LineHeight, Shaping};
+pub use text::{Ellipsis, LineHeight, Shaping, Wrapping};
 
 /// A paragraph of text.
 #[allow(missing_debug_implementations)]
@@ -29,6 +34,13 @@ where
     vertical_alignment: alignment::Vertical,
     font: Option<Renderer::Font>,
     shaping: Shaping,
+    wrapping: Wrapping,
+    letter_spacing: Pixels,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
+    underline: bool,
+    strikethrough: bool,
+    is_selectable: bool,
     style: Theme::Style,
 }
 
@@ -49,6 +61,13 @@ where
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             shaping: Shaping::Basic,
+            wrapping: Wrapping::default(),
+            letter_spacing: Pixels(0.0),
+            ellipsis: Ellipsis::None,
+            max_lines: None,
+            underline: false,
+            strikethrough: false,
+            is_selectable: false,
             style: Default::default(),
         }
     }
@@ -114,11 +133,95 @@ where
         self.shaping = shaping;
         self
     }
+
+    /// Sets the [`Wrapping`] strategy of the [`Text`].
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Sets the extra space between the letters of the [`Text`].
+    pub fn letter_spacing(mut self, letter_spacing: impl Into<Pixels>) -> Self {
+        self.letter_spacing = letter_spacing.into();
+        self
+    }
+
+    /// Sets the [`Ellipsis`] strategy of the [`Text`], used when its contents
+    /// exceed its bounds.
+    pub fn ellipsis(mut self, ellipsis: Ellipsis) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Sets the maximum amount of lines of the [`Text`].
+    ///
+    /// Any lines beyond the limit are truncated with the [`Ellipsis`] of the
+    /// [`Text`], if any.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Sets whether the [`Text`] should be underlined.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets whether the [`Text`] should be struck through.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    /// Sets whether the contents of the [`Text`] can be selected with the
+    /// mouse and copied to the clipboard.
+    ///
+    /// By default, a [`Text`] is not selectable.
+    pub fn selectable(mut self, is_selectable: bool) -> Self {
+        self.is_selectable = is_selectable;
+        self
+    }
 }
 
 /// The internal state of a [`Text`] widget.
 #[derive(Debug, Default)]
-pub struct State<P: Paragraph>(P);
+pub struct State<P: Paragraph> {
+    paragraph: P,
+    selection: Option<Selection>,
+    is_focused: bool,
+    is_dragging: bool,
+    last_click: Option<mouse::Click>,
+}
+
+impl<P: Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.selection = None;
+        self.is_dragging = false;
+    }
+}
+
+/// A selection in the contents of a [`Text`], as byte offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Selection {
+    anchor: usize,
+    head: usize,
+}
+
+impl Selection {
+    fn range(self) -> std::ops::Range<usize> {
+        self.anchor.min(self.head)..self.anchor.max(self.head)
+    }
+}
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Text<'a, Theme, Renderer>
@@ -131,7 +234,7 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State(Renderer::Paragraph::default()))
+        tree::State::new(State::<Renderer::Paragraph>::default())
     }
 
     fn size(&self) -> Size<Length> {
@@ -160,9 +263,71 @@ where
             self.horizontal_alignment,
             self.vertical_alignment,
             self.shaping,
+            self.wrapping,
+            self.letter_spacing,
+            self.ellipsis,
+            self.max_lines,
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        // A selectable text only takes part in focus operations while it is
+        // focused, so it can lose its focus without becoming reachable
+        // through keyboard navigation
+        if self.is_selectable && state.is_focused {
+            operation.focusable(state, None);
+        }
+
+        operation.text(None, layout.bounds(), &self.content);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        if !self.is_selectable {
+            return event::Status::Ignored;
+        }
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        update(state, event, layout, cursor, clipboard, &self.content)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if self.is_selectable
+            && (state.is_dragging || cursor.is_over(layout.bounds()))
+        {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
     fn draw(
         &self,
         tree: &Tree,
@@ -174,15 +339,21 @@ where
         viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let appearance = theme.appearance(self.style.clone());
+
+        if self.underline || self.strikethrough {
+            draw_decorations(
+                renderer,
+                layout,
+                &state.paragraph,
+                self.size.unwrap_or_else(|| renderer.default_size()),
+                appearance.color.unwrap_or(style.text_color),
+                self.underline,
+                self.strikethrough,
+            );
+        }
 
-        draw(
-            renderer,
-            style,
-            layout,
-            state,
-            theme.appearance(self.style.clone()),
-            viewport,
-        );
+        draw(renderer, style, layout, state, appearance, viewport);
     }
 }
 
@@ -200,6 +371,10 @@ pub fn layout<Renderer>(
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     shaping: Shaping,
+    wrapping: Wrapping,
+    letter_spacing: Pixels,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
 ) -> layout::Node
 where
     Renderer: text::Renderer,
@@ -210,7 +385,7 @@ where
         let size = size.unwrap_or_else(|| renderer.default_size());
         let font = font.unwrap_or_else(|| renderer.default_font());
 
-        let State(ref mut paragraph) = state;
+        let paragraph = &mut state.paragraph;
 
         paragraph.update(text::Text {
             content,
@@ -221,12 +396,113 @@ where
             horizontal_alignment,
             vertical_alignment,
             shaping,
+            wrapping,
+            letter_spacing,
+            ellipsis,
+            max_lines,
         });
 
         paragraph.min_bounds()
     })
 }
 
+/// Processes an [`Event`] and updates the selection of a selectable
+/// [`Text`] accordingly.
+pub fn update<P: Paragraph>(
+    state: &mut State<P>,
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    clipboard: &mut dyn Clipboard,
+    content: &str,
+) -> event::Status {
+    let bounds = layout.bounds();
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            let Some(position) = cursor.position_over(bounds) else {
+                operation::Focusable::unfocus(state);
+
+                return event::Status::Ignored;
+            };
+
+            let offset =
+                find_offset(&state.paragraph, bounds, content, position);
+            let click = mouse::Click::new(position, state.last_click);
+
+            let (selection, is_dragging) = match click.kind() {
+                click::Kind::Single => (
+                    Selection {
+                        anchor: offset,
+                        head: offset,
+                    },
+                    true,
+                ),
+                click::Kind::Double => (
+                    Selection {
+                        anchor: previous_start_of_word(content, offset),
+                        head: next_end_of_word(content, offset),
+                    },
+                    false,
+                ),
+                click::Kind::Triple => (
+                    Selection {
+                        anchor: 0,
+                        head: content.len(),
+                    },
+                    false,
+                ),
+            };
+
+            state.selection = Some(selection);
+            state.is_focused = true;
+            state.is_dragging = is_dragging;
+            state.last_click = Some(click);
+
+            return event::Status::Captured;
+        }
+        Event::Mouse(mouse::Event::CursorMoved { position })
+        | Event::Touch(touch::Event::FingerMoved { position, .. })
+            if state.is_dragging =>
+        {
+            let offset =
+                find_offset(&state.paragraph, bounds, content, position);
+
+            if let Some(selection) = &mut state.selection {
+                selection.head = offset;
+            }
+
+            return event::Status::Captured;
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. }) => {
+            state.is_dragging = false;
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Character(c),
+            modifiers,
+            ..
+        }) if c.as_str() == "c" && modifiers.command() && state.is_focused => {
+            if let Some(selection) = state.selection {
+                let range = selection.range();
+
+                if !range.is_empty() {
+                    if let Some(selected) = content.get(range) {
+                        clipboard.write(selected.to_owned());
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    event::Status::Ignored
+}
+
 /// Draws text using the same logic as the [`Text`] widget.
 ///
 /// Specifically:
@@ -237,6 +513,7 @@ where
 ///   used.
 /// * The alignment attributes do not affect the position of the bounds of the
 ///   [`Layout`].
+/// * Any selection is drawn behind the text.
 pub fn draw<Renderer>(
     renderer: &mut Renderer,
     style: &renderer::Style,
@@ -247,9 +524,85 @@ pub fn draw<Renderer>(
 ) where
     Renderer: text::Renderer,
 {
-    let State(ref paragraph) = state;
+    let paragraph = &state.paragraph;
     let bounds = layout.bounds();
+    let color = appearance.color.unwrap_or(style.text_color);
+
+    if let Some(selection) = state.selection {
+        let origin = origin(paragraph, bounds);
+        let translation = Vector::new(origin.x, origin.y);
+
+        let selection_color = appearance.selection.unwrap_or(Color {
+            a: color.a * 0.3,
+            ..color
+        });
+
+        for bounds in paragraph.selection_bounds(selection.range()) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds + translation,
+                    ..Default::default()
+                },
+                selection_color,
+            );
+        }
+    }
+
+    renderer.fill_paragraph(
+        paragraph,
+        anchor(paragraph, bounds),
+        color,
+        *viewport,
+    );
+}
 
+/// Draws the underline and strikethrough of the lines of a [`Paragraph`]
+/// with the given text size.
+fn draw_decorations<Renderer>(
+    renderer: &mut Renderer,
+    layout: Layout<'_>,
+    paragraph: &Renderer::Paragraph,
+    size: Pixels,
+    color: Color,
+    underline: bool,
+    strikethrough: bool,
+) where
+    Renderer: text::Renderer,
+{
+    let origin = origin(paragraph, layout.bounds());
+
+    for bounds in paragraph.selection_bounds(0..usize::MAX) {
+        let bounds = bounds + Vector::new(origin.x, origin.y);
+        let baseline = bounds.y + (bounds.height + size.0) / 2.0;
+
+        let mut draw_line = |y: f32| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x,
+                        y,
+                        width: bounds.width,
+                        height: 1.0,
+                    },
+                    ..Default::default()
+                },
+                color,
+            );
+        };
+
+        if underline {
+            draw_line(baseline - size.0 * 0.1);
+        }
+
+        if strikethrough {
+            draw_line(baseline - size.0 * 0.4);
+        }
+    }
+}
+
+/// Returns the position where the [`Paragraph`] is anchored when drawn in
+/// the given bounds.
+fn anchor(paragraph: &impl Paragraph, bounds: Rectangle) -> Point {
     let x = match paragraph.horizontal_alignment() {
         alignment::Horizontal::Left => bounds.x,
         alignment::Horizontal::Center => bounds.center_x(),
@@ -262,12 +615,74 @@ pub fn draw<Renderer>(
         alignment::Vertical::Bottom => bounds.y + bounds.height,
     };
 
-    renderer.fill_paragraph(
-        paragraph,
-        Point::new(x, y),
-        appearance.color.unwrap_or(style.text_color),
-        *viewport,
-    );
+    Point::new(x, y)
+}
+
+/// Returns the top left corner of the [`Paragraph`] when drawn in the given
+/// bounds.
+fn origin(paragraph: &impl Paragraph, bounds: Rectangle) -> Point {
+    let anchor = anchor(paragraph, bounds);
+    let min_bounds = paragraph.min_bounds();
+
+    let x = match paragraph.horizontal_alignment() {
+        alignment::Horizontal::Left => anchor.x,
+        alignment::Horizontal::Center => anchor.x - min_bounds.width / 2.0,
+        alignment::Horizontal::Right => anchor.x - min_bounds.width,
+    };
+
+    let y = match paragraph.vertical_alignment() {
+        alignment::Vertical::Top => anchor.y,
+        alignment::Vertical::Center => anchor.y - min_bounds.height / 2.0,
+        alignment::Vertical::Bottom => anchor.y - min_bounds.height,
+    };
+
+    Point::new(x, y)
+}
+
+/// Finds the byte offset in the `content` of the [`Paragraph`] that is
+/// closest to the given position.
+fn find_offset(
+    paragraph: &impl Paragraph,
+    bounds: Rectangle,
+    content: &str,
+    position: Point,
+) -> usize {
+    let origin = origin(paragraph, bounds);
+    let point = Point::new(position.x - origin.x, position.y - origin.y);
+
+    let offset = match paragraph.hit_test(point) {
+        Some(hit) => hit.cursor(),
+        None if point.y < 0.0 => 0,
+        None => content.len(),
+    };
+
+    let mut offset = offset.min(content.len());
+
+    while !content.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    offset
+}
+
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn previous_start_of_word(content: &str, offset: usize) -> usize {
+    content[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_word(*c))
+        .last()
+        .map_or(offset, |(i, _)| i)
+}
+
+fn next_end_of_word(content: &str, offset: usize) -> usize {
+    content[offset..]
+        .char_indices()
+        .find(|(_, c)| !is_word(*c))
+        .map_or(content.len(), |(i, _)| offset + i)
 }
 
 impl<'a, Message, Theme, Renderer> From<Text<'a, Theme, Renderer>>
@@ -300,6 +715,13 @@ where
             font: self.font,
             style: self.style.clone(),
             shaping: self.shaping,
+            wrapping: self.wrapping,
+            letter_spacing: self.letter_spacing,
+            ellipsis: self.ellipsis,
+            max_lines: self.max_lines,
+            underline: self.underline,
+            strikethrough: self.strikethrough,
+            is_selectable: self.is_selectable,
         }
     }
 }
@@ -341,4 +763,79 @@ pub struct Appearance {
     ///
     /// The default, `None`, means using the inherited color.
     pub color: Option<Color>,
+
+    /// The [`Color`] of the selection of a selectable [`Text`].
+    ///
+    /// The default, `None`, means using a translucent version of the color of
+    /// the text.
+    pub selection: Option<Color>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Clipboard(Option<String>);
+
+    impl crate::Clipboard for Clipboard {
+        fn read(&self) -> Option<String> {
+            self.0.clone()
+        }
+
+        fn write(&mut self, contents: String) {
+            self.0 = Some(contents);
+        }
+    }
+
+    #[test]
+    fn only_focused_text_copies_its_selection() {
+        let node = layout::Node::new(Size::new(100.0, 20.0));
+        let content = "Hello, world!";
+
+        let mut state = State::<()>::default();
+        let mut clipboard = Clipboard::default();
+
+        let mut update = |state: &mut State<()>, event, cursor| {
+            update(
+                state,
+                event,
+                Layout::new(&node),
+                cursor,
+                &mut clipboard,
+                content,
+            )
+        };
+
+        let press =
+            || Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+
+        let copy = || {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character("c".into()),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::COMMAND,
+                text: None,
+            })
+        };
+
+        let inside = mouse::Cursor::Available(Point::new(10.0, 10.0));
+        let outside = mouse::Cursor::Available(Point::new(10.0, 50.0));
+        let hello = Some(Selection { anchor: 0, head: 5 });
+
+        state.selection = hello;
+        assert_eq!(update(&mut state, copy(), inside), event::Status::Ignored);
+
+        let _ = update(&mut state, press(), inside);
+        assert!(state.is_focused);
+
+        state.selection = hello;
+        assert_eq!(update(&mut state, copy(), inside), event::Status::Captured);
+
+        let _ = update(&mut state, press(), outside);
+        assert!(!state.is_focused);
+        assert_eq!(state.selection, None);
+
+        assert_eq!(clipboard.0.as_deref(), Some("Hello"));
+    }
 }
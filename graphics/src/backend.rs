@@ -1,4 +1,5 @@
 //! Write a graphics backend.
+use crate::core::font;
 use crate::core::image;
 use crate::core::svg;
 use crate::core::Size;
@@ -16,13 +17,19 @@ pub trait Backend {
 /// A graphics backend that supports text rendering.
 pub trait Text {
     /// Loads a font from its bytes.
-    fn load_font(&mut self, font: Cow<'static, [u8]>);
+    fn load_font(
+        &mut self,
+        font: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error>;
 }
 
 /// A graphics backend that supports image rendering.
 pub trait Image {
     /// Returns the dimensions of the provided image.
     fn dimensions(&self, handle: &image::Handle) -> Size<u32>;
+
+    /// Returns whether the provided image is still being decoded.
+    fn is_loading(&self, handle: &image::Handle) -> bool;
 }
 
 /// A graphics backend that supports SVG rendering.
@@ -92,9 +92,13 @@ impl<T: Damage> Damage for Primitive<T> {
 
                 bounds_with_shadow.expand(1.0)
             }
-            Self::Quad { bounds, .. }
-            | Self::Image { bounds, .. }
-            | Self::Svg { bounds, .. } => bounds.expand(1.0),
+            Self::Quad { bounds, .. } => bounds.expand(1.0),
+            Self::Image {
+                bounds, rotation, ..
+            }
+            | Self::Svg {
+                bounds, rotation, ..
+            } => bounds.rotate(*rotation).expand(1.0),
             Self::Clip { bounds, .. } => bounds.expand(1.0),
             Self::Group { primitives } => primitives
                 .iter()
@@ -2,21 +2,26 @@
 //!
 //! For a gradient that you can use as a background variant for a widget, see [`Gradient`].
 use crate::color;
-use crate::core::gradient::ColorStop;
-use crate::core::{self, Color, Point, Rectangle};
+use crate::core::gradient::{ColorStop, MAX_STOPS};
+use crate::core::{self, Color, Point, Radians, Rectangle};
 
 use bytemuck::{Pod, Zeroable};
 use half::f16;
-use std::cmp::Ordering;
 
 #[derive(Debug, Clone, PartialEq)]
-/// A fill which linearly interpolates colors along a direction.
+/// A fill which interpolates colors along a direction, outwards from a center or
+/// around a center.
 ///
 /// For a gradient which can be used as a fill for a background of a widget, see [`crate::core::Gradient`].
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction from its `start` to its `end`
     /// point.
     Linear(Linear),
+    /// A radial gradient interpolates colors from its `center` outwards until its `radius`.
+    Radial(Radial),
+    /// A conic gradient interpolates colors clockwise around its `center`, starting at its
+    /// `angle`.
+    Conic(Conic),
 }
 
 impl From<Linear> for Gradient {
@@ -25,11 +30,25 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 impl Gradient {
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
         match self {
             Gradient::Linear(linear) => linear.pack(),
+            Gradient::Radial(radial) => radial.pack(),
+            Gradient::Conic(conic) => conic.pack(),
         }
     }
 }
@@ -44,7 +63,7 @@ pub struct Linear {
     pub end: Point,
 
     /// [`ColorStop`]s along the linear gradient direction.
-    pub stops: [Option<ColorStop>; 8],
+    pub stops: [Option<ColorStop>; MAX_STOPS],
 }
 
 impl Linear {
@@ -53,53 +72,119 @@ impl Linear {
         Self {
             start,
             end,
-            stops: [None; 8],
+            stops: [None; MAX_STOPS],
         }
     }
 
-    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
-    ///
-    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
-    ///
-    /// Any stop added after the 8th will be silently ignored.
-    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) =
-                self.stops.binary_search_by(|stop| match stop {
-                    None => Ordering::Greater,
-                    Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-                });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
-        };
-
-        self
+    core::color_stops!();
+
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        Packed::new(
+            &self.stops,
+            [self.start.x, self.start.y, self.end.x, self.end.y],
+            Packed::LINEAR,
+        )
     }
+}
 
-    /// Adds multiple [`ColorStop`]s to the gradient.
-    ///
-    /// Any stop added after the 8th will be silently ignored.
-    pub fn add_stops(
-        mut self,
-        stops: impl IntoIterator<Item = ColorStop>,
-    ) -> Self {
-        for stop in stops {
-            self = self.add_stop(stop.offset, stop.color);
+/// A radial gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The absolute center of the gradient.
+    pub center: Point,
+
+    /// The radius of the gradient.
+    pub radius: f32,
+
+    /// [`ColorStop`]s along the radius of the gradient.
+    pub stops: [Option<ColorStop>; MAX_STOPS],
+}
+
+impl Radial {
+    /// Creates a new [`Radial`] builder.
+    pub fn new(center: Point, radius: f32) -> Self {
+        Self {
+            center,
+            radius,
+            stops: [None; MAX_STOPS],
         }
+    }
+
+    core::color_stops!();
+
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        Packed::new(
+            &self.stops,
+            [self.center.x, self.center.y, self.radius, 0.0],
+            Packed::RADIAL,
+        )
+    }
+}
+
+/// A conic gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The absolute center of the gradient.
+    pub center: Point,
+
+    /// The angle where the gradient starts, measured clockwise from the top.
+    pub angle: Radians,
+
+    /// [`ColorStop`]s around the center of the gradient.
+    pub stops: [Option<ColorStop>; MAX_STOPS],
+}
 
-        self
+impl Conic {
+    /// Creates a new [`Conic`] builder.
+    pub fn new(center: Point, angle: impl Into<Radians>) -> Self {
+        Self {
+            center,
+            angle: angle.into(),
+            stops: [None; MAX_STOPS],
+        }
     }
 
+    core::color_stops!();
+
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
-        let mut colors = [[0u32; 2]; 8];
-        let mut offsets = [f16::from(0u8); 8];
+        Packed::new(
+            &self.stops,
+            [self.center.x, self.center.y, self.angle.0, 0.0],
+            Packed::CONIC,
+        )
+    }
+}
+
+/// Packed [`Gradient`] data for use in shader code.
+#[derive(Debug, Copy, Clone, PartialEq, Zeroable, Pod)]
+#[repr(C)]
+pub struct Packed {
+    // 16 colors, each channel = 16 bit float, 2 colors packed into 1 u32
+    colors: [[u32; 2]; MAX_STOPS],
+    // 16 offsets, 16x 16 bit floats packed into 8 u32s
+    offsets: [u32; MAX_STOPS / 2],
+    // Linear: start & end, radial: center & radius, conic: center & angle
+    direction: [f32; 4],
+    kind: u32,
+}
 
-        for (index, stop) in self.stops.iter().enumerate() {
+impl Packed {
+    const LINEAR: u32 = 0;
+    const RADIAL: u32 = 1;
+    const CONIC: u32 = 2;
+
+    fn new(
+        stops: &[Option<ColorStop>; MAX_STOPS],
+        direction: [f32; 4],
+        kind: u32,
+    ) -> Self {
+        let mut colors = [[0u32; 2]; MAX_STOPS];
+        let mut offsets = [f16::from(0u8); MAX_STOPS];
+
+        for (index, stop) in stops.iter().enumerate() {
             let [r, g, b, a] =
                 color::pack(stop.map_or(Color::default(), |s| s.color))
                     .components();
@@ -113,71 +198,50 @@ impl Linear {
                 stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
         }
 
-        let offsets = [
-            pack_f16s([offsets[0], offsets[1]]),
-            pack_f16s([offsets[2], offsets[3]]),
-            pack_f16s([offsets[4], offsets[5]]),
-            pack_f16s([offsets[6], offsets[7]]),
-        ];
+        let mut packed_offsets = [0u32; MAX_STOPS / 2];
 
-        let direction = [self.start.x, self.start.y, self.end.x, self.end.y];
+        for (packed, pair) in packed_offsets.iter_mut().zip(offsets.chunks(2)) {
+            *packed = pack_f16s([pair[0], pair[1]]);
+        }
 
-        Packed {
+        Self {
             colors,
-            offsets,
+            offsets: packed_offsets,
             direction,
+            kind,
         }
     }
 }
 
-/// Packed [`Gradient`] data for use in shader code.
-#[derive(Debug, Copy, Clone, PartialEq, Zeroable, Pod)]
-#[repr(C)]
-pub struct Packed {
-    // 8 colors, each channel = 16 bit float, 2 colors packed into 1 u32
-    colors: [[u32; 2]; 8],
-    // 8 offsets, 8x 16 bit floats packed into 4 u32s
-    offsets: [u32; 4],
-    direction: [f32; 4],
-}
-
 /// Creates a new [`Packed`] gradient for use in shader code.
 pub fn pack(gradient: &core::Gradient, bounds: Rectangle) -> Packed {
     match gradient {
         core::Gradient::Linear(linear) => {
-            let mut colors = [[0u32; 2]; 8];
-            let mut offsets = [f16::from(0u8); 8];
-
-            for (index, stop) in linear.stops.iter().enumerate() {
-                let [r, g, b, a] =
-                    color::pack(stop.map_or(Color::default(), |s| s.color))
-                        .components();
-
-                colors[index] = [
-                    pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                    pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-                ];
-
-                offsets[index] = stop
-                    .map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
-            }
-
-            let offsets = [
-                pack_f16s([offsets[0], offsets[1]]),
-                pack_f16s([offsets[2], offsets[3]]),
-                pack_f16s([offsets[4], offsets[5]]),
-                pack_f16s([offsets[6], offsets[7]]),
-            ];
-
             let (start, end) = linear.angle.to_distance(&bounds);
 
-            let direction = [start.x, start.y, end.x, end.y];
+            Packed::new(
+                &linear.stops,
+                [start.x, start.y, end.x, end.y],
+                Packed::LINEAR,
+            )
+        }
+        core::Gradient::Radial(radial) => {
+            let (center, radius) = radial.to_absolute(&bounds);
+
+            Packed::new(
+                &radial.stops,
+                [center.x, center.y, radius, 0.0],
+                Packed::RADIAL,
+            )
+        }
+        core::Gradient::Conic(conic) => {
+            let center = conic.to_absolute(&bounds);
 
-            Packed {
-                colors,
-                offsets,
-                direction,
-            }
+            Packed::new(
+                &conic.stops,
+                [center.x, center.y, conic.angle.0, 0.0],
+                Packed::CONIC,
+            )
         }
     }
 }
@@ -189,3 +253,41 @@ fn pack_f16s(f: [f16; 2]) -> u32 {
 
     one | two
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Size;
+
+    #[test]
+    fn radial_gradients_are_packed_with_their_center_and_radius() {
+        let packed = Radial::new(Point::new(10.0, 20.0), 5.0)
+            .add_stop(0.0, Color::BLACK)
+            .add_stop(1.0, Color::WHITE)
+            .pack();
+
+        assert_eq!(packed.kind, Packed::RADIAL);
+        assert_eq!(packed.direction, [10.0, 20.0, 5.0, 0.0]);
+        assert_eq!(
+            packed.offsets[0],
+            pack_f16s([f16::from_f32(0.0), f16::from_f32(1.0)])
+        );
+        assert_eq!(
+            packed.offsets[1],
+            pack_f16s([f16::from_f32(2.0), f16::from_f32(2.0)])
+        );
+
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(60.0, 80.0));
+        let packed = pack(
+            &core::Gradient::Radial(
+                core::gradient::Radial::new(1.0)
+                    .center(Point::new(0.25, 0.75))
+                    .add_stop(0.0, Color::BLACK),
+            ),
+            bounds,
+        );
+
+        assert_eq!(packed.kind, Packed::RADIAL);
+        assert_eq!(packed.direction, [15.0, 60.0, 75.0, 0.0]);
+    }
+}
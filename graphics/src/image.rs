@@ -1,5 +1,8 @@
 //! Load and operate on images.
-use crate::core::image::{Data, Handle};
+pub mod decoder;
+
+use crate::core::image::{Animation, Data, Frame, Handle};
+use crate::core::time::Duration;
 
 use bitflags::bitflags;
 
@@ -50,6 +53,75 @@ pub fn load(handle: &Handle) -> image_rs::ImageResult<image_rs::DynamicImage> {
     }
 }
 
+/// Tries to load all the frames of an animated image by its [`Handle`].
+///
+/// Animated GIF, APNG, and WebP images are decoded frame by frame, along with
+/// their delays. Any other image is loaded as an [`Animation`] with a single
+/// frame.
+pub fn load_animation(handle: &Handle) -> image_rs::ImageResult<Animation> {
+    use image_rs::codecs::{gif, png, webp};
+    use image_rs::AnimationDecoder;
+
+    let bytes = match handle.data() {
+        Data::Path(path) => std::fs::read(path)?,
+        Data::Bytes(bytes) => bytes.to_vec(),
+        Data::Rgba { .. } => return single_frame(handle),
+    };
+
+    let cursor = std::io::Cursor::new(bytes.as_slice());
+
+    let frames = match image_rs::guess_format(&bytes)? {
+        image_rs::ImageFormat::Gif => {
+            gif::GifDecoder::new(cursor)?.into_frames()
+        }
+        image_rs::ImageFormat::Png => {
+            let decoder = png::PngDecoder::new(cursor)?;
+
+            if !decoder.is_apng() {
+                return single_frame(handle);
+            }
+
+            decoder.apng().into_frames()
+        }
+        image_rs::ImageFormat::WebP => {
+            let decoder = webp::WebPDecoder::new(cursor)?;
+
+            if !decoder.has_animation() {
+                return single_frame(handle);
+            }
+
+            decoder.into_frames()
+        }
+        _ => return single_frame(handle),
+    };
+
+    let frames = frames
+        .map(|frame| {
+            let frame = frame?;
+            let delay = Duration::from(frame.delay());
+            let buffer = frame.into_buffer();
+
+            Ok(Frame {
+                handle: Handle::from_pixels(
+                    buffer.width(),
+                    buffer.height(),
+                    buffer.into_raw(),
+                ),
+                delay,
+            })
+        })
+        .collect::<image_rs::ImageResult<Vec<_>>>()?;
+
+    Ok(Animation::new(frames))
+}
+
+fn single_frame(handle: &Handle) -> image_rs::ImageResult<Animation> {
+    Ok(Animation::new([Frame {
+        handle: handle.clone(),
+        delay: Duration::ZERO,
+    }]))
+}
+
 bitflags! {
     struct Operation: u8 {
         const FLIP_HORIZONTALLY = 0b001;
@@ -0,0 +1,440 @@
+//! Decode raster images on a pool of background threads.
+//!
+//! Decoded images are kept in a shared cache bounded by a memory budget,
+//! evicting the least recently used images first. The budget only accounts
+//! for the decoded pixels kept here; the copies uploaded or cached by each
+//! renderer are not included.
+use crate::core::image::{Data, Handle};
+use crate::core::Size;
+use crate::image::{self, image_rs};
+
+use iced_futures::futures::channel::oneshot;
+use iced_futures::futures::{Future, FutureExt};
+use once_cell::sync::Lazy;
+use rustc_hash::FxHashMap;
+
+use std::sync::{Arc, Mutex};
+use std::task::Poll;
+
+/// The default memory budget of the decoded image cache, in bytes.
+pub const DEFAULT_LIMIT: usize = 256 * 1024 * 1024;
+
+/// The amount of images that must finish decoding before an image that was
+/// never requested after being decoded can be evicted.
+const PINNED_DECODES: u64 = 32;
+
+/// The pixels of a decoded image, in RGBA format.
+pub type Decoded = Arc<image_rs::RgbaImage>;
+
+/// An error produced when decoding an image.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The image could not be read.
+    #[error("the image could not be found")]
+    NotFound,
+    /// The image data could not be decoded.
+    #[error("the image data is invalid: {0}")]
+    Invalid(String),
+}
+
+impl From<image_rs::ImageError> for Error {
+    fn from(error: image_rs::ImageError) -> Self {
+        match error {
+            image_rs::ImageError::IoError(_) => Self::NotFound,
+            error => Self::Invalid(error.to_string()),
+        }
+    }
+}
+
+/// Returns the decoded image of the given [`Handle`], if ready.
+///
+/// If the image is not being decoded yet, it is scheduled for decoding in the
+/// background and [`Poll::Pending`] is returned. Images given as pixels are
+/// converted once and available immediately.
+pub fn poll(handle: &Handle) -> Poll<Result<Decoded, Error>> {
+    request(handle, None)
+}
+
+/// Decodes the image of the given [`Handle`] in the background, resolving
+/// once it is ready.
+///
+/// The decoded image is stored in the shared cache, so renderers can draw it
+/// right away.
+pub fn decode(
+    handle: &Handle,
+) -> impl Future<Output = Result<Decoded, Error>> + Send + 'static {
+    let (sender, receiver) = oneshot::channel();
+
+    let _ = request(handle, Some(sender));
+
+    receiver.map(|result| {
+        result.unwrap_or_else(|_| {
+            Err(Error::Invalid(String::from("decoding was cancelled")))
+        })
+    })
+}
+
+fn request(
+    handle: &Handle,
+    waiter: Option<oneshot::Sender<Result<Decoded, Error>>>,
+) -> Poll<Result<Decoded, Error>> {
+    let ready = |waiter: Option<oneshot::Sender<_>>, result: Result<_, _>| {
+        if let Some(waiter) = waiter {
+            let _ = waiter.send(Clone::clone(&result));
+        }
+
+        Poll::Ready(result)
+    };
+
+    let mut cache = CACHE.lock().expect("Lock decoded image cache");
+
+    match cache.get_mut(handle.id()) {
+        Some(Entry::Loaded { result, .. }) => ready(waiter, result.clone()),
+        Some(Entry::Loading { waiters }) => {
+            waiters.extend(waiter);
+
+            Poll::Pending
+        }
+        None if matches!(handle.data(), Data::Rgba { .. }) => {
+            let result = decode_now(handle);
+
+            cache.finish(handle.id(), result.clone());
+            let _ = cache.get_mut(handle.id());
+
+            ready(waiter, result)
+        }
+        None => {
+            let _ = cache.entries.insert(
+                handle.id(),
+                Entry::Loading {
+                    waiters: waiter.into_iter().collect(),
+                },
+            );
+
+            drop(cache);
+            schedule(handle.clone());
+
+            Poll::Pending
+        }
+    }
+}
+
+/// Returns the dimensions of the image of the given [`Handle`], if known.
+///
+/// Only the header of the image is read, so this is cheap to call while the
+/// image is still being decoded.
+pub fn dimensions(handle: &Handle) -> Option<Size<u32>> {
+    if let Data::Rgba { width, height, .. } = handle.data() {
+        return Some(Size::new(*width, *height));
+    }
+
+    if let Some(size) = CACHE
+        .lock()
+        .expect("Lock decoded image cache")
+        .sizes
+        .get(&handle.id())
+    {
+        return *size;
+    }
+
+    let size = match handle.data() {
+        Data::Path(path) => image_rs::io::Reader::open(path)
+            .and_then(image_rs::io::Reader::with_guessed_format)
+            .ok()
+            .and_then(|reader| reader.into_dimensions().ok()),
+        Data::Bytes(bytes) => {
+            image_rs::io::Reader::new(std::io::Cursor::new(bytes.as_ref()))
+                .with_guessed_format()
+                .ok()
+                .and_then(|reader| reader.into_dimensions().ok())
+        }
+        Data::Rgba { .. } => None,
+    }
+    .map(|(width, height)| Size::new(width, height));
+
+    let _ = CACHE
+        .lock()
+        .expect("Lock decoded image cache")
+        .sizes
+        .insert(handle.id(), size);
+
+    size
+}
+
+/// Sets the memory budget of the decoded image cache, in bytes.
+///
+/// The least recently used images are evicted once the budget is exceeded.
+/// Images that have not been requested since they were decoded are kept
+/// until a few other images are decoded. Renderer caches are not part of this
+/// budget. Defaults to [`DEFAULT_LIMIT`].
+pub fn set_limit(bytes: usize) {
+    let mut cache = CACHE.lock().expect("Lock decoded image cache");
+
+    cache.limit = bytes;
+    cache.evict();
+}
+
+fn decode_now(handle: &Handle) -> Result<Decoded, Error> {
+    Ok(Arc::new(image::load(handle)?.into_rgba8()))
+}
+
+static CACHE: Lazy<Mutex<Cache>> = Lazy::new(|| Mutex::new(Cache::default()));
+
+enum Entry {
+    Loading {
+        waiters: Vec<oneshot::Sender<Result<Decoded, Error>>>,
+    },
+    Loaded {
+        result: Result<Decoded, Error>,
+        /// When the image was last requested, if ever since it was decoded.
+        last_used: Option<u64>,
+        /// The amount of images decoded before this one.
+        generation: u64,
+    },
+}
+
+struct Cache {
+    entries: FxHashMap<u64, Entry>,
+    sizes: FxHashMap<u64, Option<Size<u32>>>,
+    bytes: usize,
+    limit: usize,
+    clock: u64,
+    decodes: u64,
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            sizes: FxHashMap::default(),
+            bytes: 0,
+            limit: DEFAULT_LIMIT,
+            clock: 0,
+            decodes: 0,
+        }
+    }
+}
+
+impl Cache {
+    fn get_mut(&mut self, id: u64) -> Option<&mut Entry> {
+        self.clock += 1;
+
+        let entry = self.entries.get_mut(&id)?;
+
+        if let Entry::Loaded { last_used, .. } = entry {
+            *last_used = Some(self.clock);
+        }
+
+        Some(entry)
+    }
+
+    fn finish(&mut self, id: u64, result: Result<Decoded, Error>) {
+        if let Ok(image) = &result {
+            let _ = self
+                .sizes
+                .insert(id, Some(Size::new(image.width(), image.height())));
+        }
+
+        self.bytes += cost(&result);
+
+        let previous = self.entries.insert(
+            id,
+            Entry::Loaded {
+                result: result.clone(),
+                last_used: None,
+                generation: self.decodes,
+            },
+        );
+
+        self.decodes += 1;
+
+        if let Some(Entry::Loaded { result, .. }) = &previous {
+            self.bytes -= cost(result);
+        }
+
+        if let Some(Entry::Loading { waiters }) = previous {
+            for waiter in waiters {
+                let _ = waiter.send(result.clone());
+            }
+        }
+
+        self.evict();
+    }
+
+    /// Evicts the least recently used images until the cache fits its budget.
+    ///
+    /// Images that have not been requested since they were decoded are
+    /// pinned until [`PINNED_DECODES`] other images are decoded, so that
+    /// waiting for an image larger than the budget—like headless rendering
+    /// does—can not evict it before it is drawn. Expired pins are evicted
+    /// first.
+    fn evict(&mut self) {
+        let mut is_evicting = false;
+
+        while self.bytes > self.limit {
+            let Some((id, bytes)) = self
+                .entries
+                .iter()
+                .filter_map(|(id, entry)| match entry {
+                    Entry::Loaded {
+                        result,
+                        last_used,
+                        generation,
+                    } => {
+                        let rank = match last_used {
+                            Some(last_used) => *last_used,
+                            None if self.decodes
+                                > generation + PINNED_DECODES =>
+                            {
+                                0
+                            }
+                            None => return None,
+                        };
+
+                        Some((*id, cost(result), rank))
+                    }
+                    Entry::Loading { .. } => None,
+                })
+                .min_by_key(|(_, _, rank)| *rank)
+                .map(|(id, bytes, _)| (id, bytes))
+            else {
+                break;
+            };
+
+            let _ = self.entries.remove(&id);
+            self.bytes -= bytes;
+
+            is_evicting = true;
+        }
+
+        // Dimensions are only kept for images that are still around
+        if is_evicting {
+            let entries = &self.entries;
+
+            self.sizes.retain(|id, _| entries.contains_key(id));
+        }
+    }
+}
+
+/// Returns the amount of bytes a decoding result takes in the cache.
+///
+/// Errors have a nominal cost, so they are evicted eventually as well.
+fn cost(result: &Result<Decoded, Error>) -> usize {
+    match result {
+        Ok(image) => image.as_raw().len(),
+        Err(_) => std::mem::size_of::<Entry>(),
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn schedule(handle: Handle) {
+    use std::sync::mpsc;
+
+    static POOL: Lazy<Mutex<mpsc::Sender<Handle>>> = Lazy::new(|| {
+        let (sender, receiver) = mpsc::channel::<Handle>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = std::thread::available_parallelism()
+            .map_or(1, |count| count.get().clamp(1, 4));
+
+        for _ in 0..workers {
+            let receiver = receiver.clone();
+
+            let _ = std::thread::Builder::new()
+                .name(String::from("iced image decoder"))
+                .spawn(move || loop {
+                    let handle = {
+                        let receiver =
+                            receiver.lock().expect("Lock decoding queue");
+
+                        match receiver.recv() {
+                            Ok(handle) => handle,
+                            Err(_) => break,
+                        }
+                    };
+
+                    let result = decode_now(&handle);
+
+                    CACHE
+                        .lock()
+                        .expect("Lock decoded image cache")
+                        .finish(handle.id(), result);
+                });
+        }
+
+        Mutex::new(sender)
+    });
+
+    let _ = POOL.lock().expect("Lock decoding queue").send(handle);
+}
+
+#[cfg(target_arch = "wasm32")]
+fn schedule(handle: Handle) {
+    let result = decode_now(&handle);
+
+    CACHE
+        .lock()
+        .expect("Lock decoded image cache")
+        .finish(handle.id(), result);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(side: u32) -> Result<Decoded, Error> {
+        Ok(Arc::new(image_rs::RgbaImage::new(side, side)))
+    }
+
+    #[test]
+    fn images_are_evicted_only_after_being_used() {
+        let mut cache = Cache {
+            limit: 4 * 4 * 4,
+            ..Cache::default()
+        };
+
+        cache.finish(0, image(8));
+        cache.finish(1, image(8));
+
+        assert!(cache.entries.contains_key(&0));
+        assert!(cache.entries.contains_key(&1));
+
+        let _ = cache.get_mut(0);
+        cache.finish(2, image(2));
+
+        assert!(!cache.entries.contains_key(&0));
+        assert!(!cache.sizes.contains_key(&0));
+        assert!(cache.entries.contains_key(&1));
+        assert_eq!(cache.bytes, 8 * 8 * 4 + 2 * 2 * 4);
+    }
+
+    #[test]
+    fn pinned_images_expire() {
+        let mut cache = Cache {
+            limit: 8 * 8 * 4 - 1,
+            ..Cache::default()
+        };
+
+        cache.finish(0, image(8));
+
+        let _ = cache.sizes.insert(1, Some(Size::new(1, 1)));
+
+        cache.finish(2, Err(Error::NotFound));
+        let _ = cache.get_mut(2);
+
+        for id in 3..PINNED_DECODES + 1 {
+            cache.finish(id, image(1));
+        }
+
+        assert!(cache.entries.contains_key(&0));
+        assert!(!cache.entries.contains_key(&2));
+        assert!(cache.bytes > cache.limit);
+
+        cache.finish(PINNED_DECODES + 1, image(1));
+
+        assert!(!cache.entries.contains_key(&0));
+        assert!(!cache.sizes.contains_key(&0));
+        assert!(!cache.sizes.contains_key(&1));
+        assert_eq!(cache.bytes, (PINNED_DECODES as usize - 1) * 4);
+    }
+}
@@ -1,10 +1,12 @@
 //! Draw using different graphical primitives.
 use crate::core::alignment;
+use crate::core::border;
 use crate::core::image;
 use crate::core::svg;
 use crate::core::text;
 use crate::core::{
-    Background, Border, Color, Font, Pixels, Point, Rectangle, Shadow, Vector,
+    Background, Border, Color, Font, Pixels, Point, Radians, Rectangle, Shadow,
+    Vector,
 };
 use crate::text::editor;
 use crate::text::paragraph;
@@ -13,6 +15,7 @@ use std::sync::Arc;
 
 /// A rendering primitive.
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
 pub enum Primitive<T> {
     /// A text primitive
     Text {
@@ -34,6 +37,8 @@ pub enum Primitive<T> {
         vertical_alignment: alignment::Vertical,
         /// The shaping strategy of the text.
         shaping: text::Shaping,
+        /// The wrapping strategy of the text.
+        wrapping: text::Wrapping,
         /// The clip bounds of the text.
         clip_bounds: Rectangle,
     },
@@ -80,6 +85,14 @@ pub enum Primitive<T> {
         filter_method: image::FilterMethod,
         /// The bounds of the image
         bounds: Rectangle,
+        /// The rotation of the image around its center
+        rotation: Radians,
+        /// The opacity of the image
+        opacity: f32,
+        /// The [`Color`] multiplied with the pixels of the image
+        tint: Option<Color>,
+        /// The border radius of the image
+        border_radius: border::Radius,
     },
     /// An SVG primitive
     Svg {
@@ -91,6 +104,18 @@ pub enum Primitive<T> {
 
         /// The bounds of the viewport
         bounds: Rectangle,
+
+        /// The rotation of the SVG around its center
+        rotation: Radians,
+
+        /// The opacity of the SVG
+        opacity: f32,
+
+        /// The [`Color`] multiplied with the pixels of the SVG
+        tint: Option<Color>,
+
+        /// The border radius of the SVG
+        border_radius: border::Radius,
     },
     /// A group of primitives
     Group {
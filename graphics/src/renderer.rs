@@ -1,17 +1,20 @@
 //! Create a renderer from a [`Backend`].
 use crate::backend::{self, Backend};
 use crate::core;
+use crate::core::border;
+use crate::core::font;
 use crate::core::image;
 use crate::core::renderer;
 use crate::core::svg;
 use crate::core::text::Text;
 use crate::core::{
-    Background, Color, Font, Pixels, Point, Rectangle, Size, Vector,
+    Background, Color, Font, Pixels, Point, Radians, Rectangle, Size, Vector,
 };
 use crate::text;
 use crate::Primitive;
 
 use std::borrow::Cow;
+use std::cell::RefCell;
 
 /// A backend-agnostic renderer that supports all the built-in widgets.
 #[derive(Debug)]
@@ -20,6 +23,7 @@ pub struct Renderer<B: Backend> {
     default_font: Font,
     default_text_size: Pixels,
     primitives: Vec<Primitive<B::Primitive>>,
+    loading: RefCell<Vec<image::Handle>>,
 }
 
 impl<B: Backend> Renderer<B> {
@@ -34,6 +38,7 @@ impl<B: Backend> Renderer<B> {
             default_font,
             default_text_size,
             primitives: Vec::new(),
+            loading: RefCell::new(Vec::new()),
         }
     }
 
@@ -56,6 +61,14 @@ impl<B: Backend> Renderer<B> {
         f(&mut self.backend, &self.primitives)
     }
 
+    /// Takes the [`image::Handle`]s that were reported as loading since the
+    /// [`Renderer`] was last cleared.
+    ///
+    /// Widgets may draw a placeholder instead of these images.
+    pub fn take_loading_images(&mut self) -> Vec<image::Handle> {
+        std::mem::take(self.loading.get_mut())
+    }
+
     /// Starts recording a new layer.
     pub fn start_layer(&mut self) -> Vec<Primitive<B::Primitive>> {
         std::mem::take(&mut self.primitives)
@@ -126,6 +139,7 @@ impl<B: Backend> iced_core::Renderer for Renderer<B> {
 
     fn clear(&mut self) {
         self.primitives.clear();
+        self.loading.get_mut().clear();
     }
 }
 
@@ -149,8 +163,11 @@ where
         self.default_text_size
     }
 
-    fn load_font(&mut self, bytes: Cow<'static, [u8]>) {
-        self.backend.load_font(bytes);
+    fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        self.backend.load_font(bytes)
     }
 
     fn fill_paragraph(
@@ -200,6 +217,7 @@ where
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            wrapping: text.wrapping,
             clip_bounds,
         });
     }
@@ -215,16 +233,55 @@ where
         self.backend().dimensions(handle)
     }
 
+    fn is_loading(&self, handle: &image::Handle) -> bool {
+        let is_loading = self.backend().is_loading(handle);
+
+        if is_loading {
+            let mut loading = self.loading.borrow_mut();
+
+            if loading.iter().all(|loading| loading.id() != handle.id()) {
+                loading.push(handle.clone());
+            }
+        }
+
+        is_loading
+    }
+
     fn draw(
         &mut self,
         handle: image::Handle,
         filter_method: image::FilterMethod,
         bounds: Rectangle,
+    ) {
+        self.draw_image(
+            handle,
+            filter_method,
+            bounds,
+            Radians(0.0),
+            1.0,
+            None,
+            border::Radius::default(),
+        );
+    }
+
+    fn draw_image(
+        &mut self,
+        handle: image::Handle,
+        filter_method: image::FilterMethod,
+        bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
+        tint: Option<Color>,
+        border_radius: border::Radius,
     ) {
         self.primitives.push(Primitive::Image {
             handle,
             filter_method,
             bounds,
+            rotation,
+            opacity,
+            tint,
+            border_radius,
         });
     }
 }
@@ -242,11 +299,36 @@ where
         handle: svg::Handle,
         color: Option<Color>,
         bounds: Rectangle,
+    ) {
+        self.draw_svg(
+            handle,
+            color,
+            bounds,
+            Radians(0.0),
+            1.0,
+            None,
+            border::Radius::default(),
+        );
+    }
+
+    fn draw_svg(
+        &mut self,
+        handle: svg::Handle,
+        color: Option<Color>,
+        bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
+        tint: Option<Color>,
+        border_radius: border::Radius,
     ) {
         self.primitives.push(Primitive::Svg {
             handle,
             color,
             bounds,
+            rotation,
+            opacity,
+            tint,
+            border_radius,
         });
     }
 }
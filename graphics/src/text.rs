@@ -10,11 +10,12 @@ pub use paragraph::Paragraph;
 pub use cosmic_text;
 
 use crate::core::font::{self, Font};
-use crate::core::text::Shaping;
+use crate::core::text::{Shaping, Wrapping};
 use crate::core::{Color, Point, Rectangle, Size};
 
 use once_cell::sync::OnceCell;
 use std::borrow::Cow;
+use std::ops::Range;
 use std::sync::{Arc, RwLock, Weak};
 
 /// Returns the global [`FontSystem`].
@@ -47,12 +48,58 @@ impl FontSystem {
     }
 
     /// Loads a font from its bytes.
-    pub fn load_font(&mut self, bytes: Cow<'static, [u8]>) {
-        let _ = self.raw.db_mut().load_font_source(
+    ///
+    /// Fails if the bytes do not contain any valid font face.
+    pub fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        let ids = self.raw.db_mut().load_font_source(
             cosmic_text::fontdb::Source::Binary(Arc::new(bytes.into_owned())),
         );
 
+        if ids.is_empty() {
+            return Err(font::Error::Invalid);
+        }
+
         self.version = Version(self.version.0 + 1);
+
+        Ok(())
+    }
+
+    /// Returns the font faces available in the [`FontSystem`], sorted by
+    /// family name.
+    pub fn faces(&self) -> Vec<font::Face> {
+        let mut faces: Vec<_> = self
+            .raw
+            .db()
+            .faces()
+            .filter_map(|face| {
+                let (family, _) = face.families.first()?;
+
+                Some(font::Face {
+                    family: family.clone(),
+                    weight: from_weight(face.weight),
+                    stretch: from_stretch(face.stretch),
+                    style: from_style(face.style),
+                    monospaced: face.monospaced,
+                })
+            })
+            .collect();
+
+        let key = |face: &font::Face| {
+            (
+                face.family.clone(),
+                to_weight(face.weight).0,
+                to_stretch(face.stretch).to_number(),
+                face.style as u8,
+            )
+        };
+
+        faces.sort_by_cached_key(key);
+        faces.dedup();
+
+        faces
     }
 
     /// Returns the current [`Version`] of the [`FontSystem`].
@@ -92,20 +139,112 @@ impl PartialEq for Raw {
 
 /// Measures the dimensions of the given [`cosmic_text::Buffer`].
 pub fn measure(buffer: &cosmic_text::Buffer) -> Size {
-    let (width, total_lines) = buffer
-        .layout_runs()
-        .fold((0.0, 0usize), |(width, total_lines), run| {
-            (run.line_w.max(width), total_lines + 1)
-        });
+    let (width, height) =
+        buffer
+            .layout_runs()
+            .fold((0.0, 0.0), |(width, height), run| {
+                (run.line_w.max(width), height + run.line_height)
+            });
 
     let (max_width, max_height) = buffer.size();
 
     Size::new(
-        width.min(max_width),
-        (total_lines as f32 * buffer.metrics().line_height).min(max_height),
+        width.min(max_width.unwrap_or(f32::INFINITY)),
+        f32::min(height, max_height.unwrap_or(f32::INFINITY)),
     )
 }
 
+/// Returns the horizontal offsets produced by some letter spacing for each of
+/// the given glyphs of a [`cosmic_text::LayoutRun`].
+///
+/// Glyphs of the same cluster share the same offset.
+pub fn letter_offsets(
+    glyphs: &[cosmic_text::LayoutGlyph],
+    letter_spacing: f32,
+) -> impl Iterator<Item = f32> + '_ {
+    let mut clusters = 0;
+    let mut last_start = None;
+
+    glyphs.iter().map(move |glyph| {
+        if last_start.is_some_and(|start| start != glyph.start) {
+            clusters += 1;
+        }
+
+        last_start = Some(glyph.start);
+
+        clusters as f32 * letter_spacing
+    })
+}
+
+/// A cluster of a [`cosmic_text::Buffer`] shaped on its own, so it can be
+/// drawn at the position given by some letter spacing.
+#[derive(Debug)]
+pub struct Letter {
+    /// The buffer containing only the cluster.
+    pub buffer: cosmic_text::Buffer,
+    /// The position of the buffer relative to the original one.
+    pub position: Point,
+}
+
+/// Splits the given [`cosmic_text::Buffer`] into [`Letter`]s spaced by the
+/// given amount.
+///
+/// Each cluster keeps the position of the original layout, shifted by the
+/// [`letter_offsets`] of its run and aligned to the same baseline.
+pub fn space_letters(
+    font_system: &mut cosmic_text::FontSystem,
+    buffer: &cosmic_text::Buffer,
+    letter_spacing: f32,
+) -> Vec<Letter> {
+    let mut letters = Vec::new();
+
+    for run in buffer.layout_runs() {
+        let line = &buffer.lines[run.line_i];
+        let offsets = letter_offsets(run.glyphs, letter_spacing);
+
+        let mut glyphs = run.glyphs.iter().zip(offsets).peekable();
+
+        while let Some((first, offset)) = glyphs.next() {
+            let mut x = first.x;
+            let mut end = first.end;
+
+            while let Some((glyph, _)) =
+                glyphs.next_if(|(glyph, _)| glyph.start == first.start)
+            {
+                x = x.min(glyph.x);
+                end = end.max(glyph.end);
+            }
+
+            let Some(content) = line.text().get(first.start..end) else {
+                continue;
+            };
+
+            let mut letter =
+                cosmic_text::Buffer::new(font_system, buffer.metrics());
+
+            letter.set_text(
+                font_system,
+                content,
+                line.attrs_list().get_span(first.start),
+                cosmic_text::Shaping::Advanced,
+            );
+
+            let Some(baseline) =
+                letter.layout_runs().next().map(|letter| letter.line_y)
+            else {
+                continue;
+            };
+
+            letters.push(Letter {
+                buffer: letter,
+                position: Point::new(x + offset, run.line_y - baseline),
+            });
+        }
+    }
+
+    letters
+}
+
 /// Returns the attributes of the given [`Font`].
 pub fn to_attributes(font: Font) -> cosmic_text::Attrs<'static> {
     cosmic_text::Attrs::new()
@@ -115,6 +254,108 @@ pub fn to_attributes(font: Font) -> cosmic_text::Attrs<'static> {
         .style(to_style(font.style))
 }
 
+/// Splits the given content into spans with the attributes of the given
+/// [`Font`], honoring its fallback chain.
+///
+/// See [`fallback`] for how the font of each span is chosen.
+pub fn to_spans<'a>(
+    font_system: &mut cosmic_text::FontSystem,
+    content: &'a str,
+    font: Font,
+) -> Vec<(&'a str, cosmic_text::Attrs<'static>)> {
+    fallback(font_system, content, font)
+        .into_iter()
+        .map(|(range, attributes)| (&content[range], attributes))
+        .collect()
+}
+
+/// Splits the given content into ranges with the attributes of the given
+/// [`Font`], honoring its fallback chain.
+///
+/// Each grapheme cluster uses the first [`font::Family`] of the chain
+/// containing all of its glyphs. Clusters missing from all of them are left
+/// to the fallback fonts of the system.
+pub fn fallback(
+    font_system: &mut cosmic_text::FontSystem,
+    content: &str,
+    font: Font,
+) -> Vec<(Range<usize>, cosmic_text::Attrs<'static>)> {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let attributes = to_attributes(font);
+
+    if font.fallback.is_empty() {
+        return vec![(0..content.len(), attributes)];
+    }
+
+    let chain: Vec<_> = std::iter::once(font.family)
+        .chain(font.fallback.iter().copied())
+        .filter_map(|family| {
+            let id = font_system.db().query(&cosmic_text::fontdb::Query {
+                families: &[to_family(family)],
+                weight: attributes.weight,
+                stretch: attributes.stretch,
+                style: attributes.style,
+            })?;
+
+            Some((family, font_system.get_font(id)?))
+        })
+        .collect();
+
+    let with_family =
+        |family: font::Family| attributes.family(to_family(family));
+
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut current = None;
+
+    for (i, cluster) in content.grapheme_indices(true) {
+        // Joiners and variation selectors are shaped along with the
+        // characters they modify, so they do not need a glyph of their own
+        let mut chars = cluster.chars().filter(|c| {
+            !c.is_whitespace()
+                && !c.is_control()
+                && *c != '\u{200D}'
+                && !('\u{FE00}'..='\u{FE0F}').contains(c)
+                && !('\u{E0100}'..='\u{E01EF}').contains(c)
+        });
+
+        let Some(first) = chars.next() else {
+            continue;
+        };
+
+        let has_glyph = |face: &cosmic_text::Font, c: char| {
+            face.rustybuzz().glyph_index(c).is_some()
+        };
+
+        let family = chain
+            .iter()
+            .find(|(_, face)| {
+                has_glyph(face, first)
+                    && chars.clone().all(|c| has_glyph(face, c))
+            })
+            .or_else(|| chain.iter().find(|(_, face)| has_glyph(face, first)))
+            .map_or(font.family, |(family, _)| *family);
+
+        match current {
+            Some(current) if current != family => {
+                ranges.push((start..i, with_family(current)));
+                start = i;
+            }
+            _ => {}
+        }
+
+        current = Some(family);
+    }
+
+    ranges.push((
+        start..content.len(),
+        with_family(current.unwrap_or(font.family)),
+    ));
+
+    ranges
+}
+
 fn to_family(family: font::Family) -> cosmic_text::Family<'static> {
     match family {
         font::Family::Name(name) => cosmic_text::Family::Name(name),
@@ -162,6 +403,42 @@ fn to_style(style: font::Style) -> cosmic_text::Style {
     }
 }
 
+fn from_weight(weight: cosmic_text::Weight) -> font::Weight {
+    match weight.0 {
+        0..=149 => font::Weight::Thin,
+        150..=249 => font::Weight::ExtraLight,
+        250..=349 => font::Weight::Light,
+        350..=449 => font::Weight::Normal,
+        450..=549 => font::Weight::Medium,
+        550..=649 => font::Weight::Semibold,
+        650..=749 => font::Weight::Bold,
+        750..=849 => font::Weight::ExtraBold,
+        _ => font::Weight::Black,
+    }
+}
+
+fn from_stretch(stretch: cosmic_text::Stretch) -> font::Stretch {
+    match stretch {
+        cosmic_text::Stretch::UltraCondensed => font::Stretch::UltraCondensed,
+        cosmic_text::Stretch::ExtraCondensed => font::Stretch::ExtraCondensed,
+        cosmic_text::Stretch::Condensed => font::Stretch::Condensed,
+        cosmic_text::Stretch::SemiCondensed => font::Stretch::SemiCondensed,
+        cosmic_text::Stretch::Normal => font::Stretch::Normal,
+        cosmic_text::Stretch::SemiExpanded => font::Stretch::SemiExpanded,
+        cosmic_text::Stretch::Expanded => font::Stretch::Expanded,
+        cosmic_text::Stretch::ExtraExpanded => font::Stretch::ExtraExpanded,
+        cosmic_text::Stretch::UltraExpanded => font::Stretch::UltraExpanded,
+    }
+}
+
+fn from_style(style: cosmic_text::Style) -> font::Style {
+    match style {
+        cosmic_text::Style::Normal => font::Style::Normal,
+        cosmic_text::Style::Italic => font::Style::Italic,
+        cosmic_text::Style::Oblique => font::Style::Oblique,
+    }
+}
+
 /// Converts some [`Shaping`] strategy to a [`cosmic_text::Shaping`] strategy.
 pub fn to_shaping(shaping: Shaping) -> cosmic_text::Shaping {
     match shaping {
@@ -170,6 +447,16 @@ pub fn to_shaping(shaping: Shaping) -> cosmic_text::Shaping {
     }
 }
 
+/// Converts some [`Wrapping`] strategy to a [`cosmic_text::Wrap`] strategy.
+pub fn to_wrap(wrapping: Wrapping) -> cosmic_text::Wrap {
+    match wrapping {
+        Wrapping::None => cosmic_text::Wrap::None,
+        Wrapping::Word => cosmic_text::Wrap::Word,
+        Wrapping::Glyph => cosmic_text::Wrap::Glyph,
+        Wrapping::WordOrGlyph => cosmic_text::Wrap::WordOrGlyph,
+    }
+}
+
 /// Converts some [`Color`] to a [`cosmic_text::Color`].
 pub fn to_color(color: Color) -> cosmic_text::Color {
     let [r, g, b, a] = color.into_rgba8();
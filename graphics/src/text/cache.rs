@@ -50,14 +50,17 @@ impl Cache {
             );
             let mut buffer = cosmic_text::Buffer::new(font_system, metrics);
 
+            buffer.set_wrap(font_system, text::to_wrap(key.wrapping));
             buffer.set_size(
                 font_system,
-                key.bounds.width,
-                key.bounds.height.max(key.line_height),
+                Some(key.bounds.width),
+                Some(key.bounds.height.max(key.line_height)),
             );
-            buffer.set_text(
+            let spans = text::to_spans(font_system, key.content, key.font);
+
+            buffer.set_rich_text(
                 font_system,
-                key.content,
+                spans,
                 text::to_attributes(key.font),
                 text::to_shaping(key.shaping),
             );
@@ -118,6 +121,8 @@ pub struct Key<'a> {
     pub bounds: Size,
     /// The shaping strategy of the text.
     pub shaping: text::Shaping,
+    /// The wrapping strategy of the text.
+    pub wrapping: text::Wrapping,
 }
 
 impl Key<'_> {
@@ -129,6 +134,7 @@ impl Key<'_> {
         self.bounds.width.to_bits().hash(&mut hasher);
         self.bounds.height.to_bits().hash(&mut hasher);
         self.shaping.hash(&mut hasher);
+        self.wrapping.hash(&mut hasher);
 
         hasher.finish()
     }
@@ -3,7 +3,7 @@ use crate::core::text::editor::{
     self, Action, Cursor, Direction, Edit, Motion,
 };
 use crate::core::text::highlighter::{self, Highlighter};
-use crate::core::text::LineHeight;
+use crate::core::text::{LineHeight, Wrapping};
 use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
@@ -17,11 +17,20 @@ use std::sync::{self, Arc};
 pub struct Editor(Option<Arc<Internal>>);
 
 struct Internal {
-    editor: cosmic_text::Editor,
+    editor: cosmic_text::Editor<'static>,
     font: Font,
+    wrapping: Wrapping,
     bounds: Size,
     topmost_line_changed: Option<usize>,
     version: text::Version,
+    preedit: Option<Preedit>,
+}
+
+/// The text being composed by an input method, laid out inline at the
+/// cursor of an [`Editor`].
+struct Preedit {
+    content: String,
+    buffer: cosmic_text::Buffer,
 }
 
 impl Editor {
@@ -31,8 +40,16 @@ impl Editor {
     }
 
     /// Returns the buffer of the [`Editor`].
+    ///
+    /// If an input method is composing text, the returned buffer contains
+    /// it at the cursor.
     pub fn buffer(&self) -> &cosmic_text::Buffer {
-        self.internal().editor.buffer()
+        let internal = self.internal();
+
+        match &internal.preedit {
+            Some(preedit) => &preedit.buffer,
+            None => buffer_from_editor(&internal.editor),
+        }
     }
 
     /// Creates a [`Weak`] reference to the [`Editor`].
@@ -83,27 +100,61 @@ impl editor::Editor for Editor {
     }
 
     fn line(&self, index: usize) -> Option<&str> {
-        self.buffer()
+        buffer_from_editor(&self.internal().editor)
             .lines
             .get(index)
             .map(cosmic_text::BufferLine::text)
     }
 
     fn line_count(&self) -> usize {
-        self.buffer().lines.len()
+        buffer_from_editor(&self.internal().editor).lines.len()
     }
 
     fn selection(&self) -> Option<String> {
         self.internal().editor.copy_selection()
     }
 
+    fn selection_anchor(&self) -> Option<(usize, usize)> {
+        let selection = selection(&self.internal().editor)?;
+
+        Some((selection.line, selection.index))
+    }
+
+    fn select_range(&mut self, from: (usize, usize), to: (usize, usize)) {
+        let editor =
+            self.0.take().expect("Editor should always be initialized");
+
+        let mut internal = Arc::try_unwrap(editor)
+            .expect("Editor cannot have multiple strong references");
+
+        let editor = &mut internal.editor;
+
+        let clamp = |(line, index): (usize, usize)| {
+            let lines = &buffer_from_editor(editor).lines;
+            let line = line.min(lines.len().saturating_sub(1));
+            let index = lines
+                .get(line)
+                .map(|line| index.min(line.text().len()))
+                .unwrap_or(0);
+
+            cosmic_text::Cursor::new(line, index)
+        };
+
+        let (from, to) = (clamp(from), clamp(to));
+
+        editor.set_cursor(to);
+        set_selection(editor, if from == to { None } else { Some(from) });
+
+        self.0 = Some(Arc::new(internal));
+    }
+
     fn cursor(&self) -> editor::Cursor {
         let internal = self.internal();
 
         let cursor = internal.editor.cursor();
-        let buffer = internal.editor.buffer();
+        let buffer = buffer_from_editor(&internal.editor);
 
-        match internal.editor.select_opt() {
+        match selection(&internal.editor) {
             Some(selection) => {
                 let (start, end) = if cursor < selection {
                     (cursor, selection)
@@ -112,6 +163,7 @@ impl editor::Editor for Editor {
                 };
 
                 let line_height = buffer.metrics().line_height;
+                let scroll = buffer.scroll();
                 let selected_lines = end.line - start.line + 1;
 
                 let visual_lines_offset =
@@ -142,7 +194,8 @@ impl editor::Editor for Editor {
                                 width,
                                 y: (visual_line as i32 + visual_lines_offset)
                                     as f32
-                                    * line_height,
+                                    * line_height
+                                    - scroll.vertical,
                                 height: line_height,
                             })
                         } else {
@@ -224,7 +277,8 @@ impl editor::Editor for Editor {
                 Cursor::Caret(Point::new(
                     offset,
                     (visual_lines_offset + visual_line as i32) as f32
-                        * line_height,
+                        * line_height
+                        - buffer.scroll().vertical,
                 ))
             }
         }
@@ -252,7 +306,7 @@ impl editor::Editor for Editor {
         match action {
             // Motion events
             Action::Move(motion) => {
-                if let Some(selection) = editor.select_opt() {
+                if let Some(selection) = selection(editor) {
                     let cursor = editor.cursor();
 
                     let (left, right) = if cursor < selection {
@@ -261,7 +315,7 @@ impl editor::Editor for Editor {
                         (selection, cursor)
                     };
 
-                    editor.set_select_opt(None);
+                    set_selection(editor, None);
 
                     match motion {
                         // These motions are performed as-is even when a selection
@@ -290,20 +344,20 @@ impl editor::Editor for Editor {
             Action::Select(motion) => {
                 let cursor = editor.cursor();
 
-                if editor.select_opt().is_none() {
-                    editor.set_select_opt(Some(cursor));
+                if selection(editor).is_none() {
+                    set_selection(editor, Some(cursor));
                 }
 
                 editor.action(font_system.raw(), motion_to_action(motion));
 
                 // Deselect if selection matches cursor position
-                if let Some(selection) = editor.select_opt() {
+                if let Some(selection) = selection(editor) {
                     let cursor = editor.cursor();
 
                     if cursor.line == selection.line
                         && cursor.index == selection.index
                     {
-                        editor.set_select_opt(None);
+                        set_selection(editor, None);
                     }
                 }
             }
@@ -312,7 +366,9 @@ impl editor::Editor for Editor {
 
                 let cursor = editor.cursor();
 
-                if let Some(line) = editor.buffer().lines.get(cursor.line) {
+                if let Some(line) =
+                    buffer_from_editor(editor).lines.get(cursor.line)
+                {
                     let (start, end) =
                         UnicodeSegmentation::unicode_word_indices(line.text())
                             // Split words with dots
@@ -360,18 +416,20 @@ impl editor::Editor for Editor {
                             ..cursor
                         });
 
-                        editor.set_select_opt(Some(cosmic_text::Cursor {
-                            index: end,
-                            ..cursor
-                        }));
+                        set_selection(
+                            editor,
+                            Some(cosmic_text::Cursor {
+                                index: end,
+                                ..cursor
+                            }),
+                        );
                     }
                 }
             }
             Action::SelectLine => {
                 let cursor = editor.cursor();
 
-                if let Some(line_length) = editor
-                    .buffer()
+                if let Some(line_length) = buffer_from_editor(editor)
                     .lines
                     .get(cursor.line)
                     .map(|line| line.text().len())
@@ -379,10 +437,13 @@ impl editor::Editor for Editor {
                     editor
                         .set_cursor(cosmic_text::Cursor { index: 0, ..cursor });
 
-                    editor.set_select_opt(Some(cosmic_text::Cursor {
-                        index: line_length,
-                        ..cursor
-                    }));
+                    set_selection(
+                        editor,
+                        Some(cosmic_text::Cursor {
+                            index: line_length,
+                            ..cursor
+                        }),
+                    );
                 }
             }
 
@@ -419,7 +480,7 @@ impl editor::Editor for Editor {
                 }
 
                 let cursor = editor.cursor();
-                let selection = editor.select_opt().unwrap_or(cursor);
+                let selection = selection(editor).unwrap_or(cursor);
 
                 internal.topmost_line_changed =
                     Some(cursor.min(selection).line);
@@ -445,13 +506,13 @@ impl editor::Editor for Editor {
                 );
 
                 // Deselect if selection matches cursor position
-                if let Some(selection) = editor.select_opt() {
+                if let Some(selection) = selection(editor) {
                     let cursor = editor.cursor();
 
                     if cursor.line == selection.line
                         && cursor.index == selection.index
                     {
-                        editor.set_select_opt(None);
+                        set_selection(editor, None);
                     }
                 }
             }
@@ -461,8 +522,14 @@ impl editor::Editor for Editor {
                     cosmic_text::Action::Scroll { lines },
                 );
             }
+
+            // History events
+            // The edit history is kept by the owner of the `Editor`
+            Action::Undo | Action::Redo => {}
         }
 
+        lay_out_preedit(&mut internal, font_system.raw());
+
         self.0 = Some(Arc::new(internal));
     }
 
@@ -476,6 +543,7 @@ impl editor::Editor for Editor {
         new_font: Font,
         new_size: Pixels,
         new_line_height: LineHeight,
+        new_wrapping: Wrapping,
         new_highlighter: &mut impl Highlighter,
     ) {
         let editor =
@@ -490,7 +558,10 @@ impl editor::Editor for Editor {
         if font_system.version() != internal.version {
             log::trace!("Updating `FontSystem` of `Editor`...");
 
-            for line in internal.editor.buffer_mut().lines.iter_mut() {
+            for line in buffer_mut_from_editor(&mut internal.editor)
+                .lines
+                .iter_mut()
+            {
                 line.reset();
             }
 
@@ -501,7 +572,10 @@ impl editor::Editor for Editor {
         if new_font != internal.font {
             log::trace!("Updating font of `Editor`...");
 
-            for line in internal.editor.buffer_mut().lines.iter_mut() {
+            for line in buffer_mut_from_editor(&mut internal.editor)
+                .lines
+                .iter_mut()
+            {
                 let _ = line.set_attrs_list(cosmic_text::AttrsList::new(
                     text::to_attributes(new_font),
                 ));
@@ -511,7 +585,7 @@ impl editor::Editor for Editor {
             internal.topmost_line_changed = Some(0);
         }
 
-        let metrics = internal.editor.buffer().metrics();
+        let metrics = buffer_from_editor(&internal.editor).metrics();
         let new_line_height = new_line_height.to_absolute(new_size);
 
         if new_size.0 != metrics.font_size
@@ -519,7 +593,7 @@ impl editor::Editor for Editor {
         {
             log::trace!("Updating `Metrics` of `Editor`...");
 
-            internal.editor.buffer_mut().set_metrics(
+            buffer_mut_from_editor(&mut internal.editor).set_metrics(
                 font_system.raw(),
                 cosmic_text::Metrics::new(new_size.0, new_line_height.0),
             );
@@ -528,15 +602,26 @@ impl editor::Editor for Editor {
         if new_bounds != internal.bounds {
             log::trace!("Updating size of `Editor`...");
 
-            internal.editor.buffer_mut().set_size(
+            buffer_mut_from_editor(&mut internal.editor).set_size(
                 font_system.raw(),
-                new_bounds.width,
-                new_bounds.height,
+                Some(new_bounds.width),
+                Some(new_bounds.height),
             );
 
             internal.bounds = new_bounds;
         }
 
+        let new_wrap = text::to_wrap(new_wrapping);
+
+        if new_wrap != buffer_from_editor(&internal.editor).wrap() {
+            log::trace!("Updating wrapping of `Editor`...");
+
+            buffer_mut_from_editor(&mut internal.editor)
+                .set_wrap(font_system.raw(), new_wrap);
+        }
+
+        internal.wrapping = new_wrapping;
+
         if let Some(topmost_line_changed) = internal.topmost_line_changed.take()
         {
             log::trace!(
@@ -546,7 +631,31 @@ impl editor::Editor for Editor {
             new_highlighter.change_line(topmost_line_changed);
         }
 
-        internal.editor.shape_as_needed(font_system.raw());
+        internal.editor.shape_as_needed(font_system.raw(), false);
+
+        lay_out_preedit(&mut internal, font_system.raw());
+
+        self.0 = Some(Arc::new(internal));
+    }
+
+    fn set_preedit(&mut self, preedit: Option<&str>) {
+        let editor =
+            self.0.take().expect("Editor should always be initialized");
+
+        let mut internal = Arc::try_unwrap(editor)
+            .expect("Editor cannot have multiple strong references");
+
+        internal.preedit = preedit.map(|content| Preedit {
+            content: content.to_owned(),
+            buffer: cosmic_text::Buffer::new_empty(
+                buffer_from_editor(&internal.editor).metrics(),
+            ),
+        });
+
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        lay_out_preedit(&mut internal, font_system.raw());
 
         self.0 = Some(Arc::new(internal));
     }
@@ -558,12 +667,13 @@ impl editor::Editor for Editor {
         format_highlight: impl Fn(&H::Highlight) -> highlighter::Format<Self::Font>,
     ) {
         let internal = self.internal();
-        let buffer = internal.editor.buffer();
+        let buffer = buffer_from_editor(&internal.editor);
 
-        let mut window = buffer.scroll() + buffer.visible_lines();
+        let scroll = buffer.scroll();
+        let mut window = (internal.bounds.height / buffer.metrics().line_height)
+            .ceil() as i32;
 
-        let last_visible_line = buffer
-            .lines
+        let last_visible_line = buffer.lines[scroll.line..]
             .iter()
             .enumerate()
             .find_map(|(i, line)| {
@@ -577,7 +687,7 @@ impl editor::Editor for Editor {
                     window -= visible_lines;
                     None
                 } else {
-                    Some(i)
+                    Some(scroll.line + i)
                 }
             })
             .unwrap_or(buffer.lines.len().saturating_sub(1));
@@ -599,33 +709,69 @@ impl editor::Editor for Editor {
 
         let attributes = text::to_attributes(font);
 
-        for line in &mut internal.editor.buffer_mut().lines
+        for line in &mut buffer_mut_from_editor(&mut internal.editor).lines
             [current_line..=last_visible_line]
         {
-            let mut list = cosmic_text::AttrsList::new(attributes);
+            let content = line.text();
+            let mut segments = Vec::new();
+            let mut end = 0;
 
-            for (range, highlight) in highlighter.highlight_line(line.text()) {
+            for (range, highlight) in highlighter.highlight_line(content) {
                 let format = format_highlight(&highlight);
+                let start = range.start.max(end);
 
-                if format.color.is_some() || format.font.is_some() {
-                    list.add_span(
-                        range,
-                        cosmic_text::Attrs {
-                            color_opt: format.color.map(text::to_color),
-                            ..if let Some(font) = format.font {
-                                text::to_attributes(font)
-                            } else {
-                                attributes
-                            }
-                        },
-                    );
+                if start >= range.end {
+                    continue;
+                }
+
+                if start > end {
+                    segments.push((end..start, font, None));
+                }
+
+                segments.push((
+                    start..range.end,
+                    format.font.unwrap_or(font),
+                    format.color,
+                ));
+
+                end = range.end;
+            }
+
+            if end < content.len() {
+                segments.push((end..content.len(), font, None));
+            }
+
+            let mut list = cosmic_text::AttrsList::new(attributes);
+
+            for (segment, font, color) in segments {
+                let fallback = text::fallback(
+                    font_system.raw(),
+                    &content[segment.clone()],
+                    font,
+                );
+
+                for (range, attrs) in fallback {
+                    let attrs = cosmic_text::Attrs {
+                        color_opt: color.map(text::to_color),
+                        ..attrs
+                    };
+
+                    if attrs != attributes {
+                        list.add_span(
+                            segment.start + range.start
+                                ..segment.start + range.end,
+                            attrs,
+                        );
+                    }
                 }
             }
 
             let _ = line.set_attrs_list(list);
         }
 
-        internal.editor.shape_as_needed(font_system.raw());
+        internal.editor.shape_as_needed(font_system.raw(), false);
+
+        lay_out_preedit(&mut internal, font_system.raw());
 
         self.0 = Some(Arc::new(internal));
     }
@@ -640,8 +786,10 @@ impl Default for Editor {
 impl PartialEq for Internal {
     fn eq(&self, other: &Self) -> bool {
         self.font == other.font
+            && self.wrapping == other.wrapping
             && self.bounds == other.bounds
-            && self.editor.buffer().metrics() == other.editor.buffer().metrics()
+            && buffer_from_editor(&self.editor).metrics()
+                == buffer_from_editor(&other.editor).metrics()
     }
 }
 
@@ -655,9 +803,11 @@ impl Default for Internal {
                 },
             )),
             font: Font::default(),
+            wrapping: Wrapping::default(),
             bounds: Size::ZERO,
             topmost_line_changed: None,
             version: text::Version::default(),
+            preedit: None,
         }
     }
 }
@@ -666,6 +816,7 @@ impl fmt::Debug for Internal {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Internal")
             .field("font", &self.font)
+            .field("wrapping", &self.wrapping)
             .field("bounds", &self.bounds)
             .finish()
     }
@@ -695,6 +846,43 @@ impl PartialEq for Weak {
     }
 }
 
+/// Lays out the [`Preedit`] of the given [`Internal`] editor, if any, by
+/// inserting its content at the cursor of a copy of the editor lines.
+fn lay_out_preedit(
+    internal: &mut Internal,
+    font_system: &mut cosmic_text::FontSystem,
+) {
+    let Some(preedit) = &mut internal.preedit else {
+        return;
+    };
+
+    let source = buffer_from_editor(&internal.editor);
+    let cursor = internal.editor.cursor();
+
+    let mut lines = source.lines.clone();
+
+    if let Some(line) = lines.get_mut(cursor.line) {
+        let rest = line.split_off(cursor.index.min(line.text().len()));
+
+        line.append(cosmic_text::BufferLine::new(
+            &preedit.content,
+            cosmic_text::LineEnding::None,
+            cosmic_text::AttrsList::new(line.attrs_list().defaults()),
+            cosmic_text::Shaping::Advanced,
+        ));
+        line.append(rest);
+    }
+
+    let (width, height) = source.size();
+
+    let buffer = &mut preedit.buffer;
+    buffer.lines = lines;
+    buffer.set_metrics_and_size(font_system, source.metrics(), width, height);
+    buffer.set_wrap(font_system, source.wrap());
+    buffer.set_scroll(source.scroll());
+    buffer.shape_until_scroll(font_system, false);
+}
+
 fn highlight_line(
     line: &cosmic_text::BufferLine,
     from: usize,
@@ -746,10 +934,14 @@ fn highlight_line(
 }
 
 fn visual_lines_offset(line: usize, buffer: &cosmic_text::Buffer) -> i32 {
-    let visual_lines_before_start: usize = buffer
-        .lines
+    let scroll = buffer.scroll();
+
+    let start = scroll.line.min(line);
+    let end = scroll.line.max(line);
+
+    let visual_lines_offset: usize = buffer.lines[start..]
         .iter()
-        .take(line)
+        .take(end - start)
         .map(|line| {
             line.layout_opt()
                 .as_ref()
@@ -758,22 +950,93 @@ fn visual_lines_offset(line: usize, buffer: &cosmic_text::Buffer) -> i32 {
         })
         .sum();
 
-    visual_lines_before_start as i32 - buffer.scroll()
+    visual_lines_offset as i32 * if scroll.line < line { 1 } else { -1 }
 }
 
 fn motion_to_action(motion: Motion) -> cosmic_text::Action {
-    match motion {
-        Motion::Left => cosmic_text::Action::Left,
-        Motion::Right => cosmic_text::Action::Right,
-        Motion::Up => cosmic_text::Action::Up,
-        Motion::Down => cosmic_text::Action::Down,
-        Motion::WordLeft => cosmic_text::Action::LeftWord,
-        Motion::WordRight => cosmic_text::Action::RightWord,
-        Motion::Home => cosmic_text::Action::Home,
-        Motion::End => cosmic_text::Action::End,
-        Motion::PageUp => cosmic_text::Action::PageUp,
-        Motion::PageDown => cosmic_text::Action::PageDown,
-        Motion::DocumentStart => cosmic_text::Action::BufferStart,
-        Motion::DocumentEnd => cosmic_text::Action::BufferEnd,
+    cosmic_text::Action::Motion(match motion {
+        Motion::Left => cosmic_text::Motion::Left,
+        Motion::Right => cosmic_text::Motion::Right,
+        Motion::Up => cosmic_text::Motion::Up,
+        Motion::Down => cosmic_text::Motion::Down,
+        Motion::WordLeft => cosmic_text::Motion::LeftWord,
+        Motion::WordRight => cosmic_text::Motion::RightWord,
+        Motion::Home => cosmic_text::Motion::Home,
+        Motion::End => cosmic_text::Motion::End,
+        Motion::PageUp => cosmic_text::Motion::PageUp,
+        Motion::PageDown => cosmic_text::Motion::PageDown,
+        Motion::DocumentStart => cosmic_text::Motion::BufferStart,
+        Motion::DocumentEnd => cosmic_text::Motion::BufferEnd,
+    })
+}
+
+fn buffer_from_editor<'a>(
+    editor: &'a cosmic_text::Editor<'static>,
+) -> &'a cosmic_text::Buffer {
+    match editor.buffer_ref() {
+        cosmic_text::BufferRef::Owned(buffer) => buffer,
+        cosmic_text::BufferRef::Borrowed(buffer) => buffer,
+        cosmic_text::BufferRef::Arc(buffer) => buffer,
+    }
+}
+
+fn buffer_mut_from_editor<'a>(
+    editor: &'a mut cosmic_text::Editor<'static>,
+) -> &'a mut cosmic_text::Buffer {
+    match editor.buffer_ref_mut() {
+        cosmic_text::BufferRef::Owned(buffer) => buffer,
+        cosmic_text::BufferRef::Borrowed(buffer) => buffer,
+        cosmic_text::BufferRef::Arc(_buffer) => unreachable!(),
+    }
+}
+
+fn selection(
+    editor: &cosmic_text::Editor<'static>,
+) -> Option<cosmic_text::Cursor> {
+    match editor.selection() {
+        cosmic_text::Selection::None => None,
+        cosmic_text::Selection::Normal(cursor)
+        | cosmic_text::Selection::Line(cursor)
+        | cosmic_text::Selection::Word(cursor) => Some(cursor),
+    }
+}
+
+fn set_selection(
+    editor: &mut cosmic_text::Editor<'static>,
+    selection: Option<cosmic_text::Cursor>,
+) {
+    editor.set_selection(match selection {
+        Some(cursor) => cosmic_text::Selection::Normal(cursor),
+        None => cosmic_text::Selection::None,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::editor::Editor as _;
+
+    #[test]
+    fn preedit_is_laid_out_at_the_cursor_outside_of_the_content() {
+        let mut editor = Editor::with_text("Hello world");
+
+        editor.update(
+            Size::new(200.0, 100.0),
+            Font::default(),
+            Pixels(16.0),
+            LineHeight::default(),
+            Wrapping::default(),
+            &mut highlighter::PlainText,
+        );
+
+        editor.select_range((0, 5), (0, 5));
+        editor.set_preedit(Some(","));
+
+        assert_eq!(editor.buffer().lines[0].text(), "Hello, world");
+        assert_eq!(editor.line(0), Some("Hello world"));
+
+        editor.set_preedit(None);
+
+        assert_eq!(editor.buffer().lines[0].text(), "Hello world");
     }
 }
@@ -1,11 +1,14 @@
 //! Draw paragraphs.
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Hit, LineHeight, Shaping, Text};
-use crate::core::{Font, Pixels, Point, Size};
+use crate::core::text::{
+    Ellipsis, Hit, LineHeight, Shaping, Span, Text, Wrapping,
+};
+use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc};
 
 /// A bunch of text.
@@ -15,15 +18,37 @@ pub struct Paragraph(Option<Arc<Internal>>);
 struct Internal {
     buffer: cosmic_text::Buffer,
     content: String, // TODO: Reuse from `buffer` (?)
+    spans: Vec<(Range<usize>, cosmic_text::AttrsOwned)>,
+    truncated: Option<Truncation>,
     font: Font,
     shaping: Shaping,
+    wrapping: Wrapping,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    letter_spacing: Pixels,
+    letters: Vec<text::Letter>,
+    ellipsis: Ellipsis,
+    max_lines: Option<usize>,
     bounds: Size,
     min_bounds: Size,
     version: text::Version,
 }
 
+impl Internal {
+    /// Returns the contents displayed by the buffer, which may be truncated.
+    fn displayed(&self) -> &str {
+        self.truncated
+            .as_ref()
+            .map_or(&self.content, |truncation| &truncation.content)
+    }
+
+    /// Returns whether the contents may be truncated depending on the
+    /// bounds.
+    fn is_truncatable(&self) -> bool {
+        self.ellipsis != Ellipsis::None || self.max_lines.is_some()
+    }
+}
+
 impl Paragraph {
     /// Creates a new empty [`Paragraph`].
     pub fn new() -> Self {
@@ -35,6 +60,18 @@ impl Paragraph {
         &self.internal().buffer
     }
 
+    /// Returns the extra space between the letters of the [`Paragraph`] in
+    /// logical pixels.
+    pub fn letter_spacing(&self) -> f32 {
+        self.internal().letter_spacing.0
+    }
+
+    /// Returns the [`text::Letter`]s of the [`Paragraph`] to draw instead of
+    /// its buffer, if it has any letter spacing.
+    pub fn letters(&self) -> &[text::Letter] {
+        &self.internal().letters
+    }
+
     /// Creates a [`Weak`] reference to the [`Paragraph`].
     ///
     /// This is useful to avoid cloning the [`Paragraph`] when
@@ -75,34 +112,98 @@ impl core::text::Paragraph for Paragraph {
             ),
         );
 
+        buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrapping));
         buffer.set_size(
             font_system.raw(),
-            text.bounds.width,
-            text.bounds.height,
+            Some(text.bounds.width),
+            Some(text.bounds.height),
         );
 
-        buffer.set_text(
+        let spans = text::to_spans(font_system.raw(), text.content, text.font);
+
+        buffer.set_rich_text(
             font_system.raw(),
-            text.content,
+            spans,
             text::to_attributes(text.font),
             text::to_shaping(text.shaping),
         );
 
-        let min_bounds = text::measure(&buffer);
+        let truncated =
+            truncate(font_system.raw(), &mut buffer, &text, text.content, &[]);
+
+        let min_bounds = measure(&buffer, text.letter_spacing.0);
+        let letters = space_letters(font_system.raw(), &buffer, &text);
 
         Self(Some(Arc::new(Internal {
             buffer,
             content: text.content.to_owned(),
+            spans: Vec::new(),
+            truncated,
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
+            wrapping: text.wrapping,
+            letter_spacing: text.letter_spacing,
+            letters,
+            ellipsis: text.ellipsis,
+            max_lines: text.max_lines,
             bounds: text.bounds,
             min_bounds,
             version: font_system.version(),
         })))
     }
 
+    fn with_spans<Link>(
+        text: Text<'_, Font>,
+        spans: &[Span<'_, Link, Font>],
+    ) -> Self {
+        log::trace!("Allocating rich paragraph: {} spans", spans.len());
+
+        let mut content = String::new();
+        let mut ranges = Vec::with_capacity(spans.len());
+
+        {
+            let mut font_system =
+                text::font_system().write().expect("Write font system");
+
+            for (i, span) in spans.iter().enumerate() {
+                let start = content.len();
+                content.push_str(&span.text);
+
+                let fallback = text::fallback(
+                    font_system.raw(),
+                    &span.text,
+                    span.font.unwrap_or(text.font),
+                );
+
+                for (range, attrs) in fallback {
+                    let attrs = attrs.metadata(i);
+
+                    let attrs = match span.color {
+                        Some(color) => attrs.color(text::to_color(color)),
+                        None => attrs,
+                    };
+
+                    let attrs = match span.size {
+                        Some(size) => attrs.metrics(cosmic_text::Metrics::new(
+                            size.0,
+                            text.line_height.to_absolute(size).0,
+                        )),
+                        None => attrs,
+                    };
+
+                    ranges.push((
+                        start + range.start..start + range.end,
+                        cosmic_text::AttrsOwned::new(attrs),
+                    ));
+                }
+            }
+        }
+
+        Self::with_rich_content(text, content, ranges)
+    }
+
     fn resize(&mut self, new_bounds: Size) {
         let paragraph = self
             .0
@@ -110,29 +211,38 @@ impl core::text::Paragraph for Paragraph {
             .expect("paragraph should always be initialized");
 
         match Arc::try_unwrap(paragraph) {
-            Ok(mut internal) => {
+            Ok(mut internal) if !internal.is_truncatable() => {
                 let mut font_system =
                     text::font_system().write().expect("Write font system");
 
                 internal.buffer.set_size(
                     font_system.raw(),
-                    new_bounds.width,
-                    new_bounds.height,
+                    Some(new_bounds.width),
+                    Some(new_bounds.height),
                 );
 
                 internal.bounds = new_bounds;
-                internal.min_bounds = text::measure(&internal.buffer);
+                internal.min_bounds =
+                    measure(&internal.buffer, internal.letter_spacing.0);
+
+                if internal.letter_spacing.0 != 0.0 {
+                    internal.letters = text::space_letters(
+                        font_system.raw(),
+                        &internal.buffer,
+                        internal.letter_spacing.0,
+                    );
+                }
 
                 self.0 = Some(Arc::new(internal));
             }
-            Err(internal) => {
+            result => {
+                let internal =
+                    result.map_or_else(|internal| internal, Arc::new);
                 let metrics = internal.buffer.metrics();
 
-                // If there is a strong reference somewhere, we recompute the
-                // buffer from scratch
-                *self = Self::with_text(Text {
+                let text = Text {
                     content: &internal.content,
-                    bounds: internal.bounds,
+                    bounds: new_bounds,
                     size: Pixels(metrics.font_size),
                     line_height: LineHeight::Absolute(Pixels(
                         metrics.line_height,
@@ -141,7 +251,23 @@ impl core::text::Paragraph for Paragraph {
                     horizontal_alignment: internal.horizontal_alignment,
                     vertical_alignment: internal.vertical_alignment,
                     shaping: internal.shaping,
-                });
+                    wrapping: internal.wrapping,
+                    letter_spacing: internal.letter_spacing,
+                    ellipsis: internal.ellipsis,
+                    max_lines: internal.max_lines,
+                };
+
+                // If there is a strong reference somewhere or the contents may
+                // need to be truncated, we recompute the buffer from scratch
+                *self = if internal.spans.is_empty() {
+                    Self::with_text(text)
+                } else {
+                    Self::with_rich_content(
+                        text,
+                        internal.content.clone(),
+                        internal.spans.clone(),
+                    )
+                };
             }
         }
     }
@@ -152,13 +278,18 @@ impl core::text::Paragraph for Paragraph {
         let metrics = paragraph.buffer.metrics();
 
         if paragraph.version != font_system.version
-            || paragraph.content != text.content
+            || (paragraph.spans.is_empty() && paragraph.content != text.content)
             || metrics.font_size != text.size.0
             || metrics.line_height != text.line_height.to_absolute(text.size).0
             || paragraph.font != text.font
             || paragraph.shaping != text.shaping
+            || paragraph.wrapping != text.wrapping
             || paragraph.horizontal_alignment != text.horizontal_alignment
             || paragraph.vertical_alignment != text.vertical_alignment
+            || paragraph.letter_spacing != text.letter_spacing
+            || paragraph.ellipsis != text.ellipsis
+            || paragraph.max_lines != text.max_lines
+            || (paragraph.is_truncatable() && paragraph.bounds != text.bounds)
         {
             core::text::Difference::Shape
         } else if paragraph.bounds != text.bounds {
@@ -181,15 +312,122 @@ impl core::text::Paragraph for Paragraph {
     }
 
     fn hit_test(&self, point: Point) -> Option<Hit> {
-        let cursor = self.internal().buffer.hit(point.x, point.y)?;
+        let internal = self.internal();
+        let content = internal.displayed();
 
-        Some(Hit::CharOffset(cursor.index))
+        let offset = internal
+            .buffer
+            .layout_runs()
+            .find(|run| {
+                point.y >= run.line_top
+                    && point.y < run.line_top + run.line_height
+            })
+            .map(|run| {
+                run.glyphs
+                    .iter()
+                    .zip(text::letter_offsets(
+                        run.glyphs,
+                        internal.letter_spacing.0,
+                    ))
+                    .take_while(|(glyph, offset)| glyph.x + offset <= point.x)
+                    .last()
+                    .map_or(0.0, |(_, offset)| offset)
+            })
+            .unwrap_or(0.0);
+
+        let cursor = internal.buffer.hit(point.x - offset, point.y)?;
+
+        let line_start = cosmic_text::BidiParagraphs::new(content)
+            .nth(cursor.line)
+            .map(|line| offset_of(content, line))
+            .unwrap_or(0);
+
+        let offset = line_start + cursor.index;
+
+        Some(Hit::CharOffset(
+            internal
+                .truncated
+                .as_ref()
+                .map_or(offset, |truncation| truncation.to_content(offset)),
+        ))
+    }
+
+    fn selection_bounds(&self, range: Range<usize>) -> Vec<Rectangle> {
+        let internal = self.internal();
+
+        let range = match &internal.truncated {
+            Some(truncation) => truncation.to_displayed(range),
+            None => range,
+        };
+
+        let start = to_cursor(internal.displayed(), range.start);
+        let end = to_cursor(internal.displayed(), range.end);
+
+        internal
+            .buffer
+            .layout_runs()
+            .filter_map(|run| {
+                let (x, width) = run.highlight(start, end)?;
+
+                let spacing = |x: f32| {
+                    run.glyphs
+                        .iter()
+                        .zip(text::letter_offsets(
+                            run.glyphs,
+                            internal.letter_spacing.0,
+                        ))
+                        .take_while(|(glyph, _)| glyph.x < x)
+                        .last()
+                        .map_or(0.0, |(_, offset)| offset)
+                };
+
+                let start = x + spacing(x + f32::EPSILON);
+                let end = x + width + spacing(x + width);
+
+                Some(Rectangle {
+                    x: start,
+                    y: run.line_top,
+                    width: end - start,
+                    height: run.line_height,
+                })
+            })
+            .collect()
+    }
+
+    fn hit_span(&self, point: Point) -> Option<usize> {
+        let internal = self.internal();
+
+        if internal.spans.is_empty() {
+            return None;
+        }
+
+        let run = internal.buffer.layout_runs().find(|run| {
+            point.y >= run.line_top && point.y < run.line_top + run.line_height
+        })?;
+
+        let glyph = run
+            .glyphs
+            .iter()
+            .find(|glyph| point.x >= glyph.x && point.x < glyph.x + glyph.w)?;
+
+        Some(glyph.metadata)
+    }
+
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle> {
+        self.span_lines(index).map(|(bounds, _)| bounds).collect()
+    }
+
+    fn span_baselines(&self, index: usize) -> Vec<f32> {
+        self.span_lines(index)
+            .map(|(_, baseline)| baseline)
+            .collect()
     }
 
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point> {
         use unicode_segmentation::UnicodeSegmentation;
 
-        let run = self.internal().buffer.layout_runs().nth(line)?;
+        let internal = self.internal();
+        let run = internal.buffer.layout_runs().nth(line)?;
 
         // index represents a grapheme, not a glyph
         // Let's find the first glyph for the given grapheme cluster
@@ -197,10 +435,11 @@ impl core::text::Paragraph for Paragraph {
         let mut last_grapheme_count = 0;
         let mut graphemes_seen = 0;
 
-        let glyph = run
+        let (glyph, offset) = run
             .glyphs
             .iter()
-            .find(|glyph| {
+            .zip(text::letter_offsets(run.glyphs, internal.letter_spacing.0))
+            .find(|(glyph, _)| {
                 if Some(glyph.start) != last_start {
                     last_grapheme_count = run.text[glyph.start..glyph.end]
                         .graphemes(false)
@@ -211,7 +450,15 @@ impl core::text::Paragraph for Paragraph {
 
                 graphemes_seen >= index
             })
-            .or_else(|| run.glyphs.last())?;
+            .or_else(|| {
+                run.glyphs
+                    .iter()
+                    .zip(text::letter_offsets(
+                        run.glyphs,
+                        internal.letter_spacing.0,
+                    ))
+                    .last()
+            })?;
 
         let advance = if index == 0 {
             0.0
@@ -223,12 +470,464 @@ impl core::text::Paragraph for Paragraph {
         };
 
         Some(Point::new(
-            glyph.x + glyph.x_offset * glyph.font_size + advance,
+            glyph.x + offset + glyph.x_offset * glyph.font_size + advance,
             glyph.y - glyph.y_offset * glyph.font_size,
         ))
     }
 }
 
+impl Paragraph {
+    /// Returns the bounds and the baseline of every line where the [`Span`]
+    /// with the given index is laid out.
+    fn span_lines(
+        &self,
+        index: usize,
+    ) -> impl Iterator<Item = (Rectangle, f32)> + '_ {
+        let internal = self.internal();
+
+        internal.buffer.layout_runs().filter_map(move |run| {
+            let (start, end) = run
+                .glyphs
+                .iter()
+                .zip(text::letter_offsets(
+                    run.glyphs,
+                    internal.letter_spacing.0,
+                ))
+                .filter(|(glyph, _)| glyph.metadata == index)
+                .fold(None, |range, (glyph, offset)| {
+                    let x = glyph.x + offset;
+                    let (start, end) = range.unwrap_or((x, x + glyph.w));
+
+                    Some((start.min(x), end.max(x + glyph.w)))
+                })?;
+
+            let bounds = Rectangle {
+                x: start,
+                y: run.line_top,
+                width: end - start,
+                height: run.line_height,
+            };
+
+            Some((bounds, run.line_y))
+        })
+    }
+
+    fn with_rich_content(
+        text: Text<'_, Font>,
+        content: String,
+        spans: Vec<(Range<usize>, cosmic_text::AttrsOwned)>,
+    ) -> Self {
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        let mut buffer = cosmic_text::Buffer::new(
+            font_system.raw(),
+            cosmic_text::Metrics::new(
+                text.size.into(),
+                text.line_height.to_absolute(text.size).into(),
+            ),
+        );
+
+        buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrapping));
+        buffer.set_size(
+            font_system.raw(),
+            Some(text.bounds.width),
+            Some(text.bounds.height),
+        );
+
+        buffer.set_rich_text(
+            font_system.raw(),
+            spans.iter().map(|(range, attrs)| {
+                (&content[range.clone()], attrs.as_attrs())
+            }),
+            text::to_attributes(text.font),
+            text::to_shaping(text.shaping),
+        );
+
+        let truncated =
+            truncate(font_system.raw(), &mut buffer, &text, &content, &spans);
+
+        let min_bounds = measure(&buffer, text.letter_spacing.0);
+        let letters = space_letters(font_system.raw(), &buffer, &text);
+
+        Self(Some(Arc::new(Internal {
+            buffer,
+            content,
+            spans,
+            truncated,
+            font: text.font,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            shaping: text.shaping,
+            wrapping: text.wrapping,
+            letter_spacing: text.letter_spacing,
+            letters,
+            ellipsis: text.ellipsis,
+            max_lines: text.max_lines,
+            bounds: text.bounds,
+            min_bounds,
+            version: font_system.version(),
+        })))
+    }
+}
+
+/// Spaces the letters of the given [`cosmic_text::Buffer`] with the letter
+/// spacing of the [`Text`], if any.
+fn space_letters(
+    font_system: &mut cosmic_text::FontSystem,
+    buffer: &cosmic_text::Buffer,
+    text: &Text<'_, Font>,
+) -> Vec<text::Letter> {
+    if text.letter_spacing.0 == 0.0 {
+        return Vec::new();
+    }
+
+    text::space_letters(font_system, buffer, text.letter_spacing.0)
+}
+
+/// Measures the dimensions of the given [`cosmic_text::Buffer`] with some
+/// letter spacing.
+fn measure(buffer: &cosmic_text::Buffer, letter_spacing: f32) -> Size {
+    let bounds = text::measure(buffer);
+
+    if letter_spacing == 0.0 {
+        return bounds;
+    }
+
+    let width = buffer
+        .layout_runs()
+        .map(|run| width(&run, letter_spacing))
+        .fold(0.0, f32::max);
+
+    Size { width, ..bounds }
+}
+
+/// Returns the width of a [`cosmic_text::LayoutRun`] with some letter
+/// spacing.
+fn width(run: &cosmic_text::LayoutRun<'_>, letter_spacing: f32) -> f32 {
+    run.line_w
+        + text::letter_offsets(run.glyphs, letter_spacing)
+            .last()
+            .unwrap_or(0.0)
+}
+
+/// Truncates the contents of the [`cosmic_text::Buffer`] of some [`Text`]
+/// exceeding its bounds or its maximum amount of lines, following its
+/// [`Ellipsis`] strategy.
+///
+/// The `spans` of rich `content` are kept around the ellipsis, which takes
+/// the attributes of the last span before it.
+///
+/// Returns the [`Truncation`] of the contents, if any.
+fn truncate(
+    font_system: &mut cosmic_text::FontSystem,
+    buffer: &mut cosmic_text::Buffer,
+    text: &Text<'_, Font>,
+    content: &str,
+    spans: &[(Range<usize>, cosmic_text::AttrsOwned)],
+) -> Option<Truncation> {
+    const ELLIPSIS: &str = "\u{2026}";
+
+    if text.ellipsis == Ellipsis::None && text.max_lines.is_none() {
+        return None;
+    }
+
+    let (width_opt, height_opt) = buffer.size();
+    let width = width_opt.unwrap_or(f32::INFINITY);
+    let height = height_opt.unwrap_or(f32::INFINITY);
+    let letter_spacing = text.letter_spacing.0;
+
+    let is_single_line =
+        text.ellipsis == Ellipsis::Middle && !content.contains('\n');
+
+    let wrapping = if is_single_line {
+        Wrapping::None
+    } else {
+        text.wrapping
+    };
+
+    // Lay out every line, even if it is not visible
+    buffer.set_size(font_system, width_opt, None);
+    buffer.set_wrap(font_system, text::to_wrap(wrapping));
+
+    let (ellipsis, ellipsis_width) = match text.ellipsis {
+        Ellipsis::None => ("", 0.0),
+        Ellipsis::End | Ellipsis::Middle => {
+            let mut ellipsis =
+                cosmic_text::Buffer::new(font_system, buffer.metrics());
+
+            ellipsis.set_size(font_system, None, None);
+            ellipsis.set_text(
+                font_system,
+                ELLIPSIS,
+                text::to_attributes(text.font),
+                text::to_shaping(text.shaping),
+            );
+
+            (ELLIPSIS, text::measure(&ellipsis).width + letter_spacing)
+        }
+    };
+
+    let truncated = if is_single_line {
+        truncate_middle(buffer, content, width, ellipsis_width, letter_spacing)
+    } else {
+        let line_height = buffer.metrics().line_height;
+
+        let visible_lines = match text.ellipsis {
+            Ellipsis::None => usize::MAX,
+            Ellipsis::End | Ellipsis::Middle => {
+                (height / line_height).floor() as usize
+            }
+        };
+
+        truncate_end(
+            buffer,
+            content,
+            width,
+            visible_lines
+                .min(text.max_lines.unwrap_or(usize::MAX))
+                .max(1),
+            ellipsis_width,
+            letter_spacing,
+        )
+    }
+    .map(|(head, tail)| Truncation::new(content, head, tail, ellipsis));
+
+    if let Some(truncation) = &truncated {
+        let displayed = &truncation.content;
+
+        if spans.is_empty() {
+            let spans = text::to_spans(font_system, displayed, text.font);
+
+            buffer.set_rich_text(
+                font_system,
+                spans,
+                text::to_attributes(text.font),
+                text::to_shaping(text.shaping),
+            );
+        } else {
+            let spans = truncation.spans(spans);
+
+            buffer.set_rich_text(
+                font_system,
+                spans.iter().map(|(range, attrs)| {
+                    (&displayed[range.clone()], attrs.as_attrs())
+                }),
+                text::to_attributes(text.font),
+                text::to_shaping(text.shaping),
+            );
+        }
+    }
+
+    buffer.set_size(font_system, width_opt, height_opt);
+
+    truncated
+}
+
+/// The contents displayed by a truncated [`Paragraph`].
+///
+/// The displayed contents keep the contents of the [`Paragraph`] up to
+/// `ellipsis.start`, followed by the ellipsis and the contents starting at
+/// `tail`.
+#[derive(Debug, Clone)]
+struct Truncation {
+    content: String,
+    ellipsis: Range<usize>,
+    tail: usize,
+}
+
+impl Truncation {
+    /// Creates a [`Truncation`] of some `content` keeping the given `head`
+    /// and `tail` slices of it around an `ellipsis`.
+    fn new(content: &str, head: &str, tail: &str, ellipsis: &str) -> Self {
+        let head = head.trim_end();
+        let tail = tail.trim_start();
+
+        Self {
+            content: format!("{head}{ellipsis}{tail}"),
+            ellipsis: head.len()..head.len() + ellipsis.len(),
+            tail: content.len() - tail.len(),
+        }
+    }
+
+    /// Converts an offset in the displayed contents into an offset in the
+    /// original contents.
+    ///
+    /// The ellipsis stands for the hidden contents, so an offset after it is
+    /// the start of the tail.
+    fn to_content(&self, offset: usize) -> usize {
+        if offset <= self.ellipsis.start {
+            offset
+        } else if offset < self.ellipsis.end {
+            self.ellipsis.start
+        } else {
+            self.tail + offset - self.ellipsis.end
+        }
+    }
+
+    /// Converts a range of the original contents into a range of the
+    /// displayed contents.
+    ///
+    /// Any hidden contents in the range select the ellipsis.
+    fn to_displayed(&self, range: Range<usize>) -> Range<usize> {
+        let to_displayed = |offset: usize, hidden: usize| {
+            if offset <= self.ellipsis.start {
+                offset
+            } else if offset < self.tail {
+                hidden
+            } else {
+                self.ellipsis.end + offset - self.tail
+            }
+        };
+
+        to_displayed(range.start, self.ellipsis.start)
+            ..to_displayed(range.end, self.ellipsis.end)
+    }
+
+    /// Returns the spans of some rich contents clipped to the displayed
+    /// contents.
+    fn spans(
+        &self,
+        spans: &[(Range<usize>, cosmic_text::AttrsOwned)],
+    ) -> Vec<(Range<usize>, cosmic_text::AttrsOwned)> {
+        let head = spans.iter().filter_map(|(range, attrs)| {
+            let end = range.end.min(self.ellipsis.start);
+
+            (range.start < end).then(|| (range.start..end, attrs.clone()))
+        });
+
+        let ellipsis = spans
+            .iter()
+            .rev()
+            .find(|(range, _)| range.start < self.ellipsis.start)
+            .or(spans.first())
+            .map(|(_, attrs)| (self.ellipsis.clone(), attrs.clone()));
+
+        let tail = spans.iter().filter_map(|(range, attrs)| {
+            let start = range.start.max(self.tail);
+
+            (start < range.end).then(|| {
+                (
+                    self.ellipsis.end + start - self.tail
+                        ..self.ellipsis.end + range.end - self.tail,
+                    attrs.clone(),
+                )
+            })
+        });
+
+        head.chain(ellipsis).chain(tail).collect()
+    }
+}
+
+/// Finds the contents to keep around the ellipsis when truncating the end of
+/// the lines of a [`cosmic_text::Buffer`].
+fn truncate_end<'a>(
+    buffer: &cosmic_text::Buffer,
+    content: &'a str,
+    max_width: f32,
+    max_lines: usize,
+    ellipsis_width: f32,
+    letter_spacing: f32,
+) -> Option<(&'a str, &'a str)> {
+    let runs: Vec<_> = buffer.layout_runs().collect();
+
+    let overflowing = runs.iter().take(max_lines).position(|run| {
+        ellipsis_width > 0.0 && width(run, letter_spacing) > max_width
+    });
+
+    let last = match overflowing {
+        Some(index) => index,
+        None if runs.len() > max_lines => max_lines - 1,
+        None => return None,
+    };
+
+    let run = &runs[last];
+
+    let line_start = cosmic_text::BidiParagraphs::new(content)
+        .nth(run.line_i)
+        .map(|line| offset_of(content, line))
+        .unwrap_or(content.len());
+
+    let available = max_width - ellipsis_width;
+
+    let end = run
+        .glyphs
+        .iter()
+        .zip(text::letter_offsets(run.glyphs, letter_spacing))
+        .take_while(|(glyph, offset)| glyph.x + glyph.w + offset <= available)
+        .last()
+        .map(|(glyph, _)| glyph.end)
+        .or_else(|| run.glyphs.first().map(|glyph| glyph.start))
+        .unwrap_or(0);
+
+    let end = (line_start + end).min(content.len());
+
+    Some((&content[..end], ""))
+}
+
+/// Finds the contents to keep around the ellipsis when truncating the middle
+/// of the single line of a [`cosmic_text::Buffer`].
+fn truncate_middle<'a>(
+    buffer: &cosmic_text::Buffer,
+    content: &'a str,
+    max_width: f32,
+    ellipsis_width: f32,
+    letter_spacing: f32,
+) -> Option<(&'a str, &'a str)> {
+    let run = buffer.layout_runs().next()?;
+    let total = width(&run, letter_spacing);
+
+    if total <= max_width {
+        return None;
+    }
+
+    let half = (max_width - ellipsis_width) / 2.0;
+
+    let glyphs: Vec<_> = run
+        .glyphs
+        .iter()
+        .zip(text::letter_offsets(run.glyphs, letter_spacing))
+        .collect();
+
+    let head = glyphs
+        .iter()
+        .take_while(|(glyph, offset)| glyph.x + glyph.w + offset <= half)
+        .last()
+        .map_or(0, |(glyph, _)| glyph.end);
+
+    let tail = glyphs
+        .iter()
+        .rev()
+        .take_while(|(glyph, offset)| total - (glyph.x + offset) <= half)
+        .last()
+        .map_or(content.len(), |(glyph, _)| glyph.start)
+        .max(head);
+
+    Some((&content[..head], &content[tail..]))
+}
+
+/// Returns the byte offset of a `line` that is a slice of `content`.
+fn offset_of(content: &str, line: &str) -> usize {
+    line.as_ptr() as usize - content.as_ptr() as usize
+}
+
+/// Converts a byte offset in some `content` into a [`cosmic_text::Cursor`].
+fn to_cursor(content: &str, offset: usize) -> cosmic_text::Cursor {
+    let mut cursor = cosmic_text::Cursor::new(0, 0);
+
+    for (i, line) in cosmic_text::BidiParagraphs::new(content).enumerate() {
+        let start = offset_of(content, line);
+
+        if offset < start {
+            break;
+        }
+
+        cursor = cosmic_text::Cursor::new(i, (offset - start).min(line.len()));
+    }
+
+    cursor
+}
+
 impl Default for Paragraph {
     fn default() -> Self {
         Self(Some(Arc::new(Internal::default())))
@@ -243,6 +942,10 @@ impl fmt::Debug for Paragraph {
             .field("content", &paragraph.content)
             .field("font", &paragraph.font)
             .field("shaping", &paragraph.shaping)
+            .field("wrapping", &paragraph.wrapping)
+            .field("letter_spacing", &paragraph.letter_spacing)
+            .field("ellipsis", &paragraph.ellipsis)
+            .field("max_lines", &paragraph.max_lines)
             .field("horizontal_alignment", &paragraph.horizontal_alignment)
             .field("vertical_alignment", &paragraph.vertical_alignment)
             .field("bounds", &paragraph.bounds)
@@ -254,8 +957,13 @@ impl fmt::Debug for Paragraph {
 impl PartialEq for Internal {
     fn eq(&self, other: &Self) -> bool {
         self.content == other.content
+            && self.spans == other.spans
             && self.font == other.font
             && self.shaping == other.shaping
+            && self.wrapping == other.wrapping
+            && self.letter_spacing == other.letter_spacing
+            && self.ellipsis == other.ellipsis
+            && self.max_lines == other.max_lines
             && self.horizontal_alignment == other.horizontal_alignment
             && self.vertical_alignment == other.vertical_alignment
             && self.bounds == other.bounds
@@ -272,8 +980,15 @@ impl Default for Internal {
                 line_height: 1.0,
             }),
             content: String::new(),
+            spans: Vec::new(),
+            truncated: None,
             font: Font::default(),
             shaping: Shaping::default(),
+            wrapping: Wrapping::default(),
+            letter_spacing: Pixels(0.0),
+            letters: Vec::new(),
+            ellipsis: Ellipsis::default(),
+            max_lines: None,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
             bounds: Size::ZERO,
@@ -310,3 +1025,188 @@ impl PartialEq for Weak {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::text::Paragraph as _;
+
+    fn text(content: &str) -> Text<'_, Font> {
+        Text {
+            content,
+            bounds: Size::INFINITY,
+            size: Pixels(10.0),
+            line_height: LineHeight::default(),
+            font: Font::default(),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::default(),
+            letter_spacing: Pixels(0.0),
+            ellipsis: Ellipsis::None,
+            max_lines: None,
+        }
+    }
+
+    #[test]
+    fn spans_are_laid_out_with_their_own_size() {
+        let spans: [Span<'_, (), Font>; 2] =
+            [Span::new("a"), Span::new("a").size(30.0)];
+
+        let paragraph = Paragraph::with_spans(text(""), &spans);
+
+        let [small] = paragraph.span_bounds(0)[..] else {
+            panic!("small span should be laid out in a single line");
+        };
+
+        let [large] = paragraph.span_bounds(1)[..] else {
+            panic!("large span should be laid out in a single line");
+        };
+
+        let [baseline] = paragraph.span_baselines(1)[..] else {
+            panic!("large span should have a single baseline");
+        };
+
+        assert!(large.width > 2.0 * small.width);
+        assert_eq!(
+            small.height,
+            LineHeight::default().to_absolute(Pixels(30.0)).0
+        );
+        assert!(baseline > large.y && baseline < large.y + large.height);
+        assert_eq!(paragraph.min_bounds().height, large.height);
+    }
+
+    #[test]
+    fn letters_are_spaced_once() {
+        let plain = Paragraph::with_text(text("abc"));
+        let spaced = Paragraph::with_text(Text {
+            letter_spacing: Pixels(5.0),
+            ..text("abc")
+        });
+
+        let glyphs: Vec<_> = plain
+            .buffer()
+            .layout_runs()
+            .flat_map(|run| run.glyphs.iter().map(|glyph| glyph.x))
+            .collect();
+
+        let letters = spaced.letters();
+
+        assert!(plain.letters().is_empty());
+        assert_eq!(letters.len(), 3);
+
+        for (i, (letter, x)) in letters.iter().zip(glyphs).enumerate() {
+            assert_eq!(letter.position.x, x + i as f32 * 5.0);
+            assert_eq!(letter.position.y, 0.0);
+        }
+
+        assert_eq!(spaced.min_bounds().width, plain.min_bounds().width + 10.0);
+    }
+
+    #[test]
+    fn truncated_offsets_refer_to_the_original_content() {
+        let content = "lorem ipsum dolor sit amet";
+
+        let paragraph = Paragraph::with_text(Text {
+            bounds: Size::new(60.0, f32::INFINITY),
+            ellipsis: Ellipsis::Middle,
+            ..text(content)
+        });
+
+        let height = paragraph.min_bounds().height;
+
+        assert_eq!(
+            paragraph.hit_test(Point::new(1_000.0, height / 2.0)),
+            Some(Hit::CharOffset(content.len()))
+        );
+
+        let hidden = content.find("dolor").unwrap();
+        let [ellipsis] = paragraph.selection_bounds(hidden..hidden + 1)[..]
+        else {
+            panic!("hidden contents should select the ellipsis");
+        };
+
+        let [all] = paragraph.selection_bounds(0..content.len())[..] else {
+            panic!("truncated contents should be laid out in a single line");
+        };
+
+        assert!(ellipsis.width > 0.0);
+        assert!(all.x <= ellipsis.x);
+        assert!(all.x + all.width >= ellipsis.x + ellipsis.width);
+        assert!(all.width <= 60.0);
+    }
+
+    #[test]
+    fn rich_text_is_truncated() {
+        let spans: [Span<'_, (), Font>; 2] =
+            [Span::new("lorem ipsum "), Span::new("dolor sit amet")];
+
+        let paragraph = Paragraph::with_spans(
+            Text {
+                bounds: Size::new(50.0, f32::INFINITY),
+                ellipsis: Ellipsis::End,
+                max_lines: Some(1),
+                ..text("")
+            },
+            &spans,
+        );
+
+        let runs: Vec<_> = paragraph.buffer().layout_runs().collect();
+
+        let [run] = &runs[..] else {
+            panic!("rich text should be truncated to a single line");
+        };
+
+        assert!(run.text.ends_with('\u{2026}'));
+        assert!(paragraph.min_bounds().width <= 50.0);
+    }
+
+    #[test]
+    fn wrapping_changes_the_min_bounds() {
+        let content = "lorem ipsum dolor sit amet";
+        let bounds = Size::new(30.0, f32::INFINITY);
+
+        let single_line = Paragraph::with_text(text(content)).min_bounds();
+
+        let none = Paragraph::with_text(Text {
+            bounds,
+            wrapping: Wrapping::None,
+            ..text(content)
+        })
+        .min_bounds();
+
+        let glyph = Paragraph::with_text(Text {
+            bounds,
+            wrapping: Wrapping::Glyph,
+            ..text(content)
+        })
+        .min_bounds();
+
+        assert_eq!(none.height, single_line.height);
+        assert!(single_line.width > bounds.width);
+        assert!(glyph.width <= bounds.width);
+        assert!(glyph.height > 2.0 * single_line.height);
+    }
+
+    #[test]
+    fn max_lines_are_kept_after_resizing() {
+        let mut paragraph = Paragraph::with_text(Text {
+            max_lines: Some(1),
+            ..text("lorem ipsum dolor sit amet")
+        });
+
+        let line_height = paragraph.min_bounds().height;
+
+        paragraph.resize(Size::new(30.0, f32::INFINITY));
+
+        assert_eq!(paragraph.min_bounds().height, line_height);
+        assert_eq!(
+            paragraph.compare(Text {
+                bounds: Size::new(20.0, f32::INFINITY),
+                max_lines: Some(1),
+                ..text("lorem ipsum dolor sit amet")
+            }),
+            core::text::Difference::Shape
+        );
+    }
+}
@@ -160,10 +160,9 @@ impl crate::graphics::Compositor for Compositor {
             (
                 Self::TinySkia(_compositor),
                 Renderer::TinySkia(renderer),
-                Surface::TinySkia(surface),
+                Surface::TinySkia(_),
             ) => renderer.with_primitives(|backend, primitives| {
                 iced_tiny_skia::window::compositor::screenshot(
-                    surface,
                     backend,
                     primitives,
                     viewport,
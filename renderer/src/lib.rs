@@ -25,13 +25,14 @@ use crate::core::text::{self, Text};
 use crate::core::{Background, Color, Font, Pixels, Point, Rectangle, Vector};
 use crate::graphics::text::Editor;
 use crate::graphics::text::Paragraph;
-use crate::graphics::Mesh;
+use crate::graphics::{Mesh, Viewport};
 
 use std::borrow::Cow;
 
 /// The default graphics renderer for [`iced`].
 ///
 /// [`iced`]: https://github.com/iced-rs/iced
+#[allow(clippy::large_enum_variant)]
 pub enum Renderer {
     TinySkia(iced_tiny_skia::Renderer),
     #[cfg(feature = "wgpu")]
@@ -49,6 +50,79 @@ macro_rules! delegate {
 }
 
 impl Renderer {
+    /// Creates a new [`Renderer`] backed by tiny-skia, which draws on the CPU
+    /// and does not need a window.
+    pub fn headless(settings: Settings) -> Self {
+        Self::TinySkia(iced_tiny_skia::Renderer::new(
+            iced_tiny_skia::Backend::new(),
+            settings.default_font,
+            settings.default_text_size,
+        ))
+    }
+
+    /// Renders everything drawn so far into the `RGBA` pixels of the given
+    /// [`Viewport`].
+    ///
+    /// Returns `None` if the [`Renderer`] is not backed by tiny-skia.
+    pub fn screenshot(
+        &mut self,
+        viewport: &Viewport,
+        background_color: Color,
+    ) -> Option<Vec<u8>> {
+        match self {
+            Self::TinySkia(renderer) => {
+                Some(renderer.with_primitives(|backend, primitives| {
+                    iced_tiny_skia::window::compositor::screenshot::<&str>(
+                        backend,
+                        primitives,
+                        viewport,
+                        background_color,
+                        &[],
+                    )
+                }))
+            }
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(_) => None,
+        }
+    }
+
+    /// Waits until every image drawn so far has been decoded, including the
+    /// images that widgets reported as loading and replaced with a
+    /// placeholder.
+    ///
+    /// Returns `true` if any image was still being decoded, in which case
+    /// everything should be drawn again.
+    #[cfg(feature = "image")]
+    pub fn load_images(&mut self) -> bool {
+        use crate::core::image::Renderer as _;
+        use crate::graphics::futures::futures::{executor, future};
+        use crate::graphics::image::decoder;
+
+        let handles = delegate!(self, renderer, {
+            let mut handles = renderer.take_loading_images();
+
+            renderer.with_primitives(|_, primitives| {
+                images(primitives, &mut handles);
+            });
+
+            handles
+        });
+
+        let loading: Vec<_> = handles
+            .iter()
+            .filter(|handle| self.is_loading(handle))
+            .map(decoder::decode)
+            .collect();
+
+        if loading.is_empty() {
+            return false;
+        }
+
+        let _ = executor::block_on(future::join_all(loading));
+
+        true
+    }
+
     pub fn draw_mesh(&mut self, mesh: Mesh) {
         match self {
             Self::TinySkia(_) => {
@@ -163,8 +237,11 @@ impl text::Renderer for Renderer {
         delegate!(self, renderer, renderer.default_size())
     }
 
-    fn load_font(&mut self, bytes: Cow<'static, [u8]>) {
-        delegate!(self, renderer, renderer.load_font(bytes));
+    fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), core::font::Error> {
+        delegate!(self, renderer, renderer.load_font(bytes))
     }
 
     fn fill_paragraph(
@@ -221,13 +298,51 @@ impl crate::core::image::Renderer for Renderer {
         delegate!(self, renderer, renderer.dimensions(handle))
     }
 
+    fn is_loading(&self, handle: &crate::core::image::Handle) -> bool {
+        delegate!(self, renderer, renderer.is_loading(handle))
+    }
+
     fn draw(
         &mut self,
         handle: crate::core::image::Handle,
         filter_method: crate::core::image::FilterMethod,
         bounds: Rectangle,
     ) {
-        delegate!(self, renderer, renderer.draw(handle, filter_method, bounds));
+        delegate!(
+            self,
+            renderer,
+            crate::core::image::Renderer::draw(
+                renderer,
+                handle,
+                filter_method,
+                bounds
+            )
+        );
+    }
+
+    fn draw_image(
+        &mut self,
+        handle: crate::core::image::Handle,
+        filter_method: crate::core::image::FilterMethod,
+        bounds: Rectangle,
+        rotation: core::Radians,
+        opacity: f32,
+        tint: Option<Color>,
+        border_radius: core::border::Radius,
+    ) {
+        delegate!(
+            self,
+            renderer,
+            renderer.draw_image(
+                handle,
+                filter_method,
+                bounds,
+                rotation,
+                opacity,
+                tint,
+                border_radius
+            )
+        );
     }
 }
 
@@ -243,7 +358,36 @@ impl crate::core::svg::Renderer for Renderer {
         color: Option<crate::core::Color>,
         bounds: Rectangle,
     ) {
-        delegate!(self, renderer, renderer.draw(handle, color, bounds));
+        delegate!(
+            self,
+            renderer,
+            crate::core::svg::Renderer::draw(renderer, handle, color, bounds)
+        );
+    }
+
+    fn draw_svg(
+        &mut self,
+        handle: crate::core::svg::Handle,
+        color: Option<crate::core::Color>,
+        bounds: Rectangle,
+        rotation: core::Radians,
+        opacity: f32,
+        tint: Option<crate::core::Color>,
+        border_radius: core::border::Radius,
+    ) {
+        delegate!(
+            self,
+            renderer,
+            renderer.draw_svg(
+                handle,
+                color,
+                bounds,
+                rotation,
+                opacity,
+                tint,
+                border_radius
+            )
+        );
     }
 }
 
@@ -298,3 +442,26 @@ impl iced_wgpu::primitive::pipeline::Renderer for Renderer {
         }
     }
 }
+
+#[cfg(feature = "image")]
+fn images<T>(
+    primitives: &[graphics::Primitive<T>],
+    handles: &mut Vec<core::image::Handle>,
+) {
+    use graphics::Primitive;
+
+    for primitive in primitives {
+        match primitive {
+            Primitive::Image { handle, .. } => handles.push(handle.clone()),
+            Primitive::Group { primitives } => images(primitives, handles),
+            Primitive::Clip { content, .. }
+            | Primitive::Translate { content, .. } => {
+                images(std::slice::from_ref(content.as_ref()), handles);
+            }
+            Primitive::Cache { content } => {
+                images(std::slice::from_ref(content.as_ref()), handles);
+            }
+            _ => {}
+        }
+    }
+}
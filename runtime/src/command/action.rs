@@ -43,6 +43,12 @@ pub enum Action<T> {
         /// The message to produce when the font has been loaded.
         tagger: Box<dyn Fn(Result<(), font::Error>) -> T>,
     },
+
+    /// List the available font faces.
+    ListFonts {
+        /// The message to produce with the available font faces.
+        tagger: Box<dyn Fn(Vec<font::Face>) -> T>,
+    },
 }
 
 impl<T> Action<T> {
@@ -72,6 +78,9 @@ impl<T> Action<T> {
                 bytes,
                 tagger: Box::new(move |result| f(tagger(result))),
             },
+            Self::ListFonts { tagger } => Action::ListFonts {
+                tagger: Box::new(move |faces| f(tagger(faces))),
+            },
         }
     }
 }
@@ -90,6 +99,7 @@ impl<T> fmt::Debug for Action<T> {
             Self::System(action) => write!(f, "Action::System({action:?})"),
             Self::Widget(_action) => write!(f, "Action::Widget"),
             Self::LoadFont { .. } => write!(f, "Action::LoadFont"),
+            Self::ListFonts { .. } => write!(f, "Action::ListFonts"),
         }
     }
 }
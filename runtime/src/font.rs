@@ -4,10 +4,6 @@ pub use iced_core::font::*;
 use crate::command::{self, Command};
 use std::borrow::Cow;
 
-/// An error while loading a font.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Error {}
-
 /// Load a font from its bytes.
 pub fn load(
     bytes: impl Into<Cow<'static, [u8]>>,
@@ -17,3 +13,10 @@ pub fn load(
         tagger: Box::new(std::convert::identity),
     })
 }
+
+/// Lists the font faces available to the renderer, sorted by family name.
+pub fn list() -> Command<Vec<Face>> {
+    Command::single(command::Action::ListFonts {
+        tagger: Box::new(std::convert::identity),
+    })
+}
@@ -1,5 +1,6 @@
 //! Implement your own event loop to drive a user interface.
 use crate::core::event::{self, Event};
+use crate::core::input_method::InputMethod;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
@@ -189,6 +190,7 @@ where
 
         let mut outdated = false;
         let mut redraw_request = None;
+        let mut input_method = InputMethod::Disabled;
 
         let mut manual_overlay = ManuallyDrop::new(
             self.root
@@ -229,6 +231,8 @@ where
                     _ => {}
                 }
 
+                input_method = input_method.merge(shell.input_method());
+
                 if shell.is_layout_invalid() {
                     let _ = ManuallyDrop::into_inner(manual_overlay);
 
@@ -335,6 +339,8 @@ where
                     _ => {}
                 }
 
+                input_method = input_method.merge(shell.input_method());
+
                 shell.revalidate_layout(|| {
                     self.base = self.root.as_widget().layout(
                         &mut self.state,
@@ -357,7 +363,10 @@ where
             if outdated {
                 State::Outdated
             } else {
-                State::Updated { redraw_request }
+                State::Updated {
+                    redraw_request,
+                    input_method,
+                }
             },
             event_statuses,
         )
@@ -642,5 +651,8 @@ pub enum State {
     Updated {
         /// The [`window::RedrawRequest`] when a redraw should be performed.
         redraw_request: Option<window::RedrawRequest>,
+
+        /// The [`InputMethod`] requested by the focused widget, if any.
+        input_method: InputMethod,
     },
 }
@@ -1,5 +1,6 @@
 //! Leverage advanced concepts like custom widgets.
 pub use crate::core::image;
+pub use crate::core::input_method::{self, InputMethod};
 pub use crate::core::layout::{self, Layout};
 pub use crate::core::mouse;
 pub use crate::core::overlay::{self, Overlay};
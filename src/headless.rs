@@ -0,0 +1,182 @@
+//! Render user interfaces offscreen, without a window.
+use crate::application;
+use crate::core::renderer;
+use crate::core::{mouse, Size};
+use crate::graphics::Viewport;
+use crate::runtime::user_interface::{self, UserInterface};
+use crate::window::Screenshot;
+use crate::{Element, Renderer};
+
+/// Renders the given [`Element`] offscreen, with the given logical `size`
+/// and `scale_factor`.
+///
+/// The [`Element`] is laid out and drawn with a [`Renderer`] backed by
+/// tiny-skia, so neither a window nor a GPU is needed. When the `image`
+/// feature is enabled, the [`Element`] is drawn again until every image it
+/// draws has been decoded, so no loading placeholders are captured in the
+/// resulting [`Screenshot`].
+pub fn render<'a, Message, Theme>(
+    element: impl Into<Element<'a, Message, Theme>>,
+    theme: &Theme,
+    size: Size,
+    scale_factor: f64,
+) -> Screenshot
+where
+    Theme: application::StyleSheet,
+{
+    let viewport = Viewport::with_physical_size(
+        Size::new(
+            (f64::from(size.width) * scale_factor).round() as u32,
+            (f64::from(size.height) * scale_factor).round() as u32,
+        ),
+        scale_factor,
+    );
+
+    let appearance = theme.appearance(&Theme::Style::default());
+    let style = renderer::Style {
+        text_color: appearance.text_color,
+    };
+
+    let mut renderer = Renderer::headless(crate::renderer::Settings::default());
+
+    let mut user_interface = UserInterface::build(
+        element,
+        viewport.logical_size(),
+        user_interface::Cache::default(),
+        &mut renderer,
+    );
+
+    let _ = user_interface.draw(
+        &mut renderer,
+        theme,
+        &style,
+        mouse::Cursor::Unavailable,
+    );
+
+    #[cfg(feature = "image")]
+    while renderer.load_images() {
+        crate::core::Renderer::clear(&mut renderer);
+
+        let _ = user_interface.draw(
+            &mut renderer,
+            theme,
+            &style,
+            mouse::Cursor::Unavailable,
+        );
+    }
+
+    let bytes = renderer
+        .screenshot(&viewport, appearance.background_color)
+        .expect("Take headless screenshot");
+
+    Screenshot::new(bytes, viewport.physical_size())
+}
+
+/// Encodes the given [`Screenshot`] as a PNG image.
+pub fn png(screenshot: &Screenshot) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::new();
+
+    {
+        let mut encoder = png::Encoder::new(
+            &mut bytes,
+            screenshot.size.width,
+            screenshot.size.height,
+        );
+
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&screenshot.bytes)?;
+        writer.finish()?;
+    }
+
+    Ok(bytes)
+}
+
+/// An error produced while encoding a [`Screenshot`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The [`Screenshot`] could not be encoded as a PNG image.
+    #[error("the screenshot could not be encoded: {0}")]
+    Encoding(String),
+}
+
+impl From<png::EncodingError> for Error {
+    fn from(error: png::EncodingError) -> Self {
+        Self::Encoding(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::{container, horizontal_space};
+    use crate::{Background, Color, Length, Theme};
+
+    #[test]
+    fn render_fills_the_physical_size() {
+        let content = container(horizontal_space(Length::Fill))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(|_: &Theme| container::Appearance {
+                background: Some(Background::Color(Color::from_rgb8(
+                    255, 0, 0,
+                ))),
+                ..container::Appearance::default()
+            });
+
+        let screenshot =
+            render::<(), _>(content, &Theme::Light, Size::new(4.0, 2.0), 2.0);
+
+        assert_eq!(screenshot.size, Size::new(8, 4));
+        assert!(screenshot
+            .bytes
+            .chunks(4)
+            .all(|pixel| pixel == [255, 0, 0, 255]));
+
+        let png = png(&screenshot).expect("Encode screenshot");
+
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn render_of_an_empty_size_is_empty() {
+        let screenshot = render::<(), _>(
+            horizontal_space(Length::Fill),
+            &Theme::Light,
+            Size::ZERO,
+            1.0,
+        );
+
+        assert_eq!(screenshot.size, Size::new(0, 0));
+        assert!(screenshot.bytes.is_empty());
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn render_waits_for_images() {
+        use crate::widget::image;
+
+        let red =
+            Screenshot::new([255, 0, 0, 255].repeat(4 * 4), Size::new(4, 4));
+
+        let handle =
+            image::Handle::from_memory(png(&red).expect("Encode image"));
+
+        let screenshot = render::<(), _>(
+            image(handle)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .content_fit(crate::ContentFit::Fill),
+            &Theme::Light,
+            Size::new(4.0, 4.0),
+            1.0,
+        );
+
+        assert!(screenshot
+            .bytes
+            .chunks(4)
+            .all(|pixel| pixel == [255, 0, 0, 255]));
+    }
+}
@@ -1,4 +1,5 @@
 //! Change the apperance of a button.
+use iced_core::animation::Interpolable;
 use iced_core::{Background, Border, Color, Shadow, Vector};
 
 /// The appearance of a button.
@@ -28,6 +29,20 @@ impl std::default::Default for Appearance {
     }
 }
 
+impl Interpolable for Appearance {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Self {
+            shadow_offset: self
+                .shadow_offset
+                .interpolate(&other.shadow_offset, ratio),
+            background: self.background.interpolate(&other.background, ratio),
+            text_color: self.text_color.interpolate(&other.text_color, ratio),
+            border: self.border.interpolate(&other.border, ratio),
+            shadow: self.shadow.interpolate(&other.shadow, ratio),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a button.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
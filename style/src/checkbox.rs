@@ -1,4 +1,5 @@
 //! Change the appearance of a checkbox.
+use iced_core::animation::Interpolable;
 use iced_core::{Background, Border, Color};
 
 /// The appearance of a checkbox.
@@ -14,6 +15,23 @@ pub struct Appearance {
     pub text_color: Option<Color>,
 }
 
+impl Interpolable for Appearance {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, ratio),
+            icon_color: self.icon_color.interpolate(&other.icon_color, ratio),
+            border: self.border.interpolate(&other.border, ratio),
+            // A missing text color inherits the default one, so it cannot
+            // fade like a missing background would
+            text_color: match (self.text_color, other.text_color) {
+                (Some(a), Some(b)) => Some(a.interpolate(&b, ratio)),
+                _ if ratio < 0.5 => self.text_color,
+                _ => other.text_color,
+            },
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a checkbox.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
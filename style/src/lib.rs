@@ -29,6 +29,8 @@ pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod svg;
+pub mod table;
+pub mod tabs;
 pub mod text_editor;
 pub mod text_input;
 pub mod theme;
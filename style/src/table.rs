@@ -0,0 +1,36 @@
+//! Change the appearance of a table.
+use iced_core::{Background, Color};
+
+/// The appearance of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the header row.
+    pub header_background: Option<Background>,
+    /// The text [`Color`] of the header row.
+    pub header_text_color: Color,
+    /// The [`Background`] of the rows.
+    pub row_background: Option<Background>,
+    /// The [`Background`] of every other row, if different.
+    pub alternate_row_background: Option<Background>,
+    /// The [`Background`] of a hovered row.
+    pub hovered_row_background: Option<Background>,
+    /// The [`Background`] of a selected row.
+    pub selected_row_background: Option<Background>,
+    /// The text [`Color`] of a selected row.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the lines dividing rows and columns.
+    pub divider: Option<Color>,
+    /// The [`Color`] of the sort indicator.
+    pub sort_indicator: Color,
+    /// The [`Color`] of a hovered or dragged resize handle.
+    pub resize_handle: Color,
+}
+
+/// A set of rules that dictate the style of a table.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a table.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
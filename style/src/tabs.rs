@@ -0,0 +1,38 @@
+//! Change the appearance of tabs.
+use iced_core::{Background, Border, Color};
+
+/// The appearance of a tab.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the tab.
+    pub background: Option<Background>,
+    /// The text [`Color`] of the tab.
+    pub text_color: Color,
+    /// The [`Color`] of the close button of the tab.
+    pub close_color: Color,
+    /// The [`Border`] of the tab.
+    pub border: Border,
+}
+
+/// A set of rules that dictate the style of tabs.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Returns the [`Background`] of the tab bar.
+    fn bar(&self, style: &Self::Style) -> Option<Background>;
+
+    /// Returns the active [`Appearance`] of a tab for the provided [`Style`].
+    ///
+    /// [`Style`]: Self::Style
+    fn active(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+
+    /// Returns the hovered [`Appearance`] of a tab for the provided [`Style`].
+    ///
+    /// [`Style`]: Self::Style
+    fn hovered(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+
+    /// Returns the [`Color`] of the indicator shown where a dragged tab
+    /// would be dropped.
+    fn drop_indicator(&self, style: &Self::Style) -> Color;
+}
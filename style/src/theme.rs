@@ -17,6 +17,8 @@ use crate::rule;
 use crate::scrollable;
 use crate::slider;
 use crate::svg;
+use crate::table;
+use crate::tabs;
 use crate::text_editor;
 use crate::text_input;
 use crate::toggler;
@@ -966,6 +968,162 @@ impl svg::StyleSheet for fn(&Theme) -> svg::Appearance {
     }
 }
 
+/// The style of a table.
+#[derive(Default)]
+pub enum Table {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn table::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> table::Appearance + 'static> From<T> for Table {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl table::StyleSheet for Theme {
+    type Style = Table;
+
+    fn appearance(&self, style: &Self::Style) -> table::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Table::Default => table::Appearance {
+                header_background: Some(palette.background.weak.color.into()),
+                header_text_color: palette.background.weak.text,
+                row_background: None,
+                alternate_row_background: Some(
+                    Color {
+                        a: 0.5,
+                        ..palette.background.weak.color
+                    }
+                    .into(),
+                ),
+                hovered_row_background: Some(
+                    Color {
+                        a: 0.3,
+                        ..palette.primary.weak.color
+                    }
+                    .into(),
+                ),
+                selected_row_background: Some(
+                    palette.primary.weak.color.into(),
+                ),
+                selected_text_color: palette.primary.weak.text,
+                divider: Some(palette.background.strong.color),
+                sort_indicator: palette.background.weak.text,
+                resize_handle: palette.primary.base.color,
+            },
+            Table::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> table::Appearance> table::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> table::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of tabs.
+#[derive(Default)]
+pub enum Tabs {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn tabs::StyleSheet<Style = Theme>>),
+}
+
+impl tabs::StyleSheet for Theme {
+    type Style = Tabs;
+
+    fn bar(&self, style: &Self::Style) -> Option<Background> {
+        match style {
+            Tabs::Default => {
+                let palette = self.extended_palette();
+
+                Some(palette.background.weak.color.into())
+            }
+            Tabs::Custom(custom) => custom.bar(self),
+        }
+    }
+
+    fn active(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+    ) -> tabs::Appearance {
+        match style {
+            Tabs::Default => {
+                let palette = self.extended_palette();
+
+                if is_selected {
+                    tabs::Appearance {
+                        background: Some(palette.background.base.color.into()),
+                        text_color: palette.background.base.text,
+                        close_color: palette.background.base.text,
+                        border: Border {
+                            radius: [4.0, 4.0, 0.0, 0.0].into(),
+                            ..Border::default()
+                        },
+                    }
+                } else {
+                    tabs::Appearance {
+                        background: None,
+                        text_color: palette.background.weak.text,
+                        close_color: palette.background.strong.color,
+                        border: Border::default(),
+                    }
+                }
+            }
+            Tabs::Custom(custom) => custom.active(self, is_selected),
+        }
+    }
+
+    fn hovered(
+        &self,
+        style: &Self::Style,
+        is_selected: bool,
+    ) -> tabs::Appearance {
+        match style {
+            Tabs::Default => {
+                let palette = self.extended_palette();
+                let active = self.active(style, is_selected);
+
+                if is_selected {
+                    active
+                } else {
+                    tabs::Appearance {
+                        background: Some(
+                            palette.background.strong.color.into(),
+                        ),
+                        text_color: palette.background.strong.text,
+                        close_color: palette.background.strong.text,
+                        border: Border {
+                            radius: [4.0, 4.0, 0.0, 0.0].into(),
+                            ..active.border
+                        },
+                    }
+                }
+            }
+            Tabs::Custom(custom) => custom.hovered(self, is_selected),
+        }
+    }
+
+    fn drop_indicator(&self, style: &Self::Style) -> Color {
+        match style {
+            Tabs::Default => self.extended_palette().primary.base.color,
+            Tabs::Custom(custom) => custom.drop_indicator(self),
+        }
+    }
+}
+
 /// The style of a scrollable.
 #[derive(Default)]
 pub enum Scrollable {
@@ -1092,9 +1250,17 @@ impl text::StyleSheet for Theme {
     type Style = Text;
 
     fn appearance(&self, style: Self::Style) -> text::Appearance {
+        let selection = Some(self.extended_palette().primary.weak.color);
+
         match style {
-            Text::Default => text::Appearance::default(),
-            Text::Color(c) => text::Appearance { color: Some(c) },
+            Text::Default => text::Appearance {
+                color: None,
+                selection,
+            },
+            Text::Color(c) => text::Appearance {
+                color: Some(c),
+                selection,
+            },
         }
     }
 }
@@ -1,4 +1,5 @@
 //! Change the appearance of a toggler.
+use iced_core::animation::Interpolable;
 use iced_core::Color;
 
 /// The appearance of a toggler.
@@ -14,6 +15,21 @@ pub struct Appearance {
     pub foreground_border: Option<Color>,
 }
 
+impl Interpolable for Appearance {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, ratio),
+            background_border: self
+                .background_border
+                .interpolate(&other.background_border, ratio),
+            foreground: self.foreground.interpolate(&other.foreground, ratio),
+            foreground_border: self
+                .foreground_border
+                .interpolate(&other.foreground_border, ratio),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a toggler.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
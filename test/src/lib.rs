@@ -0,0 +1,557 @@
+//! Test your `iced` user interfaces in headless mode.
+//!
+//! A [`Simulator`] mounts an [`Element`] against a CPU renderer, lets you
+//! find widgets, interact with them and collect the messages they produce.
+//! It can also take a [`Snapshot`] of the user interface and compare it
+//! against a PNG image stored on disk.
+//!
+//! Stored images are never written implicitly. Run your tests with the
+//! `ICED_TEST_UPDATE` environment variable set to store new snapshots or
+//! replace outdated ones.
+//!
+//! ```no_run
+//! use iced_test::{Error, Simulator};
+//! use iced_widget::{button, text};
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     Increment,
+//! }
+//!
+//! # fn main() -> Result<(), Error> {
+//! let mut ui = Simulator::new(button(text("+")).on_press(Message::Increment));
+//!
+//! let _ = ui.click("+")?;
+//!
+//! assert!(ui
+//!     .snapshot(&iced_style::Theme::Light)?
+//!     .matches_image("snapshots/increment.png", 0)?);
+//!
+//! assert_eq!(ui.into_messages().collect::<Vec<_>>(), [Message::Increment]);
+//! # Ok(())
+//! # }
+//! ```
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
+)]
+#![forbid(unsafe_code, rust_2018_idioms)]
+#![deny(
+    missing_debug_implementations,
+    missing_docs,
+    unused_results,
+    rustdoc::broken_intra_doc_links
+)]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub mod selector;
+
+pub use iced_renderer as renderer;
+pub use iced_runtime as runtime;
+pub use iced_runtime::core;
+
+pub use selector::Selector;
+
+use crate::core::clipboard;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::renderer::Style;
+use crate::core::{Element, Font, Pixels, Point, Size};
+use crate::renderer::graphics::Viewport;
+use crate::renderer::Renderer;
+use crate::runtime::user_interface::{self, UserInterface};
+use crate::runtime::window::Screenshot;
+
+use iced_style::application;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that makes [`Snapshot::matches_image`] store
+/// every [`Snapshot`] instead of comparing it.
+pub const UPDATE_VARIABLE: &str = "ICED_TEST_UPDATE";
+
+/// A user interface that can be interacted with and inspected in tests.
+#[allow(missing_debug_implementations)]
+pub struct Simulator<'a, Message, Theme = iced_style::Theme> {
+    raw: UserInterface<'a, Message, Theme, Renderer>,
+    renderer: Renderer,
+    size: Size,
+    scale_factor: f64,
+    cursor: mouse::Cursor,
+    messages: Vec<Message>,
+}
+
+/// The settings of a [`Simulator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Settings {
+    /// The logical size of the user interface.
+    ///
+    /// By default, it is `1024x768`.
+    pub size: Size,
+
+    /// The scale factor used to take a [`Snapshot`].
+    ///
+    /// By default, it is `1.0`.
+    pub scale_factor: f64,
+
+    /// The default [`Font`] to use.
+    pub default_font: Font,
+
+    /// The default size of text.
+    ///
+    /// By default, it is `16.0`.
+    pub default_text_size: Pixels,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            size: Size::new(1024.0, 768.0),
+            scale_factor: 1.0,
+            default_font: Font::default(),
+            default_text_size: Pixels(16.0),
+        }
+    }
+}
+
+impl<'a, Message, Theme> Simulator<'a, Message, Theme> {
+    /// Creates a new [`Simulator`] of the given [`Element`] with the default
+    /// [`Settings`].
+    pub fn new(
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self::with_settings(Settings::default(), element)
+    }
+
+    /// Creates a new [`Simulator`] of the given [`Element`] with the given
+    /// [`Settings`].
+    pub fn with_settings(
+        settings: Settings,
+        element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let mut renderer = Renderer::headless(iced_renderer::Settings {
+            default_font: settings.default_font,
+            default_text_size: settings.default_text_size,
+            ..iced_renderer::Settings::default()
+        });
+
+        let raw = UserInterface::build(
+            element,
+            settings.size,
+            user_interface::Cache::default(),
+            &mut renderer,
+        );
+
+        Self {
+            raw,
+            renderer,
+            size: settings.size,
+            scale_factor: settings.scale_factor,
+            cursor: mouse::Cursor::Unavailable,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Finds the first widget matching the given [`Selector`].
+    pub fn find(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<selector::Target, Error> {
+        let selector = selector.into();
+        let mut find = selector::Find::new(selector.clone());
+
+        self.raw.operate(&self.renderer, &mut find);
+
+        find.target().ok_or(Error::NotFound(selector))
+    }
+
+    /// Moves the mouse cursor to the center of the first widget matching the
+    /// given [`Selector`] and clicks it with the left button.
+    pub fn click(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<selector::Target, Error> {
+        let target = self.find(selector)?;
+
+        self.point_at(target.center());
+
+        let _ = self.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        Ok(target)
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn point_at(&mut self, position: impl Into<Point>) {
+        let position = position.into();
+
+        self.cursor = mouse::Cursor::Available(position);
+
+        let _ = self
+            .simulate([Event::Mouse(mouse::Event::CursorMoved { position })]);
+    }
+
+    /// Presses and releases the given key.
+    pub fn tap_key(&mut self, key: impl Into<keyboard::Key>) -> event::Status {
+        let key = key.into();
+
+        self.simulate([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: key.clone(),
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::default(),
+                text: None,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key,
+                location: keyboard::Location::Standard,
+                modifiers: keyboard::Modifiers::default(),
+            }),
+        ])
+        .into_iter()
+        .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn typewrite(&mut self, text: &str) -> event::Status {
+        let events: Vec<_> = text
+            .chars()
+            .flat_map(|c| {
+                let key = keyboard::Key::Character(c.to_string().into());
+
+                [
+                    Event::Keyboard(keyboard::Event::KeyPressed {
+                        key: key.clone(),
+                        location: keyboard::Location::Standard,
+                        modifiers: keyboard::Modifiers::default(),
+                        text: Some(c.to_string().into()),
+                    }),
+                    Event::Keyboard(keyboard::Event::KeyReleased {
+                        key,
+                        location: keyboard::Location::Standard,
+                        modifiers: keyboard::Modifiers::default(),
+                    }),
+                ]
+            })
+            .collect();
+
+        self.simulate(events)
+            .into_iter()
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Feeds the given events to the user interface and returns the
+    /// [`event::Status`] of each one of them.
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        let events: Vec<Event> = events.into_iter().collect();
+
+        let (_state, statuses) = self.raw.update(
+            &events,
+            self.cursor,
+            &mut self.renderer,
+            &mut clipboard::Null,
+            &mut self.messages,
+        );
+
+        statuses
+    }
+
+    /// Draws the user interface with the given `Theme` and takes a
+    /// [`Snapshot`] of it.
+    pub fn snapshot(&mut self, theme: &Theme) -> Result<Snapshot, Error>
+    where
+        Theme: application::StyleSheet,
+    {
+        let viewport = Viewport::with_physical_size(
+            Size::new(
+                (f64::from(self.size.width) * self.scale_factor).round() as u32,
+                (f64::from(self.size.height) * self.scale_factor).round()
+                    as u32,
+            ),
+            self.scale_factor,
+        );
+
+        let appearance = theme.appearance(&Theme::Style::default());
+        let style = Style {
+            text_color: appearance.text_color,
+        };
+
+        let _ = self
+            .raw
+            .draw(&mut self.renderer, theme, &style, self.cursor);
+
+        #[cfg(feature = "image")]
+        while self.renderer.load_images() {
+            let _ =
+                self.raw
+                    .draw(&mut self.renderer, theme, &style, self.cursor);
+        }
+
+        let bytes = self
+            .renderer
+            .screenshot(&viewport, appearance.background_color)
+            .ok_or(Error::Unsupported)?;
+
+        Ok(Snapshot {
+            screenshot: Screenshot::new(bytes, viewport.physical_size()),
+        })
+    }
+
+    /// Consumes the [`Simulator`] and returns the messages produced by the
+    /// user interface so far.
+    pub fn into_messages(self) -> impl Iterator<Item = Message> {
+        self.messages.into_iter()
+    }
+}
+
+/// A rendered frame of a user interface.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    screenshot: Screenshot,
+}
+
+impl Snapshot {
+    /// Returns the [`Screenshot`] of the [`Snapshot`].
+    pub fn screenshot(&self) -> &Screenshot {
+        &self.screenshot
+    }
+
+    /// Compares the [`Snapshot`] with the PNG image at the given path.
+    ///
+    /// Each channel of every pixel may differ by at most `tolerance`.
+    ///
+    /// If the [`UPDATE_VARIABLE`] is set, the [`Snapshot`] is stored at the
+    /// given path instead and considered a match. Otherwise, a missing image
+    /// produces an [`Error::MissingImage`].
+    pub fn matches_image(
+        &self,
+        path: impl AsRef<Path>,
+        tolerance: u8,
+    ) -> Result<bool, Error> {
+        let path = path.as_ref();
+
+        if env::var_os(UPDATE_VARIABLE).is_some() {
+            self.save(path)?;
+
+            return Ok(true);
+        }
+
+        if !path.exists() {
+            return Err(Error::MissingImage(path.to_path_buf()));
+        }
+
+        let decoder = png::Decoder::new(fs::File::open(path)?);
+        let mut reader = decoder.read_info()?;
+
+        let mut bytes = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut bytes)?;
+
+        if info.color_type != png::ColorType::Rgba
+            || info.bit_depth != png::BitDepth::Eight
+        {
+            return Err(Error::UnsupportedFormat);
+        }
+
+        if Size::new(info.width, info.height) != self.screenshot.size {
+            return Ok(false);
+        }
+
+        Ok(bytes[..info.buffer_size()]
+            .iter()
+            .zip(self.screenshot.bytes.iter())
+            .all(|(a, b)| a.abs_diff(*b) <= tolerance))
+    }
+
+    /// Stores the [`Snapshot`] as a PNG image at the given path, creating
+    /// any missing directories.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, self.encode()?)?;
+
+        Ok(())
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+
+        {
+            let mut encoder = png::Encoder::new(
+                &mut bytes,
+                self.screenshot.size.width,
+                self.screenshot.size.height,
+            );
+
+            encoder.set_color(png::ColorType::Rgba);
+            encoder.set_depth(png::BitDepth::Eight);
+
+            let mut writer = encoder.write_header()?;
+            writer.write_image_data(&self.screenshot.bytes)?;
+            writer.finish()?;
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// An error produced while testing a user interface.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// No widget matched the [`Selector`].
+    #[error("no widget matched the selector: {0:?}")]
+    NotFound(Selector),
+
+    /// The renderer cannot take snapshots.
+    #[error("the renderer cannot take snapshots")]
+    Unsupported,
+
+    /// The image to compare with does not exist.
+    #[error(
+        "the image {} does not exist, set {UPDATE_VARIABLE} to store it",
+        .0.display()
+    )]
+    MissingImage(PathBuf),
+
+    /// The stored image is not an 8-bit RGBA PNG.
+    #[error("the stored image is not an 8-bit RGBA PNG")]
+    UnsupportedFormat,
+
+    /// An I/O operation failed.
+    #[error("an I/O operation failed: {0}")]
+    Io(String),
+
+    /// A PNG image could not be encoded.
+    #[error("the snapshot could not be encoded: {0}")]
+    Encoding(String),
+
+    /// A PNG image could not be decoded.
+    #[error("the image could not be decoded: {0}")]
+    Decoding(String),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error.to_string())
+    }
+}
+
+impl From<png::EncodingError> for Error {
+    fn from(error: png::EncodingError) -> Self {
+        Self::Encoding(error.to_string())
+    }
+}
+
+impl From<png::DecodingError> for Error {
+    fn from(error: png::DecodingError) -> Self {
+        Self::Decoding(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::widget;
+    use iced_widget::{button, column, text, text_input};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Increment,
+        Input(String),
+    }
+
+    #[test]
+    fn click_and_typewrite_produce_messages() {
+        let id = text_input::Id::new("input");
+
+        let mut ui = Simulator::<Message>::new(column![
+            button(text("+")).on_press(Message::Increment),
+            text_input("Type something", "")
+                .id(id.clone())
+                .on_input(Message::Input),
+        ]);
+
+        let _ = ui.click("+").expect("Find button");
+        let _ = ui.click(widget::Id::from(id)).expect("Find text input");
+        let _ = ui.typewrite("a");
+
+        assert!(matches!(ui.find("-"), Err(Error::NotFound(_))));
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [Message::Increment, Message::Input(String::from("a"))]
+        );
+    }
+
+    #[test]
+    fn snapshot_matches_stored_image() {
+        let path = std::env::temp_dir()
+            .join(format!("iced_test-{}", std::process::id()))
+            .join("snapshot.png");
+
+        let mut ui = Simulator::<Message>::with_settings(
+            Settings {
+                size: Size::new(40.0, 20.0),
+                ..Settings::default()
+            },
+            text("Hello"),
+        );
+
+        let snapshot = ui.snapshot(&iced_style::Theme::Light).unwrap();
+
+        assert!(matches!(
+            snapshot.matches_image(&path, 0),
+            Err(Error::MissingImage(_))
+        ));
+        assert!(!path.exists());
+
+        snapshot.save(&path).unwrap();
+
+        assert!(snapshot.matches_image(&path, 0).unwrap());
+
+        let dark = ui.snapshot(&iced_style::Theme::Dark).unwrap();
+
+        assert!(!dark.matches_image(&path, 10).unwrap());
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn snapshot_waits_for_images() {
+        use crate::core::{ContentFit, Length};
+        use iced_widget::image;
+
+        let red = Snapshot {
+            screenshot: Screenshot::new(
+                [255, 0, 0, 255].repeat(4 * 4),
+                Size::new(4, 4),
+            ),
+        };
+
+        let handle = image::Handle::from_memory(red.encode().unwrap());
+
+        let mut ui = Simulator::<Message>::with_settings(
+            Settings {
+                size: Size::new(4.0, 4.0),
+                ..Settings::default()
+            },
+            image(handle)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .content_fit(ContentFit::Fill),
+        );
+
+        let snapshot = ui.snapshot(&iced_style::Theme::Light).unwrap();
+
+        assert_eq!(snapshot.screenshot().bytes, red.screenshot.bytes);
+    }
+}
@@ -0,0 +1,136 @@
+//! Select widgets of a user interface.
+use crate::core::widget::operation::Accessible;
+use crate::core::widget::{self, Operation};
+use crate::core::{Point, Rectangle, Vector};
+
+/// A selector describes a strategy to find a certain widget in a user
+/// interface.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selector {
+    /// Find the widget with the given [`widget::Id`].
+    Id(widget::Id),
+
+    /// Find the widget displaying or labeled with the given text.
+    Text(String),
+}
+
+impl From<widget::Id> for Selector {
+    fn from(id: widget::Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&str> for Selector {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_owned())
+    }
+}
+
+impl From<String> for Selector {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+/// A widget found by a [`Selector`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Target {
+    /// The bounds of the widget.
+    pub bounds: Rectangle,
+}
+
+impl Target {
+    /// Returns the center of the [`Target`].
+    pub fn center(&self) -> Point {
+        self.bounds.center()
+    }
+}
+
+/// An [`Operation`] that finds the first widget matching a [`Selector`].
+#[derive(Debug)]
+pub struct Find {
+    selector: Selector,
+    target: Option<Target>,
+}
+
+impl Find {
+    /// Creates a new [`Find`] operation for the given [`Selector`].
+    pub fn new(selector: Selector) -> Self {
+        Self {
+            selector,
+            target: None,
+        }
+    }
+
+    /// Returns the [`Target`] found, if any.
+    pub fn target(&self) -> Option<Target> {
+        self.target
+    }
+
+    fn select(&mut self, id: Option<&widget::Id>, bounds: Rectangle) {
+        if self.target.is_some() {
+            return;
+        }
+
+        if let Selector::Id(target) = &self.selector {
+            if id == Some(target) {
+                self.target = Some(Target { bounds });
+            }
+        }
+    }
+
+    fn select_text(&mut self, bounds: Rectangle, text: &str) {
+        if self.target.is_some() {
+            return;
+        }
+
+        if let Selector::Text(target) = &self.selector {
+            if text == target {
+                self.target = Some(Target { bounds });
+            }
+        }
+    }
+}
+
+impl<T> Operation<T> for Find {
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.select(id, bounds);
+
+        if self.target.is_none() {
+            operate_on_children(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn widget::operation::Scrollable,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        _translation: Vector,
+    ) {
+        self.select(id, bounds);
+    }
+
+    fn text(&mut self, id: Option<&widget::Id>, bounds: Rectangle, text: &str) {
+        self.select(id, bounds);
+        self.select_text(bounds, text);
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        accessible: Accessible<'_>,
+    ) {
+        self.select(id, bounds);
+
+        if let Some(label) = accessible.label {
+            self.select_text(bounds, label);
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use tiny_skia::Size;
 
+use crate::core::font;
 use crate::core::{Background, Color, Gradient, Rectangle, Vector};
+use crate::gradient;
+use crate::graphics;
 use crate::graphics::backend;
 use crate::graphics::text;
 use crate::graphics::Viewport;
@@ -10,6 +13,7 @@ use std::borrow::Cow;
 
 pub struct Backend {
     text_pipeline: crate::text::Pipeline,
+    gradient_cache: gradient::Cache,
 
     #[cfg(feature = "image")]
     raster_pipeline: crate::raster::Pipeline,
@@ -22,6 +26,7 @@ impl Backend {
     pub fn new() -> Self {
         Self {
             text_pipeline: crate::text::Pipeline::new(),
+            gradient_cache: gradient::Cache::default(),
 
             #[cfg(feature = "image")]
             raster_pipeline: crate::raster::Pipeline::new(),
@@ -131,6 +136,7 @@ impl Backend {
         }
 
         self.text_pipeline.trim_cache();
+        self.gradient_cache.trim();
 
         #[cfg(feature = "image")]
         self.raster_pipeline.trim_cache();
@@ -259,6 +265,22 @@ impl Backend {
                     }
                 }
 
+                let conic = match background {
+                    Background::Gradient(Gradient::Conic(conic)) => {
+                        self.gradient_cache.conic(
+                            &graphics::gradient::Conic {
+                                center: conic.to_absolute(bounds),
+                                angle: conic.angle,
+                                stops: conic.stops,
+                            },
+                            transform,
+                            physical_bounds,
+                            clip_bounds,
+                        )
+                    }
+                    _ => None,
+                };
+
                 pixels.fill_path(
                     &path,
                     &tiny_skia::Paint {
@@ -272,43 +294,25 @@ impl Backend {
                                 let (start, end) =
                                     linear.angle.to_distance(bounds);
 
-                                let stops: Vec<tiny_skia::GradientStop> =
-                                    linear
-                                        .stops
-                                        .into_iter()
-                                        .flatten()
-                                        .map(|stop| {
-                                            tiny_skia::GradientStop::new(
-                                                stop.offset,
-                                                tiny_skia::Color::from_rgba(
-                                                    stop.color.b,
-                                                    stop.color.g,
-                                                    stop.color.r,
-                                                    stop.color.a,
-                                                )
-                                                .expect("Create color"),
-                                            )
-                                        })
-                                        .collect();
-
-                                tiny_skia::LinearGradient::new(
-                                    tiny_skia::Point {
-                                        x: start.x,
-                                        y: start.y,
-                                    },
-                                    tiny_skia::Point { x: end.x, y: end.y },
-                                    if stops.is_empty() {
-                                        vec![tiny_skia::GradientStop::new(
-                                            0.0,
-                                            tiny_skia::Color::BLACK,
-                                        )]
-                                    } else {
-                                        stops
-                                    },
-                                    tiny_skia::SpreadMode::Pad,
-                                    tiny_skia::Transform::identity(),
-                                )
-                                .expect("Create linear gradient")
+                                gradient::linear(start, end, &linear.stops)
+                            }
+                            Background::Gradient(Gradient::Radial(radial)) => {
+                                let (center, radius) =
+                                    radial.to_absolute(bounds);
+
+                                gradient::radial(center, radius, &radial.stops)
+                            }
+                            Background::Gradient(Gradient::Conic(_)) => {
+                                match conic {
+                                    Some((pattern, bounds)) => {
+                                        gradient::pattern(
+                                            pattern, transform, bounds,
+                                        )
+                                    }
+                                    None => tiny_skia::Shader::SolidColor(
+                                        tiny_skia::Color::TRANSPARENT,
+                                    ),
+                                }
                             }
                         },
                         anti_alias: true,
@@ -495,6 +499,7 @@ impl Backend {
                 horizontal_alignment,
                 vertical_alignment,
                 shaping,
+                wrapping,
                 clip_bounds: text_clip_bounds,
             } => {
                 let physical_bounds =
@@ -517,6 +522,7 @@ impl Backend {
                     *horizontal_alignment,
                     *vertical_alignment,
                     *shaping,
+                    *wrapping,
                     scale_factor,
                     pixels,
                     clip_mask,
@@ -556,8 +562,13 @@ impl Backend {
                 handle,
                 filter_method,
                 bounds,
+                rotation,
+                opacity,
+                tint,
+                border_radius,
             } => {
-                let physical_bounds = (*bounds + translation) * scale_factor;
+                let physical_bounds =
+                    (bounds.rotate(*rotation) + translation) * scale_factor;
 
                 if !clip_bounds.intersects(&physical_bounds) {
                     return;
@@ -576,6 +587,10 @@ impl Backend {
                     handle,
                     *filter_method,
                     *bounds,
+                    *rotation,
+                    *opacity,
+                    *tint,
+                    (*border_radius).into(),
                     pixels,
                     transform,
                     clip_mask,
@@ -592,8 +607,13 @@ impl Backend {
                 handle,
                 bounds,
                 color,
+                rotation,
+                opacity,
+                tint,
+                border_radius,
             } => {
-                let physical_bounds = (*bounds + translation) * scale_factor;
+                let physical_bounds =
+                    (bounds.rotate(*rotation) + translation) * scale_factor;
 
                 if !clip_bounds.intersects(&physical_bounds) {
                     return;
@@ -602,10 +622,22 @@ impl Backend {
                 let clip_mask = (!physical_bounds.is_within(&clip_bounds))
                     .then_some(clip_mask as &_);
 
+                let [top_left, top_right, bottom_right, bottom_left]: [f32; 4] =
+                    (*border_radius).into();
+
                 self.vector_pipeline.draw(
                     handle,
                     *color,
                     (*bounds + translation) * scale_factor,
+                    *rotation,
+                    *opacity,
+                    *tint,
+                    [
+                        top_left * scale_factor,
+                        top_right * scale_factor,
+                        bottom_right * scale_factor,
+                        bottom_left * scale_factor,
+                    ],
                     pixels,
                     clip_mask,
                 );
@@ -620,6 +652,7 @@ impl Backend {
                 path,
                 paint,
                 rule,
+                conic,
             }) => {
                 let bounds = path.bounds();
 
@@ -638,22 +671,43 @@ impl Backend {
                 let clip_mask = (!physical_bounds.is_within(&clip_bounds))
                     .then_some(clip_mask as &_);
 
-                pixels.fill_path(
-                    path,
-                    paint,
-                    *rule,
-                    tiny_skia::Transform::from_translate(
-                        translation.x,
-                        translation.y,
+                let transform = tiny_skia::Transform::from_translate(
+                    translation.x,
+                    translation.y,
+                )
+                .post_scale(scale_factor, scale_factor);
+
+                let pattern = conic.as_ref().and_then(|conic| {
+                    self.gradient_cache.conic(
+                        conic,
+                        transform,
+                        physical_bounds,
+                        clip_bounds,
                     )
-                    .post_scale(scale_factor, scale_factor),
-                    clip_mask,
-                );
+                });
+
+                let pattern_paint;
+                let paint = match pattern {
+                    Some((pattern, bounds)) => {
+                        pattern_paint = tiny_skia::Paint {
+                            shader: gradient::pattern(
+                                pattern, transform, bounds,
+                            ),
+                            ..paint.clone()
+                        };
+
+                        &pattern_paint
+                    }
+                    None => paint,
+                };
+
+                pixels.fill_path(path, paint, *rule, transform, clip_mask);
             }
             Primitive::Custom(primitive::Custom::Stroke {
                 path,
                 paint,
                 stroke,
+                conic,
             }) => {
                 let bounds = path.bounds();
 
@@ -672,17 +726,37 @@ impl Backend {
                 let clip_mask = (!physical_bounds.is_within(&clip_bounds))
                     .then_some(clip_mask as &_);
 
-                pixels.stroke_path(
-                    path,
-                    paint,
-                    stroke,
-                    tiny_skia::Transform::from_translate(
-                        translation.x,
-                        translation.y,
+                let transform = tiny_skia::Transform::from_translate(
+                    translation.x,
+                    translation.y,
+                )
+                .post_scale(scale_factor, scale_factor);
+
+                let pattern = conic.as_ref().and_then(|conic| {
+                    self.gradient_cache.conic(
+                        conic,
+                        transform,
+                        physical_bounds.expand(stroke.width * scale_factor),
+                        clip_bounds,
                     )
-                    .post_scale(scale_factor, scale_factor),
-                    clip_mask,
-                );
+                });
+
+                let pattern_paint;
+                let paint = match pattern {
+                    Some((pattern, bounds)) => {
+                        pattern_paint = tiny_skia::Paint {
+                            shader: gradient::pattern(
+                                pattern, transform, bounds,
+                            ),
+                            ..paint.clone()
+                        };
+
+                        &pattern_paint
+                    }
+                    None => paint,
+                };
+
+                pixels.stroke_path(path, paint, stroke, transform, clip_mask);
             }
             Primitive::Group { primitives } => {
                 for primitive in primitives {
@@ -961,13 +1035,82 @@ fn rounded_box_sdf(to_center: Vector, size: Size, radii: &[f32]) -> f32 {
     (x.powf(2.0) + y.powf(2.0)).sqrt() - radius
 }
 
+/// Fills the given `bounds` with an image, rotated around their center and
+/// with rounded corners.
+#[cfg(any(feature = "image", feature = "svg"))]
+pub(crate) fn draw_image(
+    image: tiny_skia::PixmapRef<'_>,
+    bounds: Rectangle,
+    quality: tiny_skia::FilterQuality,
+    rotation: crate::core::Radians,
+    opacity: f32,
+    border_radius: [f32; 4],
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    transform: tiny_skia::Transform,
+    clip_mask: Option<&tiny_skia::Mask>,
+) {
+    if bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return;
+    }
+
+    let center = bounds.center();
+
+    let pattern = tiny_skia::Pattern::new(
+        image,
+        tiny_skia::SpreadMode::Pad,
+        quality,
+        opacity,
+        tiny_skia::Transform::from_translate(bounds.x, bounds.y).pre_scale(
+            bounds.width / image.width() as f32,
+            bounds.height / image.height() as f32,
+        ),
+    );
+
+    pixels.fill_path(
+        &rounded_rectangle(bounds, border_radius),
+        &tiny_skia::Paint {
+            shader: pattern,
+            anti_alias: true,
+            ..tiny_skia::Paint::default()
+        },
+        tiny_skia::FillRule::EvenOdd,
+        transform.pre_rotate_at(
+            crate::core::Degrees::from(rotation).0,
+            center.x,
+            center.y,
+        ),
+        clip_mask,
+    );
+}
+
+/// Multiplies the premultiplied BGRA `pixels` of an image with the given
+/// [`Color`].
+#[cfg(any(feature = "image", feature = "svg"))]
+pub(crate) fn tint(pixels: &mut [u8], color: Color) {
+    let [r, g, b, a] = color.into_rgba8();
+
+    let multiply = |channel: u8, factor: u8| {
+        (u16::from(channel) * u16::from(factor) / 255) as u8
+    };
+
+    for pixel in pixels.chunks_exact_mut(4) {
+        pixel[0] = multiply(multiply(pixel[0], b), a);
+        pixel[1] = multiply(multiply(pixel[1], g), a);
+        pixel[2] = multiply(multiply(pixel[2], r), a);
+        pixel[3] = multiply(pixel[3], a);
+    }
+}
+
 impl iced_graphics::Backend for Backend {
     type Primitive = primitive::Custom;
 }
 
 impl backend::Text for Backend {
-    fn load_font(&mut self, font: Cow<'static, [u8]>) {
-        self.text_pipeline.load_font(font);
+    fn load_font(
+        &mut self,
+        font: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        self.text_pipeline.load_font(font)
     }
 }
 
@@ -979,6 +1122,10 @@ impl backend::Image for Backend {
     ) -> crate::core::Size<u32> {
         self.raster_pipeline.dimensions(handle)
     }
+
+    fn is_loading(&self, handle: &crate::core::image::Handle) -> bool {
+        self.raster_pipeline.is_loading(handle)
+    }
 }
 
 #[cfg(feature = "svg")]
@@ -1,9 +1,10 @@
-use crate::core::text::LineHeight;
-use crate::core::{Pixels, Point, Rectangle, Size, Vector};
+use crate::core::text::{LineHeight, Wrapping};
+use crate::core::{Pixels, Point, Radians, Rectangle, Size, Vector};
+use crate::gradient;
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::stroke::{self, Stroke};
 use crate::graphics::geometry::{Path, Style, Text};
-use crate::graphics::Gradient;
+use crate::graphics::{self, Gradient};
 use crate::primitive::{self, Primitive};
 
 pub struct Frame {
@@ -47,6 +48,7 @@ impl Frame {
         };
 
         let fill = fill.into();
+        let conic = into_conic(&fill.style, self.transform);
 
         let mut paint = into_paint(fill.style);
         paint.shader.transform(self.transform);
@@ -56,6 +58,7 @@ impl Frame {
                 path,
                 paint,
                 rule: into_fill_rule(fill.rule),
+                conic,
             }));
     }
 
@@ -72,6 +75,7 @@ impl Frame {
         };
 
         let fill = fill.into();
+        let conic = into_conic(&fill.style, self.transform);
 
         let mut paint = tiny_skia::Paint {
             anti_alias: false,
@@ -84,6 +88,7 @@ impl Frame {
                 path,
                 paint,
                 rule: into_fill_rule(fill.rule),
+                conic,
             }));
     }
 
@@ -96,6 +101,7 @@ impl Frame {
 
         let stroke = stroke.into();
         let skia_stroke = into_stroke(&stroke);
+        let conic = into_conic(&stroke.style, self.transform);
 
         let mut paint = into_paint(stroke.style);
         paint.shader.transform(self.transform);
@@ -105,6 +111,7 @@ impl Frame {
                 path,
                 paint,
                 stroke: skia_stroke,
+                conic,
             }));
     }
 
@@ -165,6 +172,7 @@ impl Frame {
                 horizontal_alignment: text.horizontal_alignment,
                 vertical_alignment: text.vertical_alignment,
                 shaping: text.shaping,
+                wrapping: Wrapping::default(),
                 clip_bounds: Rectangle::with_size(Size::INFINITY),
             });
         } else {
@@ -293,45 +301,16 @@ pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
             ),
             Style::Gradient(gradient) => match gradient {
                 Gradient::Linear(linear) => {
-                    let stops: Vec<tiny_skia::GradientStop> = linear
-                        .stops
-                        .into_iter()
-                        .flatten()
-                        .map(|stop| {
-                            tiny_skia::GradientStop::new(
-                                stop.offset,
-                                tiny_skia::Color::from_rgba(
-                                    stop.color.b,
-                                    stop.color.g,
-                                    stop.color.r,
-                                    stop.color.a,
-                                )
-                                .expect("Create color"),
-                            )
-                        })
-                        .collect();
-
-                    tiny_skia::LinearGradient::new(
-                        tiny_skia::Point {
-                            x: linear.start.x,
-                            y: linear.start.y,
-                        },
-                        tiny_skia::Point {
-                            x: linear.end.x,
-                            y: linear.end.y,
-                        },
-                        if stops.is_empty() {
-                            vec![tiny_skia::GradientStop::new(
-                                0.0,
-                                tiny_skia::Color::BLACK,
-                            )]
-                        } else {
-                            stops
-                        },
-                        tiny_skia::SpreadMode::Pad,
-                        tiny_skia::Transform::identity(),
-                    )
-                    .expect("Create linear gradient")
+                    gradient::linear(linear.start, linear.end, &linear.stops)
+                }
+                Gradient::Radial(radial) => gradient::radial(
+                    radial.center,
+                    radial.radius,
+                    &radial.stops,
+                ),
+                // Rasterized when drawn, see `into_conic`
+                Gradient::Conic(_) => {
+                    tiny_skia::Shader::SolidColor(tiny_skia::Color::TRANSPARENT)
                 }
             },
         },
@@ -340,6 +319,28 @@ pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
     }
 }
 
+pub fn into_conic(
+    style: &Style,
+    transform: tiny_skia::Transform,
+) -> Option<graphics::gradient::Conic> {
+    let Style::Gradient(Gradient::Conic(conic)) = style else {
+        return None;
+    };
+
+    let mut center = tiny_skia::Point {
+        x: conic.center.x,
+        y: conic.center.y,
+    };
+
+    transform.map_point(&mut center);
+
+    Some(graphics::gradient::Conic {
+        center: Point::new(center.x, center.y),
+        angle: Radians(conic.angle.0 + transform.ky.atan2(transform.sx)),
+        ..*conic
+    })
+}
+
 pub fn into_fill_rule(rule: fill::Rule) -> tiny_skia::FillRule {
     match rule {
         fill::Rule::EvenOdd => tiny_skia::FillRule::EvenOdd,
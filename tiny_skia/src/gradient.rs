@@ -0,0 +1,310 @@
+use crate::core::gradient::{ColorStop, MAX_STOPS};
+use crate::core::{Color, Point, Radians, Rectangle};
+use crate::graphics::gradient::Conic;
+
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use std::collections::hash_map;
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::hash::{Hash, Hasher};
+
+pub fn linear(
+    start: Point,
+    end: Point,
+    stops: &[Option<ColorStop>; MAX_STOPS],
+) -> tiny_skia::Shader<'static> {
+    tiny_skia::LinearGradient::new(
+        tiny_skia::Point {
+            x: start.x,
+            y: start.y,
+        },
+        tiny_skia::Point { x: end.x, y: end.y },
+        into_stops(stops),
+        tiny_skia::SpreadMode::Pad,
+        tiny_skia::Transform::identity(),
+    )
+    .expect("Create linear gradient")
+}
+
+pub fn radial(
+    center: Point,
+    radius: f32,
+    stops: &[Option<ColorStop>; MAX_STOPS],
+) -> tiny_skia::Shader<'static> {
+    let center = tiny_skia::Point {
+        x: center.x,
+        y: center.y,
+    };
+
+    tiny_skia::RadialGradient::new(
+        center,
+        center,
+        radius,
+        into_stops(stops),
+        tiny_skia::SpreadMode::Pad,
+        tiny_skia::Transform::identity(),
+    )
+    // A degenerate radius collapses the gradient into its last stop
+    .unwrap_or_else(|| {
+        tiny_skia::Shader::SolidColor(
+            stops
+                .iter()
+                .flatten()
+                .last()
+                .map_or(tiny_skia::Color::BLACK, |stop| into_color(stop.color)),
+        )
+    })
+}
+
+/// A cache of rasterized [`Conic`] gradients, since `tiny-skia` has no conic
+/// shader.
+#[derive(Debug, Default)]
+pub struct Cache {
+    entries: FxHashMap<u64, tiny_skia::Pixmap>,
+    hits: FxHashSet<u64>,
+}
+
+impl Cache {
+    /// Returns the rasterized [`Conic`] gradient.
+    ///
+    /// The `transform` maps the coordinates of the gradient to physical
+    /// pixels. Only the visible part of the physical `bounds` is rasterized,
+    /// which is returned alongside the resulting [`tiny_skia::Pixmap`].
+    pub fn conic(
+        &mut self,
+        conic: &Conic,
+        transform: tiny_skia::Transform,
+        bounds: Rectangle,
+        clip_bounds: Rectangle,
+    ) -> Option<(&tiny_skia::Pixmap, Rectangle<u32>)> {
+        let bounds = bounds.expand(1.0).intersection(&clip_bounds)?.snap();
+
+        let key = {
+            let mut hasher = FxHasher::default();
+
+            for value in [
+                conic.center.x,
+                conic.center.y,
+                conic.angle.0,
+                transform.sx,
+                transform.kx,
+                transform.ky,
+                transform.sy,
+                transform.tx,
+                transform.ty,
+            ] {
+                value.to_bits().hash(&mut hasher);
+            }
+
+            for stop in conic.stops.iter().flatten() {
+                stop.offset.to_bits().hash(&mut hasher);
+                stop.color.into_rgba8().hash(&mut hasher);
+            }
+
+            (bounds.x, bounds.y, bounds.width, bounds.height).hash(&mut hasher);
+
+            hasher.finish()
+        };
+
+        let _ = self.hits.insert(key);
+
+        let pixmap = match self.entries.entry(key) {
+            hash_map::Entry::Occupied(entry) => entry.into_mut(),
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(rasterize(conic, transform, bounds)?)
+            }
+        };
+
+        Some((pixmap, bounds))
+    }
+
+    /// Drops the gradients that were not used since the last trim.
+    pub fn trim(&mut self) {
+        self.entries.retain(|key, _| self.hits.contains(key));
+        self.hits.clear();
+    }
+}
+
+/// Rasterizes a [`Conic`] gradient at the given physical `bounds`.
+fn rasterize(
+    conic: &Conic,
+    transform: tiny_skia::Transform,
+    bounds: Rectangle<u32>,
+) -> Option<tiny_skia::Pixmap> {
+    let inverse = transform.invert()?;
+
+    let mut pixmap = tiny_skia::Pixmap::new(bounds.width, bounds.height)?;
+
+    let width = pixmap.width() as usize;
+
+    for (i, pixel) in pixmap.pixels_mut().iter_mut().enumerate() {
+        let mut point = tiny_skia::Point {
+            x: (bounds.x as usize + i % width) as f32 + 0.5,
+            y: (bounds.y as usize + i / width) as f32 + 0.5,
+        };
+
+        inverse.map_point(&mut point);
+
+        let offset = conic_offset(
+            Point::new(point.x, point.y),
+            conic.center,
+            conic.angle,
+        );
+
+        *pixel = into_color(sample(&conic.stops, offset))
+            .premultiply()
+            .to_color_u8();
+    }
+
+    Some(pixmap)
+}
+
+/// Returns a [`tiny_skia::Shader`] that draws the given `pattern` at the
+/// physical `bounds` when painted with the given `transform`.
+pub fn pattern(
+    pattern: &tiny_skia::Pixmap,
+    transform: tiny_skia::Transform,
+    bounds: Rectangle<u32>,
+) -> tiny_skia::Shader<'_> {
+    tiny_skia::Pattern::new(
+        pattern.as_ref(),
+        tiny_skia::SpreadMode::Pad,
+        tiny_skia::FilterQuality::Nearest,
+        1.0,
+        tiny_skia::Transform::from_translate(bounds.x as f32, bounds.y as f32)
+            .post_concat(transform.invert().unwrap_or_default()),
+    )
+}
+
+fn conic_offset(point: Point, center: Point, angle: Radians) -> f32 {
+    let turn =
+        (point.y - center.y).atan2(point.x - center.x) + FRAC_PI_2 - angle.0;
+
+    (turn / TAU).rem_euclid(1.0)
+}
+
+fn sample(stops: &[Option<ColorStop>; MAX_STOPS], offset: f32) -> Color {
+    let mut stops = stops.iter().flatten();
+
+    let Some(mut previous) = stops.next() else {
+        return Color::BLACK;
+    };
+
+    if offset <= previous.offset {
+        return previous.color;
+    }
+
+    for stop in stops {
+        if offset <= stop.offset {
+            let factor =
+                (offset - previous.offset) / (stop.offset - previous.offset);
+
+            return Color {
+                r: previous.color.r
+                    + (stop.color.r - previous.color.r) * factor,
+                g: previous.color.g
+                    + (stop.color.g - previous.color.g) * factor,
+                b: previous.color.b
+                    + (stop.color.b - previous.color.b) * factor,
+                a: previous.color.a
+                    + (stop.color.a - previous.color.a) * factor,
+            };
+        }
+
+        previous = stop;
+    }
+
+    previous.color
+}
+
+fn into_stops(
+    stops: &[Option<ColorStop>; MAX_STOPS],
+) -> Vec<tiny_skia::GradientStop> {
+    let stops: Vec<_> = stops
+        .iter()
+        .flatten()
+        .map(|stop| {
+            tiny_skia::GradientStop::new(stop.offset, into_color(stop.color))
+        })
+        .collect();
+
+    if stops.is_empty() {
+        vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
+    } else {
+        stops
+    }
+}
+
+fn into_color(color: Color) -> tiny_skia::Color {
+    tiny_skia::Color::from_rgba(color.b, color.g, color.r, color.a)
+        .expect("Create color")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Size;
+
+    #[test]
+    fn conic_offset_turns_clockwise_from_the_angle() {
+        let center = Point::new(10.0, 10.0);
+        let offset = |x, y, angle| {
+            conic_offset(Point::new(x, y), center, Radians(angle))
+        };
+
+        assert_eq!(offset(10.0, 0.0, 0.0), 0.0);
+        assert_eq!(offset(20.0, 10.0, 0.0), 0.25);
+        assert_eq!(offset(10.0, 20.0, 0.0), 0.5);
+        assert_eq!(offset(0.0, 10.0, 0.0), 0.75);
+        assert_eq!(offset(20.0, 10.0, FRAC_PI_2), 0.0);
+        assert_eq!(offset(10.0, 0.0, FRAC_PI_2), 0.75);
+    }
+
+    #[test]
+    fn sample_interpolates_between_stops() {
+        let mut stops = [None; MAX_STOPS];
+        stops[0] = Some(ColorStop {
+            offset: 0.25,
+            color: Color::BLACK,
+        });
+        stops[1] = Some(ColorStop {
+            offset: 0.75,
+            color: Color::WHITE,
+        });
+
+        assert_eq!(sample(&[None; MAX_STOPS], 0.5), Color::BLACK);
+        assert_eq!(sample(&stops, 0.0), Color::BLACK);
+        assert_eq!(sample(&stops, 0.5), Color::from_rgb(0.5, 0.5, 0.5));
+        assert_eq!(sample(&stops, 1.0), Color::WHITE);
+    }
+
+    #[test]
+    fn conics_are_rasterized_once_until_trimmed() {
+        let mut cache = Cache::default();
+        let conic = Conic::new(Point::new(5.0, 5.0), Radians(0.0))
+            .add_stop(0.0, Color::BLACK)
+            .add_stop(1.0, Color::WHITE);
+
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0));
+        let clip_bounds =
+            Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let transform = tiny_skia::Transform::identity();
+
+        let (_, physical) = cache
+            .conic(&conic, transform, bounds, clip_bounds)
+            .expect("Rasterize conic");
+
+        assert_eq!((physical.width, physical.height), (11, 11));
+
+        let _ = cache.conic(&conic, transform, bounds, clip_bounds);
+
+        assert_eq!(cache.entries.len(), 1);
+
+        cache.trim();
+
+        assert_eq!(cache.entries.len(), 1);
+
+        cache.trim();
+
+        assert!(cache.entries.is_empty());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::core::Rectangle;
+use crate::graphics::gradient;
 use crate::graphics::Damage;
 
 pub type Primitive = crate::graphics::Primitive<Custom>;
@@ -13,6 +14,10 @@ pub enum Custom {
         paint: tiny_skia::Paint<'static>,
         /// The fill rule to follow.
         rule: tiny_skia::FillRule,
+        /// The conic gradient to paint with instead, if any.
+        ///
+        /// `tiny-skia` has no conic shader, so it is rasterized when drawn.
+        conic: Option<gradient::Conic>,
     },
     /// A path stroked with some paint.
     Stroke {
@@ -22,6 +27,10 @@ pub enum Custom {
         paint: tiny_skia::Paint<'static>,
         /// The stroke settings.
         stroke: tiny_skia::Stroke,
+        /// The conic gradient to paint with instead, if any.
+        ///
+        /// `tiny-skia` has no conic shader, so it is rasterized when drawn.
+        conic: Option<gradient::Conic>,
     },
 }
 
@@ -1,10 +1,12 @@
+use crate::backend;
 use crate::core::image as raster;
-use crate::core::{Rectangle, Size};
-use crate::graphics;
+use crate::core::{Color, Radians, Rectangle, Size};
+use crate::graphics::image::decoder;
 
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::cell::RefCell;
 use std::collections::hash_map;
+use std::task::Poll;
 
 pub struct Pipeline {
     cache: RefCell<Cache>,
@@ -18,11 +20,16 @@ impl Pipeline {
     }
 
     pub fn dimensions(&self, handle: &raster::Handle) -> Size<u32> {
-        if let Some(image) = self.cache.borrow_mut().allocate(handle) {
-            Size::new(image.width(), image.height())
-        } else {
-            Size::new(0, 0)
+        if let Some(image) = self.cache.borrow().get(handle) {
+            return Size::new(image.width(), image.height());
         }
+
+        decoder::dimensions(handle).unwrap_or(Size::new(0, 0))
+    }
+
+    pub fn is_loading(&self, handle: &raster::Handle) -> bool {
+        !self.cache.borrow().contains(handle)
+            && decoder::poll(handle).is_pending()
     }
 
     pub fn draw(
@@ -30,16 +37,15 @@ impl Pipeline {
         handle: &raster::Handle,
         filter_method: raster::FilterMethod,
         bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
+        tint: Option<Color>,
+        border_radius: [f32; 4],
         pixels: &mut tiny_skia::PixmapMut<'_>,
         transform: tiny_skia::Transform,
         clip_mask: Option<&tiny_skia::Mask>,
     ) {
-        if let Some(image) = self.cache.borrow_mut().allocate(handle) {
-            let width_scale = bounds.width / image.width() as f32;
-            let height_scale = bounds.height / image.height() as f32;
-
-            let transform = transform.pre_scale(width_scale, height_scale);
-
+        if let Some(image) = self.cache.borrow_mut().allocate(handle, tint) {
             let quality = match filter_method {
                 raster::FilterMethod::Linear => {
                     tiny_skia::FilterQuality::Bilinear
@@ -49,14 +55,14 @@ impl Pipeline {
                 }
             };
 
-            pixels.draw_pixmap(
-                (bounds.x / width_scale) as i32,
-                (bounds.y / height_scale) as i32,
+            backend::draw_image(
                 image,
-                &tiny_skia::PixmapPaint {
-                    quality,
-                    ..Default::default()
-                },
+                bounds,
+                quality,
+                rotation,
+                opacity,
+                border_radius,
+                pixels,
                 transform,
                 clip_mask,
             );
@@ -78,11 +84,20 @@ impl Cache {
     pub fn allocate(
         &mut self,
         handle: &raster::Handle,
+        tint: Option<Color>,
     ) -> Option<tiny_skia::PixmapRef<'_>> {
         let id = handle.id();
 
         if let hash_map::Entry::Vacant(entry) = self.entries.entry(id) {
-            let image = graphics::image::load(handle).ok()?.into_rgba8();
+            let image = match decoder::poll(handle) {
+                Poll::Pending => return None,
+                Poll::Ready(Ok(image)) => image,
+                Poll::Ready(Err(_)) => {
+                    let _ = entry.insert(None);
+
+                    return None;
+                }
+            };
 
             let mut buffer =
                 vec![0u32; image.width() as usize * image.height() as usize];
@@ -99,11 +114,42 @@ impl Cache {
                 width: image.width(),
                 height: image.height(),
                 pixels: buffer,
+                tinted: None,
             }));
         }
 
         let _ = self.hits.insert(id);
-        self.entries.get(&id).unwrap().as_ref().map(|entry| {
+
+        let Some(tint) = tint else {
+            return self.get(handle);
+        };
+
+        let entry = self.entries.get_mut(&id)?.as_mut()?;
+
+        let key = tint.into_rgba8();
+
+        // Only the last tint is kept, so animating it does not pile up copies
+        if entry.tinted.as_ref().map(|(last, _)| *last) != Some(key) {
+            let mut pixels = entry.pixels.clone();
+            backend::tint(bytemuck::cast_slice_mut(&mut pixels), tint);
+
+            entry.tinted = Some((key, pixels));
+        }
+
+        let (_, pixels) = entry.tinted.as_ref()?;
+
+        Some(
+            tiny_skia::PixmapRef::from_bytes(
+                bytemuck::cast_slice(pixels),
+                entry.width,
+                entry.height,
+            )
+            .expect("Build pixmap from image bytes"),
+        )
+    }
+
+    fn get(&self, handle: &raster::Handle) -> Option<tiny_skia::PixmapRef<'_>> {
+        self.entries.get(&handle.id())?.as_ref().map(|entry| {
             tiny_skia::PixmapRef::from_bytes(
                 bytemuck::cast_slice(&entry.pixels),
                 entry.width,
@@ -113,6 +159,10 @@ impl Cache {
         })
     }
 
+    fn contains(&self, handle: &raster::Handle) -> bool {
+        self.entries.contains_key(&handle.id())
+    }
+
     fn trim(&mut self) {
         self.entries.retain(|key, _| self.hits.contains(key));
         self.hits.clear();
@@ -123,4 +173,5 @@ struct Entry {
     width: u32,
     height: u32,
     pixels: Vec<u32>,
+    tinted: Option<([u8; 4], Vec<u32>)>,
 }
@@ -1,6 +1,7 @@
 use crate::core::alignment;
-use crate::core::text::{LineHeight, Shaping};
-use crate::core::{Color, Font, Pixels, Point, Rectangle, Size};
+use crate::core::font;
+use crate::core::text::{LineHeight, Shaping, Wrapping};
+use crate::core::{Color, Font, Pixels, Point, Rectangle, Size, Vector};
 use crate::graphics::text::cache::{self, Cache};
 use crate::graphics::text::editor;
 use crate::graphics::text::font_system;
@@ -25,13 +26,18 @@ impl Pipeline {
         }
     }
 
-    pub fn load_font(&mut self, bytes: Cow<'static, [u8]>) {
+    pub fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
         font_system()
             .write()
             .expect("Write font system")
-            .load_font(bytes);
+            .load_font(bytes)?;
 
         self.cache = RefCell::new(Cache::new());
+
+        Ok(())
     }
 
     pub fn draw_paragraph(
@@ -51,18 +57,41 @@ impl Pipeline {
 
         let mut font_system = font_system().write().expect("Write font system");
 
-        draw(
-            font_system.raw(),
-            &mut self.glyph_cache,
-            paragraph.buffer(),
-            Rectangle::new(position, paragraph.min_bounds()),
-            color,
-            paragraph.horizontal_alignment(),
-            paragraph.vertical_alignment(),
-            scale_factor,
-            pixels,
-            clip_mask,
-        );
+        if paragraph.letters().is_empty() {
+            draw(
+                font_system.raw(),
+                &mut self.glyph_cache,
+                paragraph.buffer(),
+                Rectangle::new(position, paragraph.min_bounds()),
+                color,
+                paragraph.horizontal_alignment(),
+                paragraph.vertical_alignment(),
+                scale_factor,
+                pixels,
+                clip_mask,
+            );
+
+            return;
+        }
+
+        for letter in paragraph.letters() {
+            draw(
+                font_system.raw(),
+                &mut self.glyph_cache,
+                &letter.buffer,
+                Rectangle::new(
+                    position
+                        + Vector::new(letter.position.x, letter.position.y),
+                    paragraph.min_bounds(),
+                ),
+                color,
+                paragraph.horizontal_alignment(),
+                paragraph.vertical_alignment(),
+                scale_factor,
+                pixels,
+                clip_mask,
+            );
+        }
     }
 
     pub fn draw_editor(
@@ -107,6 +136,7 @@ impl Pipeline {
         horizontal_alignment: alignment::Horizontal,
         vertical_alignment: alignment::Vertical,
         shaping: Shaping,
+        wrapping: Wrapping,
         scale_factor: f32,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
@@ -123,6 +153,7 @@ impl Pipeline {
             size: size.into(),
             line_height,
             shaping,
+            wrapping,
         };
 
         let (_, entry) = self.cache.get_mut().allocate(font_system, key);
@@ -165,7 +196,13 @@ impl Pipeline {
             font_system.raw(),
             &mut self.glyph_cache,
             buffer,
-            Rectangle::new(position, Size::new(width, height)),
+            Rectangle::new(
+                position,
+                Size::new(
+                    width.unwrap_or(f32::INFINITY),
+                    height.unwrap_or(f32::INFINITY),
+                ),
+            ),
             color,
             alignment::Horizontal::Left,
             alignment::Vertical::Top,
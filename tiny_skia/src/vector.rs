@@ -1,5 +1,6 @@
+use crate::backend;
 use crate::core::svg::{Data, Handle};
-use crate::core::{Color, Rectangle, Size};
+use crate::core::{Color, Radians, Rectangle, Size};
 use crate::graphics::text;
 
 use resvg::usvg::{self, TreeTextToPath};
@@ -32,19 +33,32 @@ impl Pipeline {
         handle: &Handle,
         color: Option<Color>,
         bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
+        tint: Option<Color>,
+        border_radius: [f32; 4],
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
     ) {
         if let Some(image) = self.cache.borrow_mut().draw(
             handle,
             color,
+            tint,
             Size::new(bounds.width as u32, bounds.height as u32),
         ) {
-            pixels.draw_pixmap(
-                bounds.x as i32,
-                bounds.y as i32,
+            backend::draw_image(
                 image,
-                &tiny_skia::PixmapPaint::default(),
+                Rectangle {
+                    x: bounds.x.trunc(),
+                    y: bounds.y.trunc(),
+                    width: image.width() as f32,
+                    height: image.height() as f32,
+                },
+                tiny_skia::FilterQuality::Nearest,
+                rotation,
+                opacity,
+                border_radius,
+                pixels,
                 tiny_skia::Transform::identity(),
                 clip_mask,
             );
@@ -68,6 +82,7 @@ struct Cache {
 struct RasterKey {
     id: u64,
     color: Option<[u8; 4]>,
+    tint: Option<[u8; 4]>,
     size: Size<u32>,
 }
 
@@ -122,6 +137,7 @@ impl Cache {
         &mut self,
         handle: &Handle,
         color: Option<Color>,
+        tint: Option<Color>,
         size: Size<u32>,
     ) -> Option<tiny_skia::PixmapRef<'_>> {
         if size.width == 0 || size.height == 0 {
@@ -131,6 +147,7 @@ impl Cache {
         let key = RasterKey {
             id: handle.id(),
             color: color.map(Color::into_rgba8),
+            tint: tint.map(Color::into_rgba8),
             size,
         };
 
@@ -188,6 +205,10 @@ impl Cache {
                 }
             }
 
+            if let Some(tint) = tint {
+                backend::tint(image.data_mut(), tint);
+            }
+
             let _ = self.rasters.insert(key, image);
         }
 
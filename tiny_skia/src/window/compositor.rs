@@ -118,20 +118,13 @@ impl crate::graphics::Compositor for Compositor {
     fn screenshot<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
-        surface: &mut Self::Surface,
+        _surface: &mut Self::Surface,
         viewport: &Viewport,
         background_color: Color,
         overlay: &[T],
     ) -> Vec<u8> {
         renderer.with_primitives(|backend, primitives| {
-            screenshot(
-                surface,
-                backend,
-                primitives,
-                viewport,
-                background_color,
-                overlay,
-            )
+            screenshot(backend, primitives, viewport, background_color, overlay)
         })
     }
 }
@@ -212,8 +205,11 @@ pub fn present<T: AsRef<str>>(
     buffer.present().map_err(|_| compositor::SurfaceError::Lost)
 }
 
+/// Renders the given primitives into an offscreen buffer of `RGBA` pixels.
+///
+/// No window or [`Surface`] is needed, so this can be used to render
+/// user interfaces headlessly. An empty viewport produces no pixels.
 pub fn screenshot<T: AsRef<str>>(
-    surface: &mut Surface,
     backend: &mut Backend,
     primitives: &[Primitive],
     viewport: &Viewport,
@@ -222,9 +218,16 @@ pub fn screenshot<T: AsRef<str>>(
 ) -> Vec<u8> {
     let size = viewport.physical_size();
 
+    if size.width == 0 || size.height == 0 {
+        return Vec::new();
+    }
+
     let mut offscreen_buffer: Vec<u32> =
         vec![0; size.width as usize * size.height as usize];
 
+    let mut clip_mask = tiny_skia::Mask::new(size.width, size.height)
+        .expect("Create clip mask");
+
     backend.draw(
         &mut tiny_skia::PixmapMut::from_bytes(
             bytemuck::cast_slice_mut(&mut offscreen_buffer),
@@ -232,7 +235,7 @@ pub fn screenshot<T: AsRef<str>>(
             size.height,
         )
         .expect("Create offscreen pixel map"),
-        &mut surface.clip_mask,
+        &mut clip_mask,
         primitives,
         viewport,
         &[Rectangle::with_size(Size::new(
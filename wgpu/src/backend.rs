@@ -1,3 +1,4 @@
+use crate::core::font;
 use crate::core::{Color, Size};
 use crate::graphics::backend;
 use crate::graphics::color;
@@ -123,7 +124,7 @@ impl Backend {
         device: &wgpu::Device,
         queue: &wgpu::Queue,
         format: wgpu::TextureFormat,
-        _encoder: &mut wgpu::CommandEncoder,
+        encoder: &mut wgpu::CommandEncoder,
         scale_factor: f32,
         target_size: Size<u32>,
         transformation: Transformation,
@@ -167,7 +168,7 @@ impl Backend {
                     self.image_pipeline.prepare(
                         device,
                         queue,
-                        _encoder,
+                        encoder,
                         &layer.images,
                         scaled,
                         scale_factor,
@@ -179,6 +180,7 @@ impl Backend {
                 self.text_pipeline.prepare(
                     device,
                     queue,
+                    encoder,
                     &layer.text,
                     layer.bounds,
                     scale_factor,
@@ -372,8 +374,11 @@ impl crate::graphics::Backend for Backend {
 }
 
 impl backend::Text for Backend {
-    fn load_font(&mut self, font: Cow<'static, [u8]>) {
-        self.text_pipeline.load_font(font);
+    fn load_font(
+        &mut self,
+        font: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
+        self.text_pipeline.load_font(font)
     }
 }
 
@@ -382,6 +387,10 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &crate::core::image::Handle) -> Size<u32> {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn is_loading(&self, handle: &crate::core::image::Handle) -> bool {
+        self.image_pipeline.is_loading(handle)
+    }
 }
 
 #[cfg(feature = "svg")]
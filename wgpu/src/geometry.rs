@@ -1,5 +1,5 @@
 //! Build and draw geometry.
-use crate::core::text::LineHeight;
+use crate::core::text::{LineHeight, Wrapping};
 use crate::core::{Pixels, Point, Rectangle, Size, Vector};
 use crate::graphics::color;
 use crate::graphics::geometry::fill::{self, Fill};
@@ -158,6 +158,17 @@ impl Transform {
                 linear.start = self.transform_point(linear.start);
                 linear.end = self.transform_point(linear.end);
             }
+            Gradient::Radial(radial) => {
+                radial.center = self.transform_point(radial.center);
+                radial.radius *= self
+                    .0
+                    .transform_vector(euclid::Vector2D::new(1.0, 0.0))
+                    .length();
+            }
+            Gradient::Conic(conic) => {
+                conic.center = self.transform_point(conic.center);
+                conic.angle.0 += self.0.m12.atan2(self.0.m11);
+            }
         }
 
         gradient
@@ -375,6 +386,7 @@ impl Frame {
                 horizontal_alignment: text.horizontal_alignment,
                 vertical_alignment: text.vertical_alignment,
                 shaping: text.shaping,
+                wrapping: Wrapping::default(),
                 clip_bounds: Rectangle::with_size(Size::INFINITY),
             });
         } else {
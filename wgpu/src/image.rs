@@ -8,7 +8,8 @@ mod vector;
 
 use atlas::Atlas;
 
-use crate::core::{Rectangle, Size};
+use crate::core::border;
+use crate::core::{Color, Radians, Rectangle, Size};
 use crate::graphics::Transformation;
 use crate::layer;
 use crate::Buffer;
@@ -282,6 +283,16 @@ impl Pipeline {
                             3 => Float32x2,
                             // Layer
                             4 => Sint32,
+                            // Bounds
+                            5 => Float32x4,
+                            // Rotation
+                            6 => Float32,
+                            // Opacity
+                            7 => Float32,
+                            // Tint
+                            8 => Float32x4,
+                            // Border radius
+                            9 => Float32x4,
                         ),
                     }],
                 },
@@ -355,10 +366,12 @@ impl Pipeline {
 
     #[cfg(feature = "image")]
     pub fn dimensions(&self, handle: &image::Handle) -> Size<u32> {
-        let mut cache = self.raster_cache.borrow_mut();
-        let memory = cache.load(handle);
+        self.raster_cache.borrow_mut().dimensions(handle)
+    }
 
-        memory.dimensions()
+    #[cfg(feature = "image")]
+    pub fn is_loading(&self, handle: &image::Handle) -> bool {
+        self.raster_cache.borrow().is_loading(handle)
     }
 
     #[cfg(feature = "svg")]
@@ -400,6 +413,10 @@ impl Pipeline {
                     handle,
                     filter_method,
                     bounds,
+                    rotation,
+                    opacity,
+                    tint,
+                    border_radius,
                 } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         device,
@@ -408,8 +425,11 @@ impl Pipeline {
                         &mut self.texture_atlas,
                     ) {
                         add_instances(
-                            [bounds.x, bounds.y],
-                            [bounds.width, bounds.height],
+                            *bounds,
+                            *rotation,
+                            *opacity,
+                            *tint,
+                            *border_radius,
                             atlas_entry,
                             match filter_method {
                                 image::FilterMethod::Nearest => {
@@ -428,6 +448,10 @@ impl Pipeline {
                     handle,
                     color,
                     bounds,
+                    rotation,
+                    opacity,
+                    tint,
+                    border_radius,
                 } => {
                     let size = [bounds.width, bounds.height];
 
@@ -441,8 +465,11 @@ impl Pipeline {
                         &mut self.texture_atlas,
                     ) {
                         add_instances(
-                            [bounds.x, bounds.y],
-                            size,
+                            *bounds,
+                            *rotation,
+                            *opacity,
+                            *tint,
+                            *border_radius,
                             atlas_entry,
                             nearest_instances,
                         );
@@ -540,6 +567,11 @@ struct Instance {
     _position_in_atlas: [f32; 2],
     _size_in_atlas: [f32; 2],
     _layer: u32,
+    _bounds: [f32; 4],
+    _rotation: f32,
+    _opacity: f32,
+    _tint: [f32; 4],
+    _border_radius: [f32; 4],
 }
 
 impl Instance {
@@ -553,23 +585,42 @@ struct Uniforms {
 }
 
 fn add_instances(
-    image_position: [f32; 2],
-    image_size: [f32; 2],
+    bounds: Rectangle,
+    rotation: Radians,
+    opacity: f32,
+    tint: Option<Color>,
+    border_radius: border::Radius,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
+    // Fragments share the bounds of the whole image, so they are rotated
+    // and rounded together
+    let template = Instance {
+        _bounds: [bounds.x, bounds.y, bounds.width, bounds.height],
+        _rotation: rotation.0,
+        _opacity: opacity,
+        _tint: tint.unwrap_or(Color::WHITE).into_linear(),
+        _border_radius: border_radius.into(),
+        ..Instance::zeroed()
+    };
+
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(image_position, image_size, allocation, instances);
+            add_instance(
+                [bounds.x, bounds.y],
+                [bounds.width, bounds.height],
+                allocation,
+                template,
+                instances,
+            );
         }
         atlas::Entry::Fragmented { fragments, size } => {
-            let scaling_x = image_size[0] / size.width as f32;
-            let scaling_y = image_size[1] / size.height as f32;
+            let scaling_x = bounds.width / size.width as f32;
+            let scaling_y = bounds.height / size.height as f32;
 
             for fragment in fragments {
                 let allocation = &fragment.allocation;
 
-                let [x, y] = image_position;
                 let (fragment_x, fragment_y) = fragment.position;
                 let Size {
                     width: fragment_width,
@@ -577,8 +628,8 @@ fn add_instances(
                 } = allocation.size();
 
                 let position = [
-                    x + fragment_x as f32 * scaling_x,
-                    y + fragment_y as f32 * scaling_y,
+                    bounds.x + fragment_x as f32 * scaling_x,
+                    bounds.y + fragment_y as f32 * scaling_y,
                 ];
 
                 let size = [
@@ -586,7 +637,7 @@ fn add_instances(
                     fragment_height as f32 * scaling_y,
                 ];
 
-                add_instance(position, size, allocation, instances);
+                add_instance(position, size, allocation, template, instances);
             }
         }
     }
@@ -597,6 +648,7 @@ fn add_instance(
     position: [f32; 2],
     size: [f32; 2],
     allocation: &atlas::Allocation,
+    template: Instance,
     instances: &mut Vec<Instance>,
 ) {
     let (x, y) = allocation.position();
@@ -615,6 +667,7 @@ fn add_instance(
             (height as f32 - 1.0) / atlas::SIZE as f32,
         ],
         _layer: layer as u32,
+        ..template
     };
 
     instances.push(instance);
@@ -1,16 +1,14 @@
 use crate::core::image;
 use crate::core::Size;
-use crate::graphics;
-use crate::graphics::image::image_rs;
+use crate::graphics::image::decoder;
 use crate::image::atlas::{self, Atlas};
 
 use std::collections::{HashMap, HashSet};
+use std::task::Poll;
 
 /// Entry in cache corresponding to an image handle
 #[derive(Debug)]
 pub enum Memory {
-    /// Image data on host
-    Host(image_rs::ImageBuffer<image_rs::Rgba<u8>, Vec<u8>>),
     /// Storage entry
     Device(atlas::Entry),
     /// Image not found
@@ -23,11 +21,6 @@ impl Memory {
     /// Width and height of image
     pub fn dimensions(&self) -> Size<u32> {
         match self {
-            Memory::Host(image) => {
-                let (width, height) = image.dimensions();
-
-                Size::new(width, height)
-            }
             Memory::Device(entry) => entry.size(),
             Memory::NotFound => Size::new(1, 1),
             Memory::Invalid => Size::new(1, 1),
@@ -43,23 +36,22 @@ pub struct Cache {
 }
 
 impl Cache {
-    /// Load image
-    pub fn load(&mut self, handle: &image::Handle) -> &mut Memory {
-        if self.contains(handle) {
-            return self.get(handle).unwrap();
+    /// Returns the dimensions of an image, without waiting for it to be
+    /// decoded
+    pub fn dimensions(&mut self, handle: &image::Handle) -> Size<u32> {
+        if let Some(memory) = self.get(handle) {
+            return memory.dimensions();
         }
 
-        let memory = match graphics::image::load(handle) {
-            Ok(image) => Memory::Host(image.to_rgba8()),
-            Err(image_rs::error::ImageError::IoError(_)) => Memory::NotFound,
-            Err(_) => Memory::Invalid,
-        };
+        decoder::dimensions(handle).unwrap_or(Size::new(1, 1))
+    }
 
-        self.insert(handle, memory);
-        self.get(handle).unwrap()
+    /// Returns whether an image is still being decoded
+    pub fn is_loading(&self, handle: &image::Handle) -> bool {
+        !self.contains(handle) && decoder::poll(handle).is_pending()
     }
 
-    /// Load image and upload raster data
+    /// Upload the raster data of an image, once decoded
     pub fn upload(
         &mut self,
         device: &wgpu::Device,
@@ -67,17 +59,28 @@ impl Cache {
         handle: &image::Handle,
         atlas: &mut Atlas,
     ) -> Option<&atlas::Entry> {
-        let memory = self.load(handle);
-
-        if let Memory::Host(image) = memory {
-            let (width, height) = image.dimensions();
-
-            let entry = atlas.upload(device, encoder, width, height, image)?;
+        if !self.contains(handle) {
+            let memory = match decoder::poll(handle) {
+                Poll::Pending => return None,
+                Poll::Ready(Ok(image)) => {
+                    let (width, height) = image.dimensions();
+
+                    Memory::Device(atlas.upload(
+                        device,
+                        encoder,
+                        width,
+                        height,
+                        image.as_raw(),
+                    )?)
+                }
+                Poll::Ready(Err(decoder::Error::NotFound)) => Memory::NotFound,
+                Poll::Ready(Err(decoder::Error::Invalid(_))) => Memory::Invalid,
+            };
 
-            *memory = Memory::Device(entry);
+            self.insert(handle, memory);
         }
 
-        if let Memory::Device(allocation) = memory {
+        if let Some(Memory::Device(allocation)) = self.get(handle) {
             Some(allocation)
         } else {
             None
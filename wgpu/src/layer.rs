@@ -75,6 +75,7 @@ impl<'a> Layer<'a> {
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Top,
                 shaping: core::text::Shaping::Basic,
+                wrapping: core::text::Wrapping::default(),
                 clip_bounds: Rectangle::with_size(Size::INFINITY),
             };
 
@@ -160,6 +161,7 @@ impl<'a> Layer<'a> {
                 horizontal_alignment,
                 vertical_alignment,
                 shaping,
+                wrapping,
                 clip_bounds,
             } => {
                 let layer = &mut layers[current_layer];
@@ -174,6 +176,7 @@ impl<'a> Layer<'a> {
                     horizontal_alignment: *horizontal_alignment,
                     vertical_alignment: *vertical_alignment,
                     shaping: *shaping,
+                    wrapping: *wrapping,
                     clip_bounds: *clip_bounds + translation,
                 }));
             }
@@ -220,6 +223,10 @@ impl<'a> Layer<'a> {
                 handle,
                 filter_method,
                 bounds,
+                rotation,
+                opacity,
+                tint,
+                border_radius,
             } => {
                 let layer = &mut layers[current_layer];
 
@@ -227,12 +234,20 @@ impl<'a> Layer<'a> {
                     handle: handle.clone(),
                     filter_method: *filter_method,
                     bounds: *bounds + translation,
+                    rotation: *rotation,
+                    opacity: *opacity,
+                    tint: *tint,
+                    border_radius: *border_radius,
                 });
             }
             Primitive::Svg {
                 handle,
                 color,
                 bounds,
+                rotation,
+                opacity,
+                tint,
+                border_radius,
             } => {
                 let layer = &mut layers[current_layer];
 
@@ -240,6 +255,10 @@ impl<'a> Layer<'a> {
                     handle: handle.clone(),
                     color: *color,
                     bounds: *bounds + translation,
+                    rotation: *rotation,
+                    opacity: *opacity,
+                    tint: *tint,
+                    border_radius: *border_radius,
                 });
             }
             Primitive::Group { primitives } => {
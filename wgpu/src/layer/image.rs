@@ -1,6 +1,7 @@
+use crate::core::border;
 use crate::core::image;
 use crate::core::svg;
-use crate::core::{Color, Rectangle};
+use crate::core::{Color, Radians, Rectangle};
 
 /// A raster or vector image.
 #[derive(Debug, Clone)]
@@ -15,6 +16,18 @@ pub enum Image {
 
         /// The bounds of the image.
         bounds: Rectangle,
+
+        /// The rotation of the image around its center.
+        rotation: Radians,
+
+        /// The opacity of the image.
+        opacity: f32,
+
+        /// The [`Color`] multiplied with the pixels of the image.
+        tint: Option<Color>,
+
+        /// The border radius of the image.
+        border_radius: border::Radius,
     },
     /// A vector image.
     Vector {
@@ -26,5 +39,17 @@ pub enum Image {
 
         /// The bounds of the image.
         bounds: Rectangle,
+
+        /// The rotation of the image around its center.
+        rotation: Radians,
+
+        /// The opacity of the image.
+        opacity: f32,
+
+        /// The [`Color`] multiplied with the pixels of the image.
+        tint: Option<Color>,
+
+        /// The border radius of the image.
+        border_radius: border::Radius,
     },
 }
@@ -59,6 +59,9 @@ pub struct Cached<'a> {
     /// The shaping strategy of the text.
     pub shaping: text::Shaping,
 
+    /// The wrapping strategy of the text.
+    pub wrapping: text::Wrapping,
+
     /// The clip bounds of the text.
     pub clip_bounds: Rectangle,
 }
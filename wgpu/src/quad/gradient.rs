@@ -122,18 +122,30 @@ impl Pipeline {
                             2 => Uint32x4,
                             // Colors 7-8
                             3 => Uint32x4,
-                            // Offsets 1-8
+                            // Colors 9-10
                             4 => Uint32x4,
+                            // Colors 11-12
+                            5 => Uint32x4,
+                            // Colors 13-14
+                            6 => Uint32x4,
+                            // Colors 15-16
+                            7 => Uint32x4,
+                            // Offsets 1-8
+                            8 => Uint32x4,
+                            // Offsets 9-16
+                            9 => Uint32x4,
                             // Direction
-                            5 => Float32x4,
+                            10 => Float32x4,
+                            // Kind
+                            11 => Uint32,
                             // Position & Scale
-                            6 => Float32x4,
+                            12 => Float32x4,
                             // Border color
-                            7 => Float32x4,
+                            13 => Float32x4,
                             // Border radius
-                            8 => Float32x4,
+                            14 => Float32x4,
                             // Border width
-                            9 => Float32
+                            15 => Float32
                         ),
                     }],
                 },
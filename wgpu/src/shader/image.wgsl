@@ -13,12 +13,22 @@ struct VertexInput {
     @location(2) atlas_pos: vec2<f32>,
     @location(3) atlas_scale: vec2<f32>,
     @location(4) layer: i32,
+    @location(5) bounds: vec4<f32>,
+    @location(6) rotation: f32,
+    @location(7) opacity: f32,
+    @location(8) tint: vec4<f32>,
+    @location(9) border_radius: vec4<f32>,
 }
 
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) uv: vec2<f32>,
     @location(1) layer: f32, // this should be an i32, but naga currently reads that as requiring interpolation.
+    @location(2) to_center: vec2<f32>,
+    @location(3) half_size: vec2<f32>,
+    @location(4) opacity: f32,
+    @location(5) tint: vec4<f32>,
+    @location(6) border_radius: vec4<f32>,
 }
 
 @vertex
@@ -30,19 +40,50 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     out.uv = vec2<f32>(v_pos * input.atlas_scale + input.atlas_pos);
     out.layer = f32(input.layer);
 
-    var transform: mat4x4<f32> = mat4x4<f32>(
-        vec4<f32>(input.scale.x, 0.0, 0.0, 0.0),
-        vec4<f32>(0.0, input.scale.y, 0.0, 0.0),
-        vec4<f32>(0.0, 0.0, 1.0, 0.0),
-        vec4<f32>(input.pos, 0.0, 1.0)
+    // Rotate each corner around the center of the whole image, since
+    // fragmented images are drawn with multiple instances
+    let half_size = input.bounds.zw / 2.0;
+    let center = input.bounds.xy + half_size;
+    let corner = input.pos + v_pos * input.scale - center;
+    let cos_rotation = cos(input.rotation);
+    let sin_rotation = sin(input.rotation);
+
+    let position = center + vec2<f32>(
+        corner.x * cos_rotation - corner.y * sin_rotation,
+        corner.x * sin_rotation + corner.y * cos_rotation
     );
 
-    out.position = globals.transform * transform * vec4<f32>(v_pos, 0.0, 1.0);
+    out.position = globals.transform * vec4<f32>(position, 0.0, 1.0);
+    out.to_center = corner;
+    out.half_size = half_size;
+    out.opacity = input.opacity;
+    out.tint = input.tint;
+    out.border_radius = input.border_radius;
 
     return out;
 }
 
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+    let color = textureSample(u_texture, u_sampler, input.uv, i32(input.layer)) * input.tint;
+
+    let border_radius = select_border_radius(input.border_radius, input.to_center);
+
+    let radius_alpha = select(
+        1.0,
+        clamp(0.5 - rounded_box_sdf(input.to_center, input.half_size, border_radius), 0.0, 1.0),
+        border_radius > 0.0
+    );
+
+    return vec4<f32>(color.xyz, color.w * input.opacity * radius_alpha);
+}
+
+fn rounded_box_sdf(to_center: vec2<f32>, size: vec2<f32>, radius: f32) -> f32 {
+    return length(max(abs(to_center) - size + vec2<f32>(radius, radius), vec2<f32>(0.0, 0.0))) - radius;
+}
+
+fn select_border_radius(radi: vec4<f32>, to_center: vec2<f32>) -> f32 {
+    let rx = select(radi.x, radi.y, to_center.x > 0.0);
+    let ry = select(radi.w, radi.z, to_center.x > 0.0);
+    return select(rx, ry, to_center.y > 0.0);
 }
@@ -4,12 +4,18 @@ struct GradientVertexInput {
     @location(1) @interpolate(flat) colors_2: vec4<u32>,
     @location(2) @interpolate(flat) colors_3: vec4<u32>,
     @location(3) @interpolate(flat) colors_4: vec4<u32>,
-    @location(4) @interpolate(flat) offsets: vec4<u32>,
-    @location(5) direction: vec4<f32>,
-    @location(6) position_and_scale: vec4<f32>,
-    @location(7) border_color: vec4<f32>,
-    @location(8) border_radius: vec4<f32>,
-    @location(9) border_width: f32,
+    @location(4) @interpolate(flat) colors_5: vec4<u32>,
+    @location(5) @interpolate(flat) colors_6: vec4<u32>,
+    @location(6) @interpolate(flat) colors_7: vec4<u32>,
+    @location(7) @interpolate(flat) colors_8: vec4<u32>,
+    @location(8) @interpolate(flat) offsets_1: vec4<u32>,
+    @location(9) @interpolate(flat) offsets_2: vec4<u32>,
+    @location(10) direction: vec4<f32>,
+    @location(11) @interpolate(flat) kind: u32,
+    @location(12) position_and_scale: vec4<f32>,
+    @location(13) border_color: vec4<f32>,
+    @location(14) border_radius: vec4<f32>,
+    @location(15) border_width: f32,
 }
 
 struct GradientVertexOutput {
@@ -18,12 +24,18 @@ struct GradientVertexOutput {
     @location(2) @interpolate(flat) colors_2: vec4<u32>,
     @location(3) @interpolate(flat) colors_3: vec4<u32>,
     @location(4) @interpolate(flat) colors_4: vec4<u32>,
-    @location(5) @interpolate(flat) offsets: vec4<u32>,
-    @location(6) direction: vec4<f32>,
-    @location(7) position_and_scale: vec4<f32>,
-    @location(8) border_color: vec4<f32>,
-    @location(9) border_radius: vec4<f32>,
-    @location(10) border_width: f32,
+    @location(5) @interpolate(flat) colors_5: vec4<u32>,
+    @location(6) @interpolate(flat) colors_6: vec4<u32>,
+    @location(7) @interpolate(flat) colors_7: vec4<u32>,
+    @location(8) @interpolate(flat) colors_8: vec4<u32>,
+    @location(9) @interpolate(flat) offsets_1: vec4<u32>,
+    @location(10) @interpolate(flat) offsets_2: vec4<u32>,
+    @location(11) direction: vec4<f32>,
+    @location(12) @interpolate(flat) kind: u32,
+    @location(13) position_and_scale: vec4<f32>,
+    @location(14) border_color: vec4<f32>,
+    @location(15) border_radius: vec4<f32>,
+    @location(16) border_width: f32,
 }
 
 @vertex
@@ -53,8 +65,21 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
     out.colors_2 = input.colors_2;
     out.colors_3 = input.colors_3;
     out.colors_4 = input.colors_4;
-    out.offsets = input.offsets;
-    out.direction = input.direction * globals.scale;
+    out.colors_5 = input.colors_5;
+    out.colors_6 = input.colors_6;
+    out.colors_7 = input.colors_7;
+    out.colors_8 = input.colors_8;
+    out.offsets_1 = input.offsets_1;
+    out.offsets_2 = input.offsets_2;
+    out.kind = input.kind;
+
+    // The angle of a conic gradient must not be scaled
+    if (input.kind == 2u) {
+        out.direction = vec4<f32>(input.direction.xy * globals.scale, input.direction.zw);
+    } else {
+        out.direction = input.direction * globals.scale;
+    }
+
     out.position_and_scale = vec4<f32>(pos, scale);
     out.border_color = input.border_color;
     out.border_radius = border_radius * globals.scale;
@@ -67,21 +92,44 @@ fn random(coords: vec2<f32>) -> f32 {
     return fract(sin(dot(coords, vec2(12.9898,78.233))) * 43758.5453);
 }
 
-/// Returns the current interpolated color with a max 8-stop gradient
+/// Returns the offset of the position along a linear, radial or conic gradient
+fn gradient_offset(raw_position: vec2<f32>, direction: vec4<f32>, kind: u32) -> f32 {
+    switch kind {
+        // Radial
+        case 1u: {
+            return distance(raw_position, direction.xy) / direction.z;
+        }
+        // Conic
+        case 2u: {
+            let v = raw_position - direction.xy;
+            let turn = atan2(v.y, v.x) + 1.5707964 - direction.z;
+
+            return fract(turn / 6.2831855);
+        }
+        // Linear
+        default: {
+            let start = direction.xy;
+            let end = direction.zw;
+
+            let v1 = end - start;
+            let v2 = raw_position - start;
+            let unit = normalize(v1);
+
+            return dot(unit, v2) / length(v1);
+        }
+    }
+}
+
+/// Returns the current interpolated color with a max 16-stop gradient
 fn gradient(
     raw_position: vec2<f32>,
     direction: vec4<f32>,
-    colors: array<vec4<f32>, 8>,
-    offsets: array<f32, 8>,
+    kind: u32,
+    colors: array<vec4<f32>, 16>,
+    offsets: array<f32, 16>,
     last_index: i32
 ) -> vec4<f32> {
-    let start = direction.xy;
-    let end = direction.zw;
-
-    let v1 = end - start;
-    let v2 = raw_position - start;
-    let unit = normalize(v1);
-    let coord_offset = dot(unit, v2) / length(v1);
+    let coord_offset = gradient_offset(raw_position, direction, kind);
 
     //need to store these as a var to use dynamic indexing in a loop
     //this is already added to wgsl spec but not in wgpu yet
@@ -118,7 +166,7 @@ fn gradient(
 
 @fragment
 fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
-    let colors = array<vec4<f32>, 8>(
+    let colors = array<vec4<f32>, 16>(
         unpack_u32(input.colors_1.xy),
         unpack_u32(input.colors_1.zw),
         unpack_u32(input.colors_2.xy),
@@ -127,12 +175,22 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         unpack_u32(input.colors_3.zw),
         unpack_u32(input.colors_4.xy),
         unpack_u32(input.colors_4.zw),
+        unpack_u32(input.colors_5.xy),
+        unpack_u32(input.colors_5.zw),
+        unpack_u32(input.colors_6.xy),
+        unpack_u32(input.colors_6.zw),
+        unpack_u32(input.colors_7.xy),
+        unpack_u32(input.colors_7.zw),
+        unpack_u32(input.colors_8.xy),
+        unpack_u32(input.colors_8.zw),
     );
 
-    let offsets_1: vec4<f32> = unpack_u32(input.offsets.xy);
-    let offsets_2: vec4<f32> = unpack_u32(input.offsets.zw);
+    let offsets_1: vec4<f32> = unpack_u32(input.offsets_1.xy);
+    let offsets_2: vec4<f32> = unpack_u32(input.offsets_1.zw);
+    let offsets_3: vec4<f32> = unpack_u32(input.offsets_2.xy);
+    let offsets_4: vec4<f32> = unpack_u32(input.offsets_2.zw);
 
-    var offsets = array<f32, 8>(
+    var offsets = array<f32, 16>(
         offsets_1.x,
         offsets_1.y,
         offsets_1.z,
@@ -141,18 +199,26 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         offsets_2.y,
         offsets_2.z,
         offsets_2.w,
+        offsets_3.x,
+        offsets_3.y,
+        offsets_3.z,
+        offsets_3.w,
+        offsets_4.x,
+        offsets_4.y,
+        offsets_4.z,
+        offsets_4.w,
     );
 
     //TODO could just pass this in to the shader but is probably more performant to just check it here
-    var last_index = 7;
-    for (var i: i32 = 0; i <= 7; i++) {
+    var last_index = 15;
+    for (var i: i32 = 0; i <= 15; i++) {
         if (offsets[i] > 1.0) {
             last_index = i - 1;
             break;
         }
     }
 
-    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, colors, offsets, last_index);
+    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, input.kind, colors, offsets, last_index);
 
     let pos = input.position_and_scale.xy;
     let scale = input.position_and_scale.zw;
@@ -1,4 +1,5 @@
 use crate::core::alignment;
+use crate::core::font;
 use crate::core::{Rectangle, Size};
 use crate::graphics::color;
 use crate::graphics::text::cache::{self, Cache};
@@ -13,6 +14,7 @@ use std::sync::Arc;
 pub struct Pipeline {
     renderers: Vec<glyphon::TextRenderer>,
     atlas: glyphon::TextAtlas,
+    viewport: glyphon::Viewport,
     prepare_layer: usize,
     cache: RefCell<Cache>,
 }
@@ -23,11 +25,14 @@ impl Pipeline {
         queue: &wgpu::Queue,
         format: wgpu::TextureFormat,
     ) -> Self {
+        let cache = glyphon::Cache::new(device);
+
         Pipeline {
             renderers: Vec::new(),
             atlas: glyphon::TextAtlas::with_color_mode(
                 device,
                 queue,
+                &cache,
                 format,
                 if color::GAMMA_CORRECTION {
                     glyphon::ColorMode::Accurate
@@ -35,24 +40,31 @@ impl Pipeline {
                     glyphon::ColorMode::Web
                 },
             ),
+            viewport: glyphon::Viewport::new(device, &cache),
             prepare_layer: 0,
             cache: RefCell::new(Cache::new()),
         }
     }
 
-    pub fn load_font(&mut self, bytes: Cow<'static, [u8]>) {
+    pub fn load_font(
+        &mut self,
+        bytes: Cow<'static, [u8]>,
+    ) -> Result<(), font::Error> {
         font_system()
             .write()
             .expect("Write font system")
-            .load_font(bytes);
+            .load_font(bytes)?;
 
         self.cache = RefCell::new(Cache::new());
+
+        Ok(())
     }
 
     pub fn prepare(
         &mut self,
         device: &wgpu::Device,
         queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
         sections: &[Text<'_>],
         layer_bounds: Rectangle,
         scale_factor: f32,
@@ -104,6 +116,7 @@ impl Pipeline {
                                 height: text.bounds.height,
                             },
                             shaping: text.shaping,
+                            wrapping: text.wrapping,
                         },
                     );
 
@@ -115,13 +128,14 @@ impl Pipeline {
 
         let layer_bounds = layer_bounds * scale_factor;
 
-        let text_areas = sections.iter().zip(allocations.iter()).filter_map(
+        let text_areas = sections.iter().zip(allocations.iter()).flat_map(
             |(section, allocation)| {
                 let (
                     buffer,
                     bounds,
                     horizontal_alignment,
                     vertical_alignment,
+                    letters,
                     color,
                     clip_bounds,
                 ) = match section {
@@ -135,7 +149,7 @@ impl Pipeline {
 
                         let Some(Allocation::Paragraph(paragraph)) = allocation
                         else {
-                            return None;
+                            return Vec::new();
                         };
 
                         (
@@ -143,6 +157,7 @@ impl Pipeline {
                             Rectangle::new(*position, paragraph.min_bounds()),
                             paragraph.horizontal_alignment(),
                             paragraph.vertical_alignment(),
+                            paragraph.letters(),
                             *color,
                             *clip_bounds,
                         )
@@ -157,7 +172,7 @@ impl Pipeline {
 
                         let Some(Allocation::Editor(editor)) = allocation
                         else {
-                            return None;
+                            return Vec::new();
                         };
 
                         (
@@ -165,13 +180,14 @@ impl Pipeline {
                             Rectangle::new(*position, editor.bounds()),
                             alignment::Horizontal::Left,
                             alignment::Vertical::Top,
+                            &[][..],
                             *color,
                             *clip_bounds,
                         )
                     }
                     Text::Cached(text) => {
                         let Some(Allocation::Cache(key)) = allocation else {
-                            return None;
+                            return Vec::new();
                         };
 
                         let entry = cache.get(key).expect("Get cached buffer");
@@ -184,13 +200,14 @@ impl Pipeline {
                             ),
                             text.horizontal_alignment,
                             text.vertical_alignment,
+                            &[][..],
                             text.color,
                             text.clip_bounds,
                         )
                     }
                     Text::Raw(text) => {
                         let Some(Allocation::Raw(buffer)) = allocation else {
-                            return None;
+                            return Vec::new();
                         };
 
                         let (width, height) = buffer.size();
@@ -199,10 +216,14 @@ impl Pipeline {
                             buffer.as_ref(),
                             Rectangle::new(
                                 text.position,
-                                Size::new(width, height),
+                                Size::new(
+                                    width.unwrap_or(f32::INFINITY),
+                                    height.unwrap_or(f32::INFINITY),
+                                ),
                             ),
                             alignment::Horizontal::Left,
                             alignment::Vertical::Top,
+                            &[][..],
                             text.color,
                             text.clip_bounds,
                         )
@@ -227,34 +248,59 @@ impl Pipeline {
                     alignment::Vertical::Bottom => bounds.y - bounds.height,
                 };
 
-                let clip_bounds =
-                    layer_bounds.intersection(&(clip_bounds * scale_factor))?;
+                let Some(clip_bounds) =
+                    layer_bounds.intersection(&(clip_bounds * scale_factor))
+                else {
+                    return Vec::new();
+                };
 
-                Some(glyphon::TextArea {
-                    buffer,
-                    left,
-                    top,
-                    scale: scale_factor,
-                    bounds: glyphon::TextBounds {
-                        left: clip_bounds.x as i32,
-                        top: clip_bounds.y as i32,
-                        right: (clip_bounds.x + clip_bounds.width) as i32,
-                        bottom: (clip_bounds.y + clip_bounds.height) as i32,
-                    },
-                    default_color: to_color(color),
-                })
+                let text_area =
+                    |buffer, left: f32, top: f32| glyphon::TextArea {
+                        buffer,
+                        left,
+                        top,
+                        scale: scale_factor,
+                        bounds: glyphon::TextBounds {
+                            left: clip_bounds.x as i32,
+                            top: clip_bounds.y as i32,
+                            right: (clip_bounds.x + clip_bounds.width) as i32,
+                            bottom: (clip_bounds.y + clip_bounds.height) as i32,
+                        },
+                        default_color: to_color(color),
+                    };
+
+                if letters.is_empty() {
+                    return vec![text_area(buffer, left, top)];
+                }
+
+                letters
+                    .iter()
+                    .map(|letter| {
+                        text_area(
+                            &letter.buffer,
+                            left + letter.position.x * scale_factor,
+                            top + letter.position.y * scale_factor,
+                        )
+                    })
+                    .collect()
             },
         );
 
-        let result = renderer.prepare(
-            device,
+        self.viewport.update(
             queue,
-            font_system,
-            &mut self.atlas,
             glyphon::Resolution {
                 width: target_size.width,
                 height: target_size.height,
             },
+        );
+
+        let result = renderer.prepare(
+            device,
+            queue,
+            encoder,
+            font_system,
+            &mut self.atlas,
+            &self.viewport,
             text_areas,
             &mut glyphon::SwashCache::new(),
         );
@@ -287,7 +333,7 @@ impl Pipeline {
         );
 
         renderer
-            .render(&self.atlas, render_pass)
+            .render(&self.atlas, &self.viewport, render_pass)
             .expect("Render text");
     }
 
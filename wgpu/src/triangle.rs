@@ -693,10 +693,22 @@ mod gradient {
                                 3 => Uint32x4,
                                 // Colors 7-8
                                 4 => Uint32x4,
-                                // Offsets
+                                // Colors 9-10
                                 5 => Uint32x4,
+                                // Colors 11-12
+                                6 => Uint32x4,
+                                // Colors 13-14
+                                7 => Uint32x4,
+                                // Colors 15-16
+                                8 => Uint32x4,
+                                // Offsets 1-8
+                                9 => Uint32x4,
+                                // Offsets 9-16
+                                10 => Uint32x4,
                                 // Direction
-                                6 => Float32x4
+                                11 => Float32x4,
+                                // Kind
+                                12 => Uint32
                             ),
                         }],
                     },
@@ -1,14 +1,18 @@
 //! Allow your users to perform actions by pressing a button.
 //!
 //! A [`Button`] has some local [`State`].
+use crate::core::animation::{Animation, Interpolable};
 use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::Instant;
 use crate::core::touch;
+use crate::core::widget::operation::accessible::{self, Accessible};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Operation;
+use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Rectangle,
     Shell, Size, Widget,
@@ -136,11 +140,11 @@ where
     Renderer: 'a + crate::core::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State>()
+        tree::Tag::of::<Animated>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        tree::State::new(Animated::default())
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -180,6 +184,17 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
+        operation.accessible(
+            None,
+            layout.bounds(),
+            Accessible::new(accessible::Role::Button)
+                .disabled(self.on_press.is_none()),
+        );
+
+        if let Some(on_press) = &self.on_press {
+            operation.activatable(None, &|| on_press.clone());
+        }
+
         operation.container(None, layout.bounds(), &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
@@ -214,9 +229,33 @@ where
             return event::Status::Captured;
         }
 
-        update(event, layout, cursor, shell, &self.on_press, || {
-            tree.state.downcast_mut::<State>()
-        })
+        let animated = tree.state.downcast_mut::<Animated>();
+
+        if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
+            animated.now = now;
+
+            if animated.is_animating(now) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+
+            return event::Status::Ignored;
+        }
+
+        let status =
+            update(event, layout, cursor, shell, &self.on_press, || {
+                &mut animated.state
+            });
+
+        let is_hovered =
+            self.on_press.is_some() && cursor.is_over(layout.bounds());
+
+        animated.transition(
+            is_hovered,
+            is_hovered && animated.state.is_pressed,
+            shell,
+        );
+
+        status
     }
 
     fn draw(
@@ -232,15 +271,15 @@ where
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
 
-        let styling = draw(
-            renderer,
-            bounds,
-            cursor,
-            self.on_press.is_some(),
-            theme,
-            &self.style,
-            || tree.state.downcast_ref::<State>(),
-        );
+        let styling = if self.on_press.is_some() {
+            tree.state
+                .downcast_ref::<Animated>()
+                .styling(theme, &self.style)
+        } else {
+            theme.disabled(&self.style)
+        };
+
+        fill(renderer, bounds, &styling);
 
         self.content.as_widget().draw(
             &tree.children[0],
@@ -388,6 +427,89 @@ where
         theme.active(style)
     };
 
+    fill(renderer, bounds, &styling);
+
+    styling
+}
+
+/// The state of a [`Button`] widget, which animates its interactions.
+///
+/// Redraws are requested only while a transition is running.
+#[derive(Debug, Clone)]
+struct Animated {
+    state: State,
+    hover: Animation<f32>,
+    press: Animation<f32>,
+    now: Instant,
+}
+
+impl Animated {
+    /// Transitions towards the given interaction, requesting a redraw if it
+    /// changed.
+    ///
+    /// Transitions start at the last redraw, which is the [`Instant`] the
+    /// [`Button`] is drawn at.
+    fn transition<Message>(
+        &mut self,
+        is_hovered: bool,
+        is_pressed: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let now = self.now;
+        let mut is_changed = false;
+
+        for (animation, is_active) in
+            [(&mut self.hover, is_hovered), (&mut self.press, is_pressed)]
+        {
+            let target = if is_active { 1.0 } else { 0.0 };
+
+            if *animation.target() != target {
+                animation.go(target, now);
+                is_changed = true;
+            }
+        }
+
+        if is_changed {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+    }
+
+    fn is_animating(&self, now: Instant) -> bool {
+        self.hover.is_animating(now) || self.press.is_animating(now)
+    }
+
+    fn styling<Theme>(&self, theme: &Theme, style: &Theme::Style) -> Appearance
+    where
+        Theme: StyleSheet,
+    {
+        let hover = self.hover.value(self.now);
+        let press = self.press.value(self.now);
+
+        theme
+            .active(style)
+            .interpolate(&theme.hovered(style), hover)
+            .interpolate(&theme.pressed(style), press)
+    }
+}
+
+impl Default for Animated {
+    fn default() -> Self {
+        Self {
+            state: State::new(),
+            hover: Animation::new(0.0),
+            press: Animation::new(0.0),
+            now: Instant::now(),
+        }
+    }
+}
+
+fn fill<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    styling: &Appearance,
+) where
+    Renderer: crate::core::Renderer,
+{
     if styling.background.is_some()
         || styling.border.width > 0.0
         || styling.shadow.color.a > 0.0
@@ -403,8 +525,6 @@ where
                 .unwrap_or(Background::Color(Color::TRANSPARENT)),
         );
     }
-
-    styling
 }
 
 /// Computes the layout of a [`Button`].
@@ -432,3 +552,26 @@ pub fn mouse_interaction(
         mouse::Interaction::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::time::Duration;
+
+    #[test]
+    fn transitions_start_at_the_last_redraw() {
+        let mut messages: Vec<()> = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let mut animated = Animated {
+            now: Instant::now() + Duration::from_secs(60),
+            ..Animated::default()
+        };
+
+        animated.transition(true, false, &mut shell);
+
+        assert!(animated.is_animating(animated.now));
+        assert_eq!(animated.hover.value(animated.now), 0.0);
+        assert_eq!(*animated.hover.target(), 1.0);
+    }
+}
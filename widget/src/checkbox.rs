@@ -1,13 +1,18 @@
 //! Show toggle controls using checkboxes.
 use crate::core::alignment;
+use crate::core::animation::{Animation, Interpolable};
 use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::accessible::{self, Accessible};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::window;
 use crate::core::{
     Clipboard, Element, Layout, Length, Pixels, Rectangle, Shell, Size, Widget,
 };
@@ -171,11 +176,20 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::new(self.is_checked))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let target = if self.is_checked { 1.0 } else { 0.0 };
+
+        if *state.check.target() != target {
+            state.check.go(target, Instant::now());
+        }
     }
 
     fn size(&self) -> Size<Length> {
@@ -196,12 +210,11 @@ where
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 widget::text::layout(
-                    state,
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -213,14 +226,36 @@ where
                     alignment::Horizontal::Left,
                     alignment::Vertical::Top,
                     self.text_shaping,
+                    text::Wrapping::default(),
+                    Pixels(0.0),
+                    text::Ellipsis::None,
+                    None,
                 )
             },
         )
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.accessible(
+            None,
+            layout.bounds(),
+            Accessible::new(accessible::Role::CheckBox)
+                .label(&self.label)
+                .value(accessible::Value::Checked(self.is_checked)),
+        );
+
+        operation.activatable(None, &|| (self.on_toggle)(!self.is_checked));
+    }
+
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -229,21 +264,41 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let mouse_over = cursor.is_over(layout.bounds());
+
+        let mut status = event::Status::Ignored;
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-            | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                let mouse_over = cursor.is_over(layout.bounds());
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if mouse_over =>
+            {
+                shell.publish((self.on_toggle)(!self.is_checked));
 
-                if mouse_over {
-                    shell.publish((self.on_toggle)(!self.is_checked));
+                status = event::Status::Captured;
+            }
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                state.now = now;
 
-                    return event::Status::Captured;
+                if state.is_animating(now) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
                 }
+
+                return event::Status::Ignored;
             }
             _ => {}
         }
 
-        event::Status::Ignored
+        let target = if mouse_over { 1.0 } else { 0.0 };
+
+        if *state.hover.target() != target {
+            state.hover.go(target, Instant::now());
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -268,19 +323,25 @@ where
         theme: &Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor: mouse::Cursor,
+        _cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let is_mouse_over = cursor.is_over(layout.bounds());
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
 
         let mut children = layout.children();
 
-        let custom_style = if is_mouse_over {
-            theme.hovered(&self.style, self.is_checked)
-        } else {
-            theme.active(&self.style, self.is_checked)
+        let hover = state.hover.value(state.now);
+        let check = state.check.value(state.now);
+
+        let appearance = |is_checked| {
+            theme
+                .active(&self.style, is_checked)
+                .interpolate(&theme.hovered(&self.style, is_checked), hover)
         };
 
+        let custom_style =
+            appearance(false).interpolate(&appearance(true), check);
+
         {
             let layout = children.next().unwrap();
             let bounds = layout.bounds();
@@ -314,6 +375,10 @@ where
                         horizontal_alignment: alignment::Horizontal::Center,
                         vertical_alignment: alignment::Vertical::Center,
                         shaping: *shaping,
+                        wrapping: text::Wrapping::default(),
+                        letter_spacing: Pixels(0.0),
+                        ellipsis: text::Ellipsis::None,
+                        max_lines: None,
                     },
                     bounds.center(),
                     custom_style.icon_color,
@@ -329,9 +394,10 @@ where
                 renderer,
                 style,
                 label_layout,
-                tree.state.downcast_ref(),
+                &state.label,
                 crate::text::Appearance {
                     color: custom_style.text_color,
+                    selection: None,
                 },
                 viewport,
             );
@@ -353,6 +419,30 @@ where
     }
 }
 
+/// The local state of a [`Checkbox`].
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    hover: Animation<f32>,
+    check: Animation<f32>,
+    now: Instant,
+}
+
+impl<P: text::Paragraph> State<P> {
+    fn new(is_checked: bool) -> Self {
+        Self {
+            label: widget::text::State::default(),
+            hover: Animation::new(0.0),
+            check: Animation::new(if is_checked { 1.0 } else { 0.0 }),
+            now: Instant::now(),
+        }
+    }
+
+    fn is_animating(&self, now: Instant) -> bool {
+        self.hover.is_animating(now) || self.check.is_animating(now)
+    }
+}
+
 /// The icon in a [`Checkbox`].
 #[derive(Debug, Clone, PartialEq)]
 pub struct Icon<Font> {
@@ -0,0 +1,476 @@
+//! Drag elements around and drop them on a [`DropTarget`].
+//!
+//! [`DropTarget`]: crate::DropTarget
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// The distance the cursor needs to travel before a drag starts.
+const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
+
+thread_local! {
+    /// The payload of the ongoing drag of each window, if any.
+    static PAYLOADS: RefCell<HashMap<window::Id, Box<dyn Any>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Returns the payload of the ongoing drag in the given window, if it is of
+/// type `T`.
+pub(crate) fn payload<T: Clone + 'static>(window: window::Id) -> Option<T> {
+    PAYLOADS.with(|payloads| {
+        payloads
+            .borrow()
+            .get(&window)
+            .and_then(|payload| payload.downcast_ref::<T>())
+            .cloned()
+    })
+}
+
+/// Ends the ongoing drag in the given window, if any.
+pub(crate) fn clear_payload(window: window::Id) {
+    PAYLOADS.with(|payloads| {
+        let _ = payloads.borrow_mut().remove(&window);
+    });
+}
+
+fn set_payload<T: 'static>(window: window::Id, value: T) {
+    PAYLOADS.with(|payloads| {
+        let _ = payloads.borrow_mut().insert(window, Box::new(value));
+    });
+}
+
+/// An element that can be dragged with the mouse, carrying a typed payload
+/// that a [`DropTarget`] can accept.
+///
+/// While dragging, a preview of the content—or a custom preview element—is
+/// drawn in an overlay following the cursor. Pressing `Escape` or moving the
+/// cursor out of the window cancels the drag.
+///
+/// [`DropTarget`]: crate::DropTarget
+#[allow(missing_debug_implementations)]
+pub struct Draggable<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    payload: T,
+    content: Element<'a, Message, Theme, Renderer>,
+    preview: Option<Element<'a, Message, Theme, Renderer>>,
+    on_drag_start: Option<Message>,
+    on_drag_end: Option<Message>,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    Draggable<'a, T, Message, Theme, Renderer>
+{
+    /// Creates a new [`Draggable`] with the given payload and content.
+    pub fn new(
+        payload: T,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            payload,
+            content: content.into(),
+            preview: None,
+            on_drag_start: None,
+            on_drag_end: None,
+        }
+    }
+
+    /// Sets the element drawn under the cursor while dragging.
+    ///
+    /// By default, the content of the [`Draggable`] is used.
+    pub fn preview(
+        mut self,
+        preview: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.preview = Some(preview.into());
+        self
+    }
+
+    /// Sets the message produced when a drag starts.
+    pub fn on_drag_start(mut self, message: Message) -> Self {
+        self.on_drag_start = Some(message);
+        self
+    }
+
+    /// Sets the message produced when a drag ends, whether the payload was
+    /// dropped or the drag was cancelled.
+    pub fn on_drag_end(mut self, message: Message) -> Self {
+        self.on_drag_end = Some(message);
+        self
+    }
+
+    fn elements(&self) -> Vec<&Element<'a, Message, Theme, Renderer>> {
+        std::iter::once(&self.content)
+            .chain(&self.preview)
+            .collect()
+    }
+}
+
+/// The local state of a [`Draggable`].
+#[derive(Debug, Clone, Copy)]
+struct State {
+    /// The window of the [`Draggable`], learned from its window events.
+    window: window::Id,
+    drag: Drag,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            window: window::Id::MAIN,
+            drag: Drag::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+enum Drag {
+    #[default]
+    Idle,
+    Pressed {
+        origin: Point,
+    },
+    Dragging {
+        grab: Vector,
+        cursor: Point,
+        size: Size,
+    },
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Draggable<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    Message: Clone,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.elements().into_iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.elements());
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let State { window, drag } = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Window(id, _) => {
+                *window = id;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let (event::Status::Ignored, Some(origin)) =
+                    (status, cursor.position_over(bounds))
+                {
+                    *drag = Drag::Pressed { origin };
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some(position) = cursor.position() else {
+                    return status;
+                };
+
+                match drag {
+                    Drag::Pressed { origin }
+                        if position.distance(*origin)
+                            > DRAG_DEADBAND_DISTANCE =>
+                    {
+                        *drag = Drag::Dragging {
+                            grab: *origin - bounds.position(),
+                            cursor: position,
+                            size: bounds.size(),
+                        };
+
+                        set_payload(*window, self.payload.clone());
+
+                        if let Some(on_drag_start) = &self.on_drag_start {
+                            shell.publish(on_drag_start.clone());
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    Drag::Dragging { cursor, .. } => {
+                        *cursor = position;
+                    }
+                    _ => {}
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Mouse(mouse::Event::CursorLeft)
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if let Drag::Dragging { .. } = drag {
+                    clear_payload(*window);
+
+                    if let Some(on_drag_end) = &self.on_drag_end {
+                        shell.publish(on_drag_end.clone());
+                    }
+                }
+
+                *drag = Drag::Idle;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) if matches!(drag, Drag::Dragging { .. }) => {
+                *drag = Drag::Idle;
+
+                clear_payload(*window);
+
+                if let Some(on_drag_end) = &self.on_drag_end {
+                    shell.publish(on_drag_end.clone());
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if let Drag::Dragging { .. } = tree.state.downcast_ref::<State>().drag {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let Drag::Dragging { grab, cursor, size } =
+            tree.state.downcast_ref::<State>().drag
+        else {
+            return self.content.as_widget_mut().overlay(
+                &mut tree.children[0],
+                layout,
+                renderer,
+            );
+        };
+
+        let (element, tree, size) = match &self.preview {
+            Some(preview) => (preview, &mut tree.children[1], None),
+            None => (&self.content, &mut tree.children[0], Some(size)),
+        };
+
+        Some(overlay::Element::new(
+            cursor - grab,
+            Box::new(Preview {
+                element,
+                tree,
+                size,
+                grab,
+            }),
+        ))
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<Draggable<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(draggable: Draggable<'a, T, Message, Theme, Renderer>) -> Self {
+        Self::new(draggable)
+    }
+}
+
+/// The preview of a [`Draggable`] following the cursor.
+struct Preview<'a, 'b, Message, Theme, Renderer> {
+    element: &'b Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    size: Option<Size>,
+    grab: Vector,
+}
+
+impl<'a, 'b, Message, Theme, Renderer>
+    crate::core::Overlay<Message, Theme, Renderer>
+    for Preview<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+        _translation: Vector,
+    ) -> layout::Node {
+        let limits =
+            layout::Limits::new(Size::ZERO, self.size.unwrap_or(bounds));
+
+        self.element
+            .as_widget()
+            .layout(self.tree, renderer, &limits)
+            .move_to(position)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+
+        // The layout lags one event behind, so we follow the cursor directly
+        let translation = cursor.position().map_or(Vector::ZERO, |position| {
+            let grab = Vector::new(
+                self.grab.x.min(bounds.width),
+                self.grab.y.min(bounds.height),
+            );
+
+            position - grab - bounds.position()
+        });
+
+        renderer.with_layer(bounds + translation, |renderer| {
+            renderer.with_translation(translation, |renderer| {
+                self.element.as_widget().draw(
+                    self.tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    &bounds,
+                );
+            });
+        });
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        false
+    }
+}
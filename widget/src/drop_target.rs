@@ -0,0 +1,500 @@
+//! Accept the payload of a [`Draggable`] dropped on an element.
+//!
+//! [`Draggable`]: crate::Draggable
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+use crate::draggable;
+
+/// An element that accepts the payloads of type `T` of a [`Draggable`].
+///
+/// A [`DropTarget`] produces messages when a compatible payload is dragged
+/// over it, dragged out of it, and dropped on it, which can be used to
+/// highlight the target or to show where the payload would land.
+///
+/// [`Draggable`]: crate::Draggable
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<
+    'a,
+    T,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    content: Element<'a, Message, Theme, Renderer>,
+    accept: Box<dyn Fn(&T) -> bool + 'a>,
+    on_enter: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_leave: Option<Message>,
+    on_drop: Option<Box<dyn Fn(T) -> Message + 'a>>,
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    DropTarget<'a, T, Message, Theme, Renderer>
+{
+    /// Creates a new [`DropTarget`] with the given content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            accept: Box::new(|_| true),
+            on_enter: None,
+            on_leave: None,
+            on_drop: None,
+        }
+    }
+
+    /// Sets the predicate deciding whether a payload can be dropped on the
+    /// [`DropTarget`].
+    ///
+    /// By default, every payload of type `T` is accepted.
+    pub fn accept(mut self, accept: impl Fn(&T) -> bool + 'a) -> Self {
+        self.accept = Box::new(accept);
+        self
+    }
+
+    /// Sets the message produced when an accepted payload is dragged over the
+    /// [`DropTarget`].
+    pub fn on_enter(mut self, on_enter: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_enter = Some(Box::new(on_enter));
+        self
+    }
+
+    /// Sets the message produced when an accepted payload is dragged out of
+    /// the [`DropTarget`], or the drag is cancelled over it.
+    pub fn on_leave(mut self, message: Message) -> Self {
+        self.on_leave = Some(message);
+        self
+    }
+
+    /// Sets the message produced when an accepted payload is dropped on the
+    /// [`DropTarget`].
+    pub fn on_drop(mut self, on_drop: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Returns the payload of the ongoing drag in the given window, if the
+    /// [`DropTarget`] accepts it.
+    fn accepted(&self, window: window::Id) -> Option<T>
+    where
+        T: Clone + 'static,
+    {
+        draggable::payload::<T>(window).filter(|payload| (self.accept)(payload))
+    }
+}
+
+/// The local state of a [`DropTarget`].
+struct State<T> {
+    /// The window of the [`DropTarget`], learned from its window events.
+    window: window::Id,
+    hovered: Option<T>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for DropTarget<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    Message: Clone,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<T> {
+            window: window::Id::MAIN,
+            hovered: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        match event {
+            Event::Window(id, _) => {
+                state.window = id;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let payload = self
+                    .accepted(state.window)
+                    .filter(|_| cursor.is_over(layout.bounds()));
+
+                match (payload, state.hovered.is_some()) {
+                    (Some(payload), false) => {
+                        if let Some(on_enter) = &self.on_enter {
+                            shell.publish(on_enter(payload.clone()));
+                        }
+
+                        state.hovered = Some(payload);
+                    }
+                    (None, true) => {
+                        state.hovered = None;
+
+                        if let Some(on_leave) = &self.on_leave {
+                            shell.publish(on_leave.clone());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                // The drag ends even if its draggable is gone
+                draggable::clear_payload(state.window);
+
+                if let Some(payload) = state.hovered.take() {
+                    if let Some(on_drop) = &self.on_drop {
+                        shell.publish(on_drop(payload));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorLeft)
+            | Event::Touch(touch::Event::FingerLost { .. })
+            | Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) if state.hovered.is_some() => {
+                state.hovered = None;
+
+                draggable::clear_payload(state.window);
+
+                if let Some(on_leave) = &self.on_leave {
+                    shell.publish(on_leave.clone());
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let window = tree.state.downcast_ref::<State<T>>().window;
+
+        if cursor.is_over(layout.bounds())
+            && draggable::payload::<T>(window).is_some()
+            && self.accepted(window).is_none()
+        {
+            return mouse::Interaction::NotAllowed;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<DropTarget<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + 'static,
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(drop_target: DropTarget<'a, T, Message, Theme, Renderer>) -> Self {
+        Self::new(drop_target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+    use crate::core::renderer::Null;
+    use crate::core::{Point, Size};
+    use crate::runtime::user_interface::{self, UserInterface};
+    use crate::{Draggable, Row, Space};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Start,
+        End,
+        Enter(u32),
+        Leave,
+        Drop(u32),
+    }
+
+    /// A draggable payload next to a drop target, both 50x50.
+    fn view<'a>() -> Element<'a, Message, (), Null> {
+        Row::with_children([
+            Draggable::new(7_u32, Space::new(50, 50))
+                .on_drag_start(Message::Start)
+                .on_drag_end(Message::End)
+                .into(),
+            DropTarget::new(Space::new(50, 50))
+                .on_enter(Message::Enter)
+                .on_leave(Message::Leave)
+                .on_drop(Message::Drop)
+                .into(),
+        ])
+        .into()
+    }
+
+    /// Processes the given events one at a time, rebuilding the user
+    /// interface in between like an application would.
+    fn simulate(events: impl IntoIterator<Item = Event>) -> Vec<Message> {
+        let mut renderer = Null::new();
+        let mut cache = user_interface::Cache::default();
+        let mut cursor = mouse::Cursor::Unavailable;
+        let mut messages = Vec::new();
+
+        for event in events {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    cursor = mouse::Cursor::Available(position);
+                }
+                Event::Mouse(mouse::Event::CursorLeft) => {
+                    cursor = mouse::Cursor::Unavailable;
+                }
+                _ => {}
+            }
+
+            let mut user_interface = UserInterface::build(
+                view(),
+                Size::new(200.0, 100.0),
+                cache,
+                &mut renderer,
+            );
+
+            let _ = user_interface.update(
+                &[event],
+                cursor,
+                &mut renderer,
+                &mut clipboard::Null,
+                &mut messages,
+            );
+
+            cache = user_interface.into_cache();
+        }
+
+        messages
+    }
+
+    fn move_to(x: f32, y: f32) -> Event {
+        Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(x, y),
+        })
+    }
+
+    fn press() -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    }
+
+    fn release() -> Event {
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    }
+
+    fn escape() -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(key::Named::Escape),
+            location: keyboard::Location::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            text: None,
+        })
+    }
+
+    #[test]
+    fn drags_start_after_the_deadband() {
+        assert_eq!(
+            simulate([move_to(10.0, 10.0), press(), move_to(15.0, 15.0)]),
+            []
+        );
+
+        assert_eq!(
+            simulate([move_to(10.0, 10.0), press(), move_to(30.0, 10.0)]),
+            [Message::Start]
+        );
+    }
+
+    #[test]
+    fn payloads_enter_leave_and_drop() {
+        let messages = simulate([
+            move_to(10.0, 10.0),
+            press(),
+            move_to(30.0, 10.0),
+            move_to(75.0, 25.0),
+            move_to(30.0, 25.0),
+            move_to(75.0, 25.0),
+            release(),
+        ]);
+
+        assert_eq!(
+            messages,
+            [
+                Message::Start,
+                Message::Enter(7),
+                Message::Leave,
+                Message::Enter(7),
+                Message::End,
+                Message::Drop(7),
+            ]
+        );
+        assert_eq!(draggable::payload::<u32>(window::Id::MAIN), None);
+    }
+
+    #[test]
+    fn escape_cancels_the_drag() {
+        let messages = simulate([
+            move_to(10.0, 10.0),
+            press(),
+            move_to(75.0, 25.0),
+            escape(),
+            release(),
+        ]);
+
+        assert_eq!(
+            messages,
+            [
+                Message::Start,
+                Message::Enter(7),
+                Message::End,
+                Message::Leave
+            ]
+        );
+        assert_eq!(draggable::payload::<u32>(window::Id::MAIN), None);
+    }
+
+    #[test]
+    fn leaving_the_window_cancels_the_drag() {
+        let messages = simulate([
+            move_to(10.0, 10.0),
+            press(),
+            move_to(75.0, 25.0),
+            Event::Mouse(mouse::Event::CursorLeft),
+            move_to(75.0, 25.0),
+            release(),
+        ]);
+
+        assert_eq!(
+            messages,
+            [
+                Message::Start,
+                Message::Enter(7),
+                Message::End,
+                Message::Leave
+            ]
+        );
+        assert_eq!(draggable::payload::<u32>(window::Id::MAIN), None);
+    }
+}
@@ -3,10 +3,15 @@ use crate::button::{self, Button};
 use crate::checkbox::{self, Checkbox};
 use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
+use crate::context_menu::{self, ContextMenu};
 use crate::core;
 use crate::core::widget::operation;
 use crate::core::{Element, Length, Pixels};
+use crate::draggable::Draggable;
+use crate::drop_target::DropTarget;
 use crate::keyed;
+use crate::list::{self, List};
+use crate::menu_bar::{self, MenuBar};
 use crate::overlay;
 use crate::pick_list::{self, PickList};
 use crate::progress_bar::{self, ProgressBar};
@@ -20,7 +25,7 @@ use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
-use crate::{Column, MouseArea, Row, Space, Themer, VerticalSlider};
+use crate::{Column, MouseArea, Row, Space, Stack, Themer, VerticalSlider};
 
 use std::borrow::Cow;
 use std::ops::RangeInclusive;
@@ -51,6 +56,19 @@ macro_rules! row {
     );
 }
 
+/// Creates a [`Stack`] with the given children.
+///
+/// [`Stack`]: crate::Stack
+#[macro_export]
+macro_rules! stack {
+    () => (
+        $crate::Stack::new()
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::Stack::with_children([$($crate::core::Element::from($x)),+])
+    );
+}
+
 /// Creates a new [`Container`] with the provided content.
 ///
 /// [`Container`]: crate::Container
@@ -64,6 +82,53 @@ where
     Container::new(content)
 }
 
+/// Creates a new [`ContextMenu`] that opens a menu with the given items when
+/// the content is right-clicked.
+///
+/// [`ContextMenu`]: crate::ContextMenu
+pub fn context_menu<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    items: impl IntoIterator<Item = context_menu::Item<Message>>,
+) -> ContextMenu<'a, Message, Theme, Renderer>
+where
+    Theme: context_menu::StyleSheet,
+    Renderer: core::text::Renderer,
+{
+    ContextMenu::new(content, items)
+}
+
+/// Creates a new [`MenuBar`] with the given menus.
+///
+/// [`MenuBar`]: crate::MenuBar
+pub fn menu_bar<Message, Theme, Renderer>(
+    menus: impl IntoIterator<Item = menu_bar::Menu<Message>>,
+) -> MenuBar<Message, Theme, Renderer>
+where
+    Theme: menu_bar::StyleSheet,
+    Renderer: core::text::Renderer,
+{
+    MenuBar::new(menus)
+}
+
+/// Creates a new [`Draggable`] carrying the given payload.
+///
+/// [`Draggable`]: crate::Draggable
+pub fn draggable<'a, T, Message, Theme, Renderer>(
+    payload: T,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Draggable<'a, T, Message, Theme, Renderer> {
+    Draggable::new(payload, content)
+}
+
+/// Creates a new [`DropTarget`] accepting payloads of type `T`.
+///
+/// [`DropTarget`]: crate::DropTarget
+pub fn drop_target<'a, T, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> DropTarget<'a, T, Message, Theme, Renderer> {
+    DropTarget::new(content)
+}
+
 /// Creates a new [`Column`] with the given children.
 pub fn column<'a, Message, Theme, Renderer>(
     children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
@@ -97,6 +162,31 @@ where
     Row::with_children(children)
 }
 
+/// Creates a new [`Stack`] with the given children.
+pub fn stack<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Stack<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Stack::with_children(children)
+}
+
+/// Creates a new [`List`] with the given amount of items, their height, and
+/// a closure that produces the item with the given index.
+///
+/// [`List`]: crate::List
+pub fn list<'a, Message, Theme, Renderer>(
+    count: usize,
+    item_height: list::ItemHeight,
+    view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> List<'a, usize, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    List::new(count, item_height, view)
+}
+
 /// Creates a new [`Scrollable`] with the provided content.
 ///
 /// [`Scrollable`]: crate::Scrollable
@@ -152,6 +242,29 @@ where
     Text::new(text.to_string())
 }
 
+/// Creates a new [`Rich`] text widget with the provided [`Span`]s.
+///
+/// [`Rich`]: text::Rich
+/// [`Span`]: text::Span
+pub fn rich_text<'a, Link, Theme, Renderer>(
+    spans: impl Into<Vec<text::Span<'a, Link, Renderer::Font>>>,
+) -> text::Rich<'a, Link, Theme, Renderer>
+where
+    Theme: text::StyleSheet,
+    Renderer: core::text::Renderer,
+{
+    text::Rich::new(spans)
+}
+
+/// Creates a new [`Span`] of text with the provided content.
+///
+/// [`Span`]: text::Span
+pub fn span<'a, Link, Font>(
+    text: impl Into<Cow<'a, str>>,
+) -> text::Span<'a, Link, Font> {
+    text::Span::new(text)
+}
+
 /// Creates a new [`Checkbox`].
 ///
 /// [`Checkbox`]: crate::Checkbox
@@ -1,25 +1,81 @@
 //! Display images in your user interface.
+pub mod animated;
 pub mod viewer;
+pub use animated::Animated;
 pub use viewer::Viewer;
 
+use crate::core::border;
+use crate::core::event::{self, Event};
 use crate::core::image;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::time::Duration;
 use crate::core::widget::Tree;
+use crate::core::window;
 use crate::core::{
-    ContentFit, Element, Layout, Length, Rectangle, Size, Vector, Widget,
+    Clipboard, Color, ContentFit, Element, Layout, Length, Radians, Rectangle,
+    Shell, Size, Vector, Widget,
 };
+use crate::runtime::Command;
 
 use std::hash::Hash;
 
-pub use image::{FilterMethod, Handle};
+pub use crate::graphics::image::decoder;
+pub use crate::graphics::image::load_animation;
+pub use image::{Animation, FilterMethod, Frame, Handle};
 
 /// Creates a new [`Viewer`] with the given image `Handle`.
 pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
     Viewer::new(handle)
 }
 
+/// Creates a new [`Animated`] image playing the given [`Animation`].
+pub fn animated(animation: Animation) -> Animated {
+    Animated::new(animation)
+}
+
+/// Produces a [`Command`] that decodes the image of the given [`Handle`] in
+/// the background.
+///
+/// Images are decoded lazily the first time they are drawn, showing a
+/// placeholder until ready. Loading them ahead of time avoids the placeholder
+/// altogether.
+pub fn load(
+    handle: impl Into<Handle>,
+) -> Command<Result<Handle, decoder::Error>> {
+    let handle = handle.into();
+
+    Command::perform(decoder::decode(&handle), move |result| {
+        result.map(|_| handle)
+    })
+}
+
+/// How often a widget checks whether its image has finished loading.
+const LOADING_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The color of the placeholder drawn while an image is loading.
+const PLACEHOLDER_COLOR: Color = Color::from_rgba(0.5, 0.5, 0.5, 0.2);
+
+/// Requests a redraw until the image of the given handle has finished
+/// loading.
+fn poll_loading<Renderer, Handle, Message>(
+    renderer: &Renderer,
+    handle: &Handle,
+    event: &Event,
+    shell: &mut Shell<'_, Message>,
+) where
+    Renderer: image::Renderer<Handle = Handle>,
+{
+    if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
+        if renderer.is_loading(handle) {
+            shell.request_redraw(window::RedrawRequest::At(
+                *now + LOADING_POLL_INTERVAL,
+            ));
+        }
+    }
+}
+
 /// A frame that displays an image while keeping aspect ratio.
 ///
 /// # Example
@@ -38,6 +94,10 @@ pub struct Image<Handle> {
     height: Length,
     content_fit: ContentFit,
     filter_method: FilterMethod,
+    rotation: Radians,
+    opacity: f32,
+    tint: Option<Color>,
+    border_radius: border::Radius,
 }
 
 impl<Handle> Image<Handle> {
@@ -49,6 +109,10 @@ impl<Handle> Image<Handle> {
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
             filter_method: FilterMethod::default(),
+            rotation: Radians(0.0),
+            opacity: 1.0,
+            tint: None,
+            border_radius: border::Radius::default(),
         }
     }
 
@@ -77,6 +141,38 @@ impl<Handle> Image<Handle> {
         self.filter_method = filter_method;
         self
     }
+
+    /// Sets the rotation of the [`Image`] around its center.
+    ///
+    /// The rotation does not affect the layout of the [`Image`].
+    pub fn rotation(mut self, rotation: impl Into<Radians>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Image`], from `0.0` (transparent) to `1.0`
+    /// (opaque).
+    ///
+    /// Defaults to `1.0`
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Sets the [`Color`] multiplied with the pixels of the [`Image`].
+    pub fn tint(mut self, tint: impl Into<Color>) -> Self {
+        self.tint = Some(tint.into());
+        self
+    }
+
+    /// Sets the border radius of the corners of the [`Image`].
+    pub fn border_radius(
+        mut self,
+        border_radius: impl Into<border::Radius>,
+    ) -> Self {
+        self.border_radius = border_radius.into();
+        self
+    }
 }
 
 /// Computes the layout of an [`Image`].
@@ -126,14 +222,30 @@ pub fn draw<Renderer, Handle>(
     handle: &Handle,
     content_fit: ContentFit,
     filter_method: FilterMethod,
+    rotation: Radians,
+    opacity: f32,
+    tint: Option<Color>,
+    border_radius: border::Radius,
 ) where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone + Hash,
 {
+    let bounds = layout.bounds();
+
+    if renderer.is_loading(handle) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            PLACEHOLDER_COLOR,
+        );
+
+        return;
+    }
+
     let Size { width, height } = renderer.dimensions(handle);
     let image_size = Size::new(width as f32, height as f32);
-
-    let bounds = layout.bounds();
     let adjusted_fit = content_fit.fit(image_size, bounds.size());
 
     let render = |renderer: &mut Renderer| {
@@ -148,7 +260,15 @@ pub fn draw<Renderer, Handle>(
             ..bounds
         };
 
-        renderer.draw(handle.clone(), filter_method, drawing_bounds + offset);
+        renderer.draw_image(
+            handle.clone(),
+            filter_method,
+            drawing_bounds + offset,
+            rotation,
+            opacity,
+            tint,
+            border_radius,
+        );
     };
 
     if adjusted_fit.width > bounds.width || adjusted_fit.height > bounds.height
@@ -188,6 +308,22 @@ where
         )
     }
 
+    fn on_event(
+        &mut self,
+        _state: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        poll_loading(renderer, &self.handle, &event, shell);
+
+        event::Status::Ignored
+    }
+
     fn draw(
         &self,
         _state: &Tree,
@@ -204,6 +340,10 @@ where
             &self.handle,
             self.content_fit,
             self.filter_method,
+            self.rotation,
+            self.opacity,
+            self.tint,
+            self.border_radius,
         );
     }
 }
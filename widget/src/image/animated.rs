@@ -0,0 +1,278 @@
+//! Play animated images.
+use crate::core::border;
+use crate::core::event::{self, Event};
+use crate::core::image::{self, Animation};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, ContentFit, Element, Layout, Length, Radians, Rectangle, Shell,
+    Size, Widget,
+};
+
+/// The shortest delay between two frames.
+///
+/// Many animated images declare a delay of zero, which browsers treat as a
+/// sensible default instead.
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(20);
+
+/// A frame that plays an [`Animation`] while keeping aspect ratio.
+///
+/// Frames are advanced by scheduling redraws at the exact time the next frame
+/// is due, so an idle animation does not keep the application busy.
+#[derive(Debug)]
+pub struct Animated {
+    animation: Animation,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: image::FilterMethod,
+    is_playing: bool,
+    is_looping: bool,
+}
+
+impl Animated {
+    /// Creates a new [`Animated`] image playing the given [`Animation`].
+    pub fn new(animation: Animation) -> Self {
+        Self {
+            animation,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            filter_method: image::FilterMethod::default(),
+            is_playing: true,
+            is_looping: true,
+        }
+    }
+
+    /// Sets the width of the [`Animated`] boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Animated`] boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Animated`] image.
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Animated`] image.
+    ///
+    /// [`FilterMethod`]: image::FilterMethod
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets whether the [`Animated`] image is playing or paused.
+    ///
+    /// A paused animation keeps displaying its current frame. Defaults to
+    /// `true`.
+    pub fn playing(mut self, is_playing: bool) -> Self {
+        self.is_playing = is_playing;
+        self
+    }
+
+    /// Sets whether the [`Animated`] image starts over after its last frame.
+    ///
+    /// Otherwise, the animation stops on its last frame. Defaults to `true`.
+    pub fn looping(mut self, is_looping: bool) -> Self {
+        self.is_looping = is_looping;
+        self
+    }
+
+    fn delay(&self, frame: usize) -> Duration {
+        self.animation
+            .frames()
+            .get(frame)
+            .map_or(MIN_FRAME_DELAY, |frame| frame.delay.max(MIN_FRAME_DELAY))
+    }
+
+    fn handle(&self, frame: usize) -> Option<&image::Handle> {
+        let frames = self.animation.frames();
+
+        frames
+            .get(frame)
+            .or_else(|| frames.last())
+            .map(|frame| &frame.handle)
+    }
+}
+
+/// The local state of an [`Animated`] image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct State {
+    animation: u64,
+    frame: usize,
+    deadline: Option<Instant>,
+}
+
+impl State {
+    fn new(animation: &Animation) -> Self {
+        Self {
+            animation: animation.id(),
+            frame: 0,
+            deadline: None,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Animated
+where
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(&self.animation))
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Some(handle) = self.handle(0) else {
+            return layout::Node::new(limits.resolve(
+                self.width,
+                self.height,
+                Size::ZERO,
+            ));
+        };
+
+        super::layout(
+            renderer,
+            limits,
+            handle,
+            self.width,
+            self.height,
+            self.content_fit,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let Event::Window(_, window::Event::RedrawRequested(now)) = event
+        else {
+            return event::Status::Ignored;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.animation != self.animation.id() {
+            *state = State::new(&self.animation);
+        }
+
+        if let Some(handle) = self.handle(state.frame) {
+            super::poll_loading(renderer, handle, &event, shell);
+        }
+
+        let count = self.animation.frames().len();
+
+        if !self.is_playing || count < 2 {
+            state.deadline = None;
+
+            return event::Status::Ignored;
+        }
+
+        let mut deadline = match state.deadline {
+            // Skip whole loops if we fell far behind (e.g. while hidden)
+            Some(deadline) if now < deadline + self.animation.duration() => {
+                deadline
+            }
+            _ => now + self.delay(state.frame),
+        };
+
+        while now >= deadline {
+            if state.frame + 1 < count {
+                state.frame += 1;
+            } else if self.is_looping {
+                state.frame = 0;
+            } else {
+                state.deadline = None;
+
+                return event::Status::Ignored;
+            }
+
+            deadline += self.delay(state.frame);
+        }
+
+        state.deadline = Some(deadline);
+        shell.request_redraw(window::RedrawRequest::At(deadline));
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let frame = if state.animation == self.animation.id() {
+            state.frame
+        } else {
+            0
+        };
+
+        if let Some(handle) = self.handle(frame) {
+            super::draw(
+                renderer,
+                layout,
+                handle,
+                self.content_fit,
+                self.filter_method,
+                Radians(0.0),
+                1.0,
+                None,
+                border::Radius::default(),
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Animated>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn from(animated: Animated) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(animated)
+    }
+}
@@ -1,20 +1,30 @@
 //! Zoom and pan on an image.
+use crate::core::border;
 use crate::core::event::{self, Event};
 use crate::core::image;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{self, Operation};
 use crate::core::{
-    Clipboard, Element, Layout, Length, Pixels, Point, Rectangle, Shell, Size,
-    Vector, Widget,
+    Clipboard, Element, Layout, Length, Pixels, Point, Radians, Rectangle,
+    Shell, Size, Vector, Widget,
 };
+use crate::runtime::Command;
 
+use std::any::Any;
+use std::f32::consts::FRAC_PI_2;
 use std::hash::Hash;
 
 /// A frame that displays an image with the ability to zoom in/out and pan.
+///
+/// A [`Viewer`] with an [`Id`] can also be controlled programmatically with
+/// commands like [`fit`], [`actual_size`], [`center_on`], and
+/// [`rotate_clockwise`].
 #[allow(missing_debug_implementations)]
-pub struct Viewer<Handle> {
+pub struct Viewer<Handle, F = Ignore> {
+    id: Option<Id>,
     padding: f32,
     width: Length,
     height: Length,
@@ -23,12 +33,14 @@ pub struct Viewer<Handle> {
     scale_step: f32,
     handle: Handle,
     filter_method: image::FilterMethod,
+    on_viewport_change: F,
 }
 
 impl<Handle> Viewer<Handle> {
     /// Creates a new [`Viewer`] with the given [`State`].
     pub fn new(handle: Handle) -> Self {
         Viewer {
+            id: None,
             handle,
             padding: 0.0,
             width: Length::Shrink,
@@ -37,8 +49,17 @@ impl<Handle> Viewer<Handle> {
             max_scale: 10.0,
             scale_step: 0.10,
             filter_method: image::FilterMethod::default(),
+            on_viewport_change: Ignore,
         }
     }
+}
+
+impl<Handle, F> Viewer<Handle, F> {
+    /// Sets the [`Id`] of the [`Viewer`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
 
     /// Sets the padding of the [`Viewer`].
     pub fn padding(mut self, padding: impl Into<Pixels>) -> Self {
@@ -82,13 +103,51 @@ impl<Handle> Viewer<Handle> {
         self.scale_step = scale_step;
         self
     }
+
+    /// Sets the message that should be produced when the [`Viewport`] of the
+    /// [`Viewer`] changes.
+    ///
+    /// This can be used to keep multiple viewers in sync with
+    /// [`set_viewport`].
+    pub fn on_viewport_change<Message, G>(self, f: G) -> Viewer<Handle, G>
+    where
+        G: Fn(Viewport) -> Message,
+    {
+        Viewer {
+            id: self.id,
+            padding: self.padding,
+            width: self.width,
+            height: self.height,
+            min_scale: self.min_scale,
+            max_scale: self.max_scale,
+            scale_step: self.scale_step,
+            handle: self.handle,
+            filter_method: self.filter_method,
+            on_viewport_change: f,
+        }
+    }
+
+    fn geometry<Renderer>(&self, renderer: &Renderer, bounds: Size) -> Geometry
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let Size { width, height } = renderer.dimensions(&self.handle);
+
+        Geometry {
+            bounds,
+            image: Size::new(width as f32, height as f32),
+            min_scale: self.min_scale,
+            max_scale: self.max_scale,
+        }
+    }
 }
 
-impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer>
-    for Viewer<Handle>
+impl<Message, Theme, Renderer, Handle, F> Widget<Message, Theme, Renderer>
+    for Viewer<Handle, F>
 where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone + Hash,
+    F: OnViewportChange<Message>,
 {
     fn tag(&self) -> tree::Tag {
         tree::Tag::of::<State>()
@@ -143,6 +202,20 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        state.geometry = self.geometry(renderer, layout.bounds().size());
+
+        operation.custom(state, self.id.as_ref().map(|id| &id.0));
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -151,12 +224,17 @@ where
         cursor: mouse::Cursor,
         renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
-        _shell: &mut Shell<'_, Message>,
+        shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
         let bounds = layout.bounds();
 
-        match event {
+        super::poll_loading(renderer, &self.handle, &event, shell);
+
+        let state = tree.state.downcast_mut::<State>();
+        state.geometry = self.geometry(renderer, bounds.size());
+
+        let status = match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
                 let Some(cursor_position) = cursor.position_over(bounds) else {
                     return event::Status::Ignored;
@@ -165,7 +243,6 @@ where
                 match delta {
                     mouse::ScrollDelta::Lines { y, .. }
                     | mouse::ScrollDelta::Pixels { y, .. } => {
-                        let state = tree.state.downcast_mut::<State>();
                         let previous_scale = state.scale;
 
                         if y < 0.0 && previous_scale > self.min_scale
@@ -216,16 +293,12 @@ where
                     return event::Status::Ignored;
                 };
 
-                let state = tree.state.downcast_mut::<State>();
-
                 state.cursor_grabbed_at = Some(cursor_position);
                 state.starting_offset = state.current_offset;
 
                 event::Status::Captured
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                let state = tree.state.downcast_mut::<State>();
-
                 if state.cursor_grabbed_at.is_some() {
                     state.cursor_grabbed_at = None;
 
@@ -235,8 +308,6 @@ where
                 }
             }
             Event::Mouse(mouse::Event::CursorMoved { position }) => {
-                let state = tree.state.downcast_mut::<State>();
-
                 if let Some(origin) = state.cursor_grabbed_at {
                     let image_size = image_size(
                         renderer,
@@ -278,7 +349,29 @@ where
                 }
             }
             _ => event::Status::Ignored,
+        };
+
+        let viewport = state.viewport();
+
+        // Programmatic changes are noticed on the next event, which is
+        // always a redraw request after an operation
+        match state.last_notified {
+            Some(last_notified) if last_notified == viewport => {}
+            Some(_) => {
+                state.last_notified = Some(viewport);
+
+                if let Some(message) =
+                    self.on_viewport_change.on_viewport_change(viewport)
+                {
+                    shell.publish(message);
+                }
+            }
+            None => {
+                state.last_notified = Some(viewport);
+            }
         }
+
+        status
     }
 
     fn mouse_interaction(
@@ -315,6 +408,18 @@ where
         let state = tree.state.downcast_ref::<State>();
         let bounds = layout.bounds();
 
+        if renderer.is_loading(&self.handle) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                super::PLACEHOLDER_COLOR,
+            );
+
+            return;
+        }
+
         let image_size =
             image_size(renderer, &self.handle, state, bounds.size());
 
@@ -327,23 +432,64 @@ where
             image_top_left - state.offset(bounds, image_size)
         };
 
+        // The image is drawn unrotated and then rotated around its center
+        let drawing_size = if state.rotation.is_sideways() {
+            Size::new(image_size.height, image_size.width)
+        } else {
+            image_size
+        };
+
         renderer.with_layer(bounds, |renderer| {
             renderer.with_translation(translation, |renderer| {
-                image::Renderer::draw(
-                    renderer,
+                renderer.draw_image(
                     self.handle.clone(),
                     self.filter_method,
                     Rectangle {
-                        x: bounds.x,
-                        y: bounds.y,
-                        ..Rectangle::with_size(image_size)
+                        x: bounds.x
+                            + (image_size.width - drawing_size.width) / 2.0,
+                        y: bounds.y
+                            + (image_size.height - drawing_size.height) / 2.0,
+                        ..Rectangle::with_size(drawing_size)
                     },
+                    state.rotation.radians(),
+                    1.0,
+                    None,
+                    border::Radius::default(),
                 );
             });
         });
     }
 }
 
+/// The reaction of a [`Viewer`] to changes of its [`Viewport`].
+///
+/// It is implemented by any `Fn(Viewport) -> Message` and by [`Ignore`],
+/// which produces no messages.
+pub trait OnViewportChange<Message> {
+    /// Returns the message to produce for the new [`Viewport`], if any.
+    fn on_viewport_change(&self, viewport: Viewport) -> Option<Message>;
+}
+
+impl<Message, F> OnViewportChange<Message> for F
+where
+    F: Fn(Viewport) -> Message,
+{
+    fn on_viewport_change(&self, viewport: Viewport) -> Option<Message> {
+        Some(self(viewport))
+    }
+}
+
+/// The default [`OnViewportChange`] of a [`Viewer`], which ignores every
+/// change.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ignore;
+
+impl<Message> OnViewportChange<Message> for Ignore {
+    fn on_viewport_change(&self, _viewport: Viewport) -> Option<Message> {
+        None
+    }
+}
+
 /// The local state of a [`Viewer`].
 #[derive(Debug, Clone, Copy)]
 pub struct State {
@@ -351,6 +497,9 @@ pub struct State {
     starting_offset: Vector,
     current_offset: Vector,
     cursor_grabbed_at: Option<Point>,
+    rotation: Rotation,
+    geometry: Geometry,
+    last_notified: Option<Viewport>,
 }
 
 impl Default for State {
@@ -360,6 +509,9 @@ impl Default for State {
             starting_offset: Vector::default(),
             current_offset: Vector::default(),
             cursor_grabbed_at: None,
+            rotation: Rotation::default(),
+            geometry: Geometry::default(),
+            last_notified: None,
         }
     }
 }
@@ -389,16 +541,193 @@ impl State {
     pub fn is_cursor_grabbed(&self) -> bool {
         self.cursor_grabbed_at.is_some()
     }
+
+    /// Returns the current [`Viewport`] of the [`State`].
+    fn viewport(&self) -> Viewport {
+        let Geometry { bounds, .. } = self.geometry;
+
+        let image_size = self.oriented_image();
+        let display_size = Size::new(
+            image_size.width * self.pixel_scale(),
+            image_size.height * self.pixel_scale(),
+        );
+
+        let offset = self.offset(Rectangle::with_size(bounds), display_size);
+
+        Viewport {
+            scale: self.pixel_scale(),
+            offset: offset * (1.0 / self.pixel_scale()),
+            rotation: self.rotation,
+        }
+    }
+
+    /// Returns the size of the image, as currently oriented.
+    fn oriented_image(&self) -> Size {
+        let image = self.geometry.image;
+
+        if self.rotation.is_sideways() {
+            Size::new(image.height, image.width)
+        } else {
+            image
+        }
+    }
+
+    /// Returns the ratio the image is scaled by to fit the [`Viewer`].
+    fn fit_ratio(&self) -> f32 {
+        let image = self.oriented_image();
+        let bounds = self.geometry.bounds;
+
+        (bounds.width / image.width).min(bounds.height / image.height)
+    }
+
+    /// Returns the scale of the image relative to its actual size.
+    fn pixel_scale(&self) -> f32 {
+        self.fit_ratio().min(1.0) * self.scale
+    }
+
+    /// Zooms the image to the given scale relative to its actual size,
+    /// keeping the center of the [`Viewer`] in place.
+    fn zoom_to(&mut self, scale: f32) {
+        let previous_scale = self.pixel_scale();
+
+        self.scale = (scale / self.fit_ratio().min(1.0))
+            .clamp(self.geometry.min_scale, self.geometry.max_scale);
+
+        self.current_offset =
+            self.current_offset * (self.pixel_scale() / previous_scale);
+    }
+
+    /// Centers the [`Viewer`] on the given point of the image, in image
+    /// pixels.
+    fn center_on(&mut self, point: Point) {
+        let image = self.oriented_image();
+        let center = Point::new(image.width / 2.0, image.height / 2.0);
+
+        self.current_offset = (point - center) * self.pixel_scale();
+    }
+
+    /// Rotates the image around the center of the [`Viewer`].
+    fn rotate(&mut self, rotation: Rotation) {
+        let previous_scale = self.pixel_scale();
+
+        let offset = match (rotation.turns() + 4 - self.rotation.turns()) % 4 {
+            1 => Vector::new(-self.current_offset.y, self.current_offset.x),
+            2 => Vector::new(-self.current_offset.x, -self.current_offset.y),
+            3 => Vector::new(self.current_offset.y, -self.current_offset.x),
+            _ => self.current_offset,
+        };
+
+        self.rotation = rotation;
+        self.current_offset = offset * (self.pixel_scale() / previous_scale);
+    }
+
+    /// Sets the current [`Viewport`] of the [`State`].
+    fn set_viewport(&mut self, viewport: Viewport) {
+        self.rotate(viewport.rotation);
+        self.zoom_to(viewport.scale);
+        self.current_offset = viewport.offset * self.pixel_scale();
+    }
+}
+
+/// The current [`Viewport`] of a [`Viewer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The scale of the image relative to its actual size.
+    ///
+    /// A scale of `1.0` displays the image at 100%.
+    pub scale: f32,
+    /// The offset of the center of the [`Viewer`] from the center of the
+    /// image, in image pixels.
+    pub offset: Vector,
+    /// The [`Rotation`] of the image.
+    pub rotation: Rotation,
+}
+
+/// The rotation of the image of a [`Viewer`], in quarter turns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    /// The image is not rotated.
+    #[default]
+    None,
+    /// The image is rotated 90 degrees clockwise.
+    Clockwise,
+    /// The image is rotated 180 degrees.
+    UpsideDown,
+    /// The image is rotated 90 degrees counterclockwise.
+    CounterClockwise,
+}
+
+impl Rotation {
+    /// Returns the [`Rotation`] turned 90 degrees clockwise.
+    pub fn clockwise(self) -> Self {
+        Self::from_turns(self.turns() + 1)
+    }
+
+    /// Returns the [`Rotation`] turned 90 degrees counterclockwise.
+    pub fn counterclockwise(self) -> Self {
+        Self::from_turns(self.turns() + 3)
+    }
+
+    /// Returns the angle of the [`Rotation`].
+    pub fn radians(self) -> Radians {
+        Radians(self.turns() as f32 * FRAC_PI_2)
+    }
+
+    /// Returns whether the width and height of the image are swapped.
+    pub fn is_sideways(self) -> bool {
+        self.turns() % 2 == 1
+    }
+
+    fn turns(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Clockwise => 1,
+            Self::UpsideDown => 2,
+            Self::CounterClockwise => 3,
+        }
+    }
+
+    fn from_turns(turns: u8) -> Self {
+        match turns % 4 {
+            1 => Self::Clockwise,
+            2 => Self::UpsideDown,
+            3 => Self::CounterClockwise,
+            _ => Self::None,
+        }
+    }
+}
+
+/// The last known layout of a [`Viewer`], needed to resolve operations.
+#[derive(Debug, Clone, Copy)]
+struct Geometry {
+    bounds: Size,
+    image: Size,
+    min_scale: f32,
+    max_scale: f32,
 }
 
-impl<'a, Message, Theme, Renderer, Handle> From<Viewer<Handle>>
+impl Default for Geometry {
+    fn default() -> Self {
+        Self {
+            bounds: Size::UNIT,
+            image: Size::UNIT,
+            min_scale: 0.25,
+            max_scale: 10.0,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer, Handle, F> From<Viewer<Handle, F>>
     for Element<'a, Message, Theme, Renderer>
 where
     Renderer: 'a + image::Renderer<Handle = Handle>,
     Message: 'a,
     Handle: Clone + Hash + 'a,
+    F: OnViewportChange<Message> + 'a,
 {
-    fn from(viewer: Viewer<Handle>) -> Element<'a, Message, Theme, Renderer> {
+    fn from(
+        viewer: Viewer<Handle, F>,
+    ) -> Element<'a, Message, Theme, Renderer> {
         Element::new(viewer)
     }
 }
@@ -418,7 +747,11 @@ where
     let Size { width, height } = renderer.dimensions(handle);
 
     let (width, height) = {
-        let dimensions = (width as f32, height as f32);
+        let dimensions = if state.rotation.is_sideways() {
+            (height as f32, width as f32)
+        } else {
+            (width as f32, height as f32)
+        };
 
         let width_ratio = bounds.width / dimensions.0;
         let height_ratio = bounds.height / dimensions.1;
@@ -435,3 +768,196 @@ where
 
     Size::new(width, height)
 }
+
+/// The identifier of a [`Viewer`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that zooms the image of the [`Viewer`] with the
+/// given [`Id`] to fit its bounds, centering it.
+pub fn fit<Message: 'static>(id: Id) -> Command<Message> {
+    control(id, |state| {
+        state.zoom_to(state.fit_ratio());
+        state.current_offset = Vector::default();
+    })
+}
+
+/// Produces a [`Command`] that zooms the image of the [`Viewer`] with the
+/// given [`Id`] to its actual size.
+pub fn actual_size<Message: 'static>(id: Id) -> Command<Message> {
+    zoom_to(id, 1.0)
+}
+
+/// Produces a [`Command`] that zooms the image of the [`Viewer`] with the
+/// given [`Id`] to the given scale, relative to its actual size.
+///
+/// The scale is bounded by the minimum and maximum scale of the [`Viewer`].
+pub fn zoom_to<Message: 'static>(id: Id, scale: f32) -> Command<Message> {
+    control(id, move |state| state.zoom_to(scale))
+}
+
+/// Produces a [`Command`] that centers the [`Viewer`] with the given [`Id`]
+/// on the given point of its image, in image pixels.
+pub fn center_on<Message: 'static>(id: Id, point: Point) -> Command<Message> {
+    control(id, move |state| state.center_on(point))
+}
+
+/// Produces a [`Command`] that rotates the image of the [`Viewer`] with the
+/// given [`Id`] 90 degrees clockwise.
+pub fn rotate_clockwise<Message: 'static>(id: Id) -> Command<Message> {
+    control(id, |state| state.rotate(state.rotation.clockwise()))
+}
+
+/// Produces a [`Command`] that rotates the image of the [`Viewer`] with the
+/// given [`Id`] 90 degrees counterclockwise.
+pub fn rotate_counterclockwise<Message: 'static>(id: Id) -> Command<Message> {
+    control(id, |state| state.rotate(state.rotation.counterclockwise()))
+}
+
+/// Produces a [`Command`] that sets the [`Viewport`] of the [`Viewer`] with
+/// the given [`Id`].
+///
+/// This can be used to mirror the [`Viewport`] of another [`Viewer`].
+pub fn set_viewport<Message: 'static>(
+    id: Id,
+    viewport: Viewport,
+) -> Command<Message> {
+    control(id, move |state| state.set_viewport(viewport))
+}
+
+fn control<Message: 'static>(
+    id: Id,
+    f: impl Fn(&mut State) + 'static,
+) -> Command<Message> {
+    struct Control<F> {
+        target: widget::Id,
+        f: F,
+    }
+
+    impl<T, F> Operation<T> for Control<F>
+    where
+        F: Fn(&mut State),
+    {
+        fn container(
+            &mut self,
+            _id: Option<&widget::Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+            if Some(&self.target) != id {
+                return;
+            }
+
+            if let Some(state) = state.downcast_mut::<State>() {
+                (self.f)(state);
+            }
+        }
+    }
+
+    Command::widget(Control { target: id.0, f })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> State {
+        State {
+            geometry: Geometry {
+                bounds: Size::new(200.0, 100.0),
+                image: Size::new(400.0, 100.0),
+                ..Geometry::default()
+            },
+            ..State::default()
+        }
+    }
+
+    #[test]
+    fn zoom_to_keeps_the_center_in_place() {
+        let mut state = state();
+        state.center_on(Point::new(220.0, 50.0));
+
+        assert_eq!(state.viewport().scale, 0.5);
+        assert_eq!(state.viewport().offset, Vector::new(20.0, 0.0));
+
+        state.zoom_to(1.0);
+
+        assert_eq!(state.viewport().scale, 1.0);
+        assert_eq!(state.viewport().offset, Vector::new(20.0, 0.0));
+
+        state.zoom_to(100.0);
+
+        assert_eq!(state.viewport().scale, 5.0);
+    }
+
+    #[test]
+    fn center_on_targets_image_pixels() {
+        let mut state = state();
+        state.zoom_to(1.0);
+        state.center_on(Point::new(300.0, 50.0));
+
+        assert_eq!(state.viewport().offset, Vector::new(100.0, 0.0));
+
+        state.center_on(Point::new(800.0, 50.0));
+
+        // Clamped so that the edge of the image can reach the center
+        assert_eq!(state.viewport().offset, Vector::new(300.0, 0.0));
+    }
+
+    #[test]
+    fn rotate_turns_the_offset_around_the_center() {
+        let mut state = state();
+        state.center_on(Point::new(300.0, 50.0));
+        state.rotate(Rotation::Clockwise);
+
+        let viewport = state.viewport();
+
+        assert_eq!(viewport.rotation, Rotation::Clockwise);
+        assert_eq!(viewport.scale, 0.25);
+        assert_eq!(viewport.offset, Vector::new(0.0, 100.0));
+
+        state.rotate(Rotation::None);
+
+        assert_eq!(state.viewport().scale, 0.5);
+        assert_eq!(state.viewport().offset, Vector::new(100.0, 0.0));
+    }
+
+    #[test]
+    fn set_viewport_round_trips() {
+        let mut source = state();
+        source.zoom_to(2.0);
+        source.center_on(Point::new(150.0, 60.0));
+        source.rotate(Rotation::UpsideDown);
+
+        let viewport = source.viewport();
+
+        let mut target = state();
+        target.set_viewport(viewport);
+
+        assert_eq!(target.viewport(), viewport);
+    }
+}
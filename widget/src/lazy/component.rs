@@ -312,6 +312,8 @@ where
             shell.request_redraw(redraw_request);
         }
 
+        shell.request_input_method(local_shell.input_method());
+
         if !local_messages.is_empty() {
             let mut heads = self.state.take().unwrap().into_heads();
 
@@ -383,6 +385,24 @@ where
                 self.operation.text_input(state, id);
             }
 
+            fn text(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                text: &str,
+            ) {
+                self.operation.text(id, bounds, text);
+            }
+
+            fn accessible(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                accessible: widget::operation::Accessible<'_>,
+            ) {
+                self.operation.accessible(id, bounds, accessible);
+            }
+
             fn scrollable(
                 &mut self,
                 state: &mut dyn widget::operation::Scrollable,
@@ -652,6 +672,8 @@ where
             shell.request_redraw(redraw_request);
         }
 
+        shell.request_input_method(local_shell.input_method());
+
         if !local_messages.is_empty() {
             let mut inner =
                 self.overlay.take().unwrap().0.take().unwrap().into_heads();
@@ -18,23 +18,32 @@ pub use iced_style as style;
 
 mod column;
 mod mouse_area;
-mod row;
+mod stack;
 mod themer;
 
 pub mod button;
 pub mod checkbox;
 pub mod combo_box;
 pub mod container;
+pub mod context_menu;
+pub mod draggable;
+pub mod drop_target;
+pub mod grid;
 pub mod keyed;
+pub mod list;
+pub mod menu_bar;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod row;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod space;
+pub mod table;
+pub mod tabs;
 pub mod text;
 pub mod text_editor;
 pub mod text_input;
@@ -66,6 +75,18 @@ pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use drop_target::DropTarget;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use list::List;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
 pub use pane_grid::PaneGrid;
@@ -86,6 +107,12 @@ pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_editor::TextEditor;
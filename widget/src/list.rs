@@ -0,0 +1,689 @@
+//! Display huge lists of items by only building the visible ones.
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::window;
+use crate::core::{
+    Clipboard, Element, Length, Pixels, Point, Rectangle, Shell, Size, Widget,
+};
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Range;
+
+/// A vertical list of items that are only built when visible.
+///
+/// A [`List`] is meant to be placed inside a [`Scrollable`]. It only calls
+/// its `view` closure for the items that intersect the visible viewport, and
+/// it only keeps the widget state of those items.
+///
+/// Overlays of the items are not supported.
+///
+/// [`Scrollable`]: crate::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct List<
+    'a,
+    Key,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    count: usize,
+    item_height: ItemHeight,
+    spacing: f32,
+    width: Length,
+    key: Box<dyn Fn(usize) -> Key + 'a>,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    visible: RefCell<Visible<'a, Key, Message, Theme, Renderer>>,
+}
+
+/// The height of the items of a [`List`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ItemHeight {
+    /// Every item has the same exact height.
+    ///
+    /// This is the most efficient strategy, since no item needs to be
+    /// measured.
+    Fixed(f32),
+
+    /// Items have different heights, which are estimated until they become
+    /// visible and can be measured.
+    Estimated(f32),
+}
+
+impl<'a, Message, Theme, Renderer> List<'a, usize, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`List`] with the given amount of items, their
+    /// [`ItemHeight`], and a closure that produces the item with the given
+    /// index.
+    ///
+    /// The widget state of every item is tied to its index.
+    pub fn new(
+        count: usize,
+        item_height: ItemHeight,
+        view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self::with_keys(count, item_height, |index| index, view)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> List<'a, Key, Message, Theme, Renderer>
+where
+    Key: Eq + Hash + Clone + 'static,
+    Renderer: crate::core::Renderer,
+{
+    /// Creates a new [`List`] like [`List::new`], but with a closure that
+    /// produces the key of the item with the given index.
+    ///
+    /// The widget state of every item is tied to its key.
+    pub fn with_keys(
+        count: usize,
+        item_height: ItemHeight,
+        key: impl Fn(usize) -> Key + 'a,
+        view: impl Fn(usize) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            count,
+            item_height,
+            spacing: 0.0,
+            width: Length::Fill,
+            key: Box::new(key),
+            view: Box::new(view),
+            visible: RefCell::new(Visible {
+                range: 0..0,
+                width: 0.0,
+                items: Vec::new(),
+            }),
+        }
+    }
+
+    /// Sets the vertical spacing _between_ items.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the width of the [`List`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Builds and lays out the items that intersect the given viewport.
+    fn sync(
+        &self,
+        state: &mut Internal<Key>,
+        visible: &mut Visible<'a, Key, Message, Theme, Renderer>,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) {
+        state.resize(self.count, self.item_height, self.spacing, &self.key);
+
+        let range = bounds
+            .intersection(viewport)
+            .map(|visible| {
+                state.range(
+                    visible.y - bounds.y,
+                    visible.y + visible.height - bounds.y,
+                    self.item_height,
+                    self.spacing,
+                )
+            })
+            .unwrap_or(0..0);
+
+        if range == visible.range && bounds.width == visible.width {
+            return;
+        }
+
+        let mut previous: HashMap<usize, _> = visible
+            .items
+            .drain(..)
+            .map(|item| (item.index, item))
+            .collect();
+
+        let max_height = match self.item_height {
+            ItemHeight::Fixed(height) => height,
+            ItemHeight::Estimated(_) => f32::INFINITY,
+        };
+
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(bounds.width, max_height),
+        );
+
+        let mut trees = HashMap::with_capacity(range.len());
+
+        for index in range.clone() {
+            let (key, element) = match previous.remove(&index) {
+                Some(item) => (item.key, item.element),
+                None => ((self.key)(index), (self.view)(index)),
+            };
+
+            let mut tree = match state.trees.remove(&key) {
+                Some(mut tree) => {
+                    tree.diff(&element);
+                    tree
+                }
+                None => Tree::new(&element),
+            };
+
+            let node = element.as_widget().layout(&mut tree, renderer, &limits);
+
+            if let ItemHeight::Estimated(_) = self.item_height {
+                state.measure(
+                    index,
+                    key.clone(),
+                    node.size().height,
+                    self.spacing,
+                );
+            }
+
+            let _ = trees.insert(key.clone(), tree);
+
+            visible.items.push(Item {
+                index,
+                key,
+                element,
+                node,
+            });
+        }
+
+        for item in &mut visible.items {
+            let offset =
+                state.offset(item.index, self.item_height, self.spacing);
+
+            item.node.move_to_mut(Point::new(0.0, offset));
+        }
+
+        // Only the state of the visible items is kept around
+        state.trees = trees;
+
+        visible.range = range;
+        visible.width = bounds.width;
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for List<'a, Key, Message, Theme, Renderer>
+where
+    Key: Eq + Hash + Clone + 'static,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<RefCell<Internal<Key>>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(RefCell::new(Internal::<Key>::default()))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        // The items may have been reordered since the last view
+        tree.state
+            .downcast_mut::<RefCell<Internal<Key>>>()
+            .get_mut()
+            .invalidate();
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<RefCell<Internal<Key>>>();
+        let state = state.get_mut();
+
+        state.resize(self.count, self.item_height, self.spacing, &self.key);
+        state.is_outdated = false;
+
+        let height =
+            state.total_height(self.count, self.item_height, self.spacing);
+
+        let limits = limits.width(self.width);
+
+        layout::Node::new(limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(0.0, height),
+        ))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<RefCell<Internal<Key>>>();
+        let state = state.get_mut();
+        let visible = self.visible.borrow();
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for item in &visible.items {
+                if let Some(tree) = state.trees.get_mut(&item.key) {
+                    item.element.as_widget().operate(
+                        tree,
+                        item_layout(layout, &item.node),
+                        renderer,
+                        operation,
+                    );
+                }
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<RefCell<Internal<Key>>>();
+        let state = state.get_mut();
+        let mut visible = self.visible.borrow_mut();
+
+        self.sync(state, &mut visible, renderer, layout.bounds(), viewport);
+
+        let status = visible
+            .items
+            .iter_mut()
+            .filter_map(|item| {
+                let tree = state.trees.get_mut(&item.key)?;
+
+                Some(item.element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    item_layout(layout, &item.node),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                ))
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if state.is_outdated {
+            state.is_outdated = false;
+            shell.invalidate_layout();
+
+            // Items measured while drawing this frame need another one
+            // to be laid out
+            if let Event::Window(_, window::Event::RedrawRequested(_)) = event {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut state = tree
+            .state
+            .downcast_ref::<RefCell<Internal<Key>>>()
+            .borrow_mut();
+        let mut visible = self.visible.borrow_mut();
+
+        self.sync(
+            &mut state,
+            &mut visible,
+            renderer,
+            layout.bounds(),
+            viewport,
+        );
+
+        visible
+            .items
+            .iter()
+            .filter_map(|item| {
+                let tree = state.trees.get(&item.key)?;
+
+                Some(item.element.as_widget().mouse_interaction(
+                    tree,
+                    item_layout(layout, &item.node),
+                    cursor,
+                    viewport,
+                    renderer,
+                ))
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut state = tree
+            .state
+            .downcast_ref::<RefCell<Internal<Key>>>()
+            .borrow_mut();
+        let mut visible = self.visible.borrow_mut();
+
+        self.sync(
+            &mut state,
+            &mut visible,
+            renderer,
+            layout.bounds(),
+            viewport,
+        );
+
+        let Some(viewport) = layout.bounds().intersection(viewport) else {
+            return;
+        };
+
+        for item in &visible.items {
+            if let Some(tree) = state.trees.get(&item.key) {
+                item.element.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    style,
+                    item_layout(layout, &item.node),
+                    cursor,
+                    &viewport,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer>
+    From<List<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Eq + Hash + Clone + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(list: List<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(list)
+    }
+}
+
+fn item_layout<'a>(layout: Layout<'_>, node: &'a layout::Node) -> Layout<'a> {
+    Layout::with_offset(layout.position() - Point::ORIGIN, node)
+}
+
+struct Visible<'a, Key, Message, Theme, Renderer> {
+    range: Range<usize>,
+    width: f32,
+    items: Vec<Item<'a, Key, Message, Theme, Renderer>>,
+}
+
+struct Item<'a, Key, Message, Theme, Renderer> {
+    index: usize,
+    key: Key,
+    element: Element<'a, Message, Theme, Renderer>,
+    node: layout::Node,
+}
+
+struct Internal<Key> {
+    trees: HashMap<Key, Tree>,
+    count: usize,
+    estimate: f32,
+    spacing: f32,
+    measured: HashMap<Key, f32>,
+    heights: Vec<f32>,
+    offsets: Vec<f32>,
+    is_outdated: bool,
+}
+
+impl<Key> Default for Internal<Key> {
+    fn default() -> Self {
+        Self {
+            trees: HashMap::new(),
+            count: 0,
+            estimate: 0.0,
+            spacing: 0.0,
+            measured: HashMap::new(),
+            heights: Vec::new(),
+            offsets: Vec::new(),
+            is_outdated: false,
+        }
+    }
+}
+
+impl<Key> Internal<Key>
+where
+    Key: Eq + Hash,
+{
+    fn resize(
+        &mut self,
+        count: usize,
+        item_height: ItemHeight,
+        spacing: f32,
+        key: &dyn Fn(usize) -> Key,
+    ) {
+        self.count = count;
+
+        let ItemHeight::Estimated(estimate) = item_height else {
+            self.measured.clear();
+            self.heights.clear();
+            self.offsets.clear();
+            return;
+        };
+
+        if self.offsets.len() == count + 1
+            && self.estimate == estimate
+            && self.spacing == spacing
+        {
+            return;
+        }
+
+        self.estimate = estimate;
+        self.spacing = spacing;
+
+        // Measured heights follow the keys of their items, so they survive
+        // any reordering; forgotten keys are dropped along the way
+        let mut measured = HashMap::new();
+
+        self.heights = (0..count)
+            .map(|index| {
+                let key = key(index);
+
+                match self.measured.remove(&key) {
+                    Some(height) => {
+                        let _ = measured.insert(key, height);
+                        height
+                    }
+                    None => estimate,
+                }
+            })
+            .collect();
+
+        self.measured = measured;
+        self.update_offsets(0, spacing);
+    }
+
+    /// Forces the heights of the items to be looked up by key on the next
+    /// resize.
+    fn invalidate(&mut self) {
+        self.offsets.clear();
+    }
+
+    fn measure(&mut self, index: usize, key: Key, height: f32, spacing: f32) {
+        let _ = self.measured.insert(key, height);
+
+        if self.heights[index] != height {
+            self.heights[index] = height;
+            self.update_offsets(index, spacing);
+            self.is_outdated = true;
+        }
+    }
+
+    fn update_offsets(&mut self, from: usize, spacing: f32) {
+        self.offsets.resize(self.heights.len() + 1, 0.0);
+
+        for i in from..self.heights.len() {
+            self.offsets[i + 1] = self.offsets[i] + self.heights[i] + spacing;
+        }
+    }
+
+    fn offset(
+        &self,
+        index: usize,
+        item_height: ItemHeight,
+        spacing: f32,
+    ) -> f32 {
+        match item_height {
+            ItemHeight::Fixed(height) => index as f32 * (height + spacing),
+            ItemHeight::Estimated(_) => self.offsets[index],
+        }
+    }
+
+    fn total_height(
+        &self,
+        count: usize,
+        item_height: ItemHeight,
+        spacing: f32,
+    ) -> f32 {
+        if count == 0 {
+            return 0.0;
+        }
+
+        self.offset(count, item_height, spacing) - spacing
+    }
+
+    /// Returns the range of items that intersect the given vertical span.
+    fn range(
+        &self,
+        top: f32,
+        bottom: f32,
+        item_height: ItemHeight,
+        spacing: f32,
+    ) -> Range<usize> {
+        match item_height {
+            ItemHeight::Fixed(height) => {
+                let stride = (height + spacing).max(f32::EPSILON);
+
+                let start =
+                    ((top / stride).floor().max(0.0) as usize).min(self.count);
+                let end = (bottom / stride).ceil().max(0.0) as usize;
+
+                start..end.clamp(start, self.count)
+            }
+            ItemHeight::Estimated(_) => {
+                // `offsets[i]` is where the item `i` starts, and
+                // `offsets[i + 1]` is where it ends
+                let start =
+                    self.offsets[1..].partition_point(|end| *end <= top);
+                let end = self.offsets[..self.count]
+                    .partition_point(|start| *start < bottom);
+
+                start..end.max(start)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_range_covers_visible_items() {
+        let mut state = Internal::<usize>::default();
+        let item_height = ItemHeight::Fixed(10.0);
+
+        state.resize(1_000_000, item_height, 0.0, &|index| index);
+
+        assert_eq!(state.range(95.0, 125.0, item_height, 0.0), 9..13);
+        assert_eq!(
+            state.total_height(1_000_000, item_height, 0.0),
+            10_000_000.0
+        );
+        assert_eq!(
+            state.range(1e9, 2e9, item_height, 0.0),
+            1_000_000..1_000_000
+        );
+    }
+
+    #[test]
+    fn estimated_range_follows_measurements() {
+        let mut state = Internal::<usize>::default();
+        let item_height = ItemHeight::Estimated(10.0);
+
+        state.resize(10, item_height, 0.0, &|index| index);
+        assert_eq!(state.range(0.0, 25.0, item_height, 0.0), 0..3);
+
+        state.measure(0, 0, 30.0, 0.0);
+
+        assert!(state.is_outdated);
+        assert_eq!(state.range(0.0, 25.0, item_height, 0.0), 0..1);
+        assert_eq!(state.total_height(10, item_height, 0.0), 120.0);
+    }
+
+    #[test]
+    fn estimated_offsets_follow_spacing() {
+        let mut state = Internal::<usize>::default();
+        let item_height = ItemHeight::Estimated(10.0);
+
+        state.resize(10, item_height, 0.0, &|index| index);
+        state.measure(0, 0, 30.0, 0.0);
+        state.resize(10, item_height, 5.0, &|index| index);
+
+        assert_eq!(state.offset(1, item_height, 5.0), 35.0);
+        assert_eq!(state.total_height(10, item_height, 5.0), 165.0);
+    }
+
+    #[test]
+    fn measured_heights_follow_their_keys() {
+        let mut state = Internal::<char>::default();
+        let item_height = ItemHeight::Estimated(10.0);
+        let keys = ['a', 'b', 'c'];
+
+        state.resize(3, item_height, 0.0, &|index| keys[index]);
+        state.measure(0, 'a', 30.0, 0.0);
+
+        let keys = ['b', 'c', 'a'];
+
+        state.invalidate();
+        state.resize(3, item_height, 0.0, &|index| keys[index]);
+
+        assert_eq!(state.heights, [10.0, 10.0, 30.0]);
+        assert_eq!(state.offset(2, item_height, 0.0), 20.0);
+
+        let keys = ['b', 'c'];
+
+        state.invalidate();
+        state.resize(2, item_height, 0.0, &|index| keys[index]);
+
+        assert!(state.measured.is_empty());
+    }
+}
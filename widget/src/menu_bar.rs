@@ -0,0 +1,670 @@
+//! Display a bar of menus with keyboard accelerators.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget::{tree, Tree};
+use crate::core::{
+    Border, Clipboard, Element, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::overlay::cascade::{self, Cascade};
+
+pub use cascade::{Accelerator, Appearance, Item, ParseError, StyleSheet};
+
+/// A horizontal bar of menus, like the File/Edit/View bar of desktop
+/// applications.
+///
+/// Each [`Menu`] opens a cascading overlay of [`Item`]s. Menus can be opened
+/// with the mouse or by pressing `Alt` along with their mnemonic, and then
+/// traversed with the arrow keys.
+///
+/// Items bound to an [`Accelerator`] produce their message whenever the
+/// shortcut is pressed, even if no menu is open.
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    menus: Vec<Menu<Message>>,
+    width: Length,
+    padding: Padding,
+    menu_width: f32,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
+    style: <Theme as StyleSheet>::Style,
+}
+
+impl<Message, Theme, Renderer> MenuBar<Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of the titles of a [`MenuBar`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 5.0,
+        bottom: 5.0,
+        right: 10.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`MenuBar`] with the given menus.
+    pub fn new(menus: impl IntoIterator<Item = Menu<Message>>) -> Self {
+        Self {
+            menus: menus.into_iter().collect(),
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            menu_width: 0.0,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::Basic,
+            font: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Menu`] to the [`MenuBar`].
+    pub fn push(mut self, menu: Menu<Message>) -> Self {
+        self.menus.push(menu);
+        self
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the titles of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the minimum width of the menus of the [`MenuBar`].
+    pub fn menu_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.menu_width = width.into().0;
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`MenuBar`].
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`MenuBar`].
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the message of the item bound to the given shortcut, if any.
+    fn accelerated(
+        &self,
+        key: &keyboard::Key,
+        modifiers: keyboard::Modifiers,
+    ) -> Option<&Message> {
+        self.menus
+            .iter()
+            .find_map(|menu| cascade::accelerated(&menu.items, key, modifiers))
+    }
+}
+
+/// A top-level menu of a [`MenuBar`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Menu<Message> {
+    label: String,
+    mnemonic: Option<char>,
+    items: Vec<Item<Message>>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates a new [`Menu`] with the given label and items.
+    ///
+    /// A `&` in the label marks the next character as the mnemonic of the
+    /// [`Menu`], which opens it when pressed along with `Alt`. Use `&&` to
+    /// display a literal `&`.
+    pub fn new(
+        label: impl AsRef<str>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        let mut mnemonic = None;
+        let mut label_chars = label.as_ref().chars();
+        let mut label = String::new();
+
+        while let Some(c) = label_chars.next() {
+            if c != '&' {
+                label.push(c);
+                continue;
+            }
+
+            match label_chars.next() {
+                Some('&') => label.push('&'),
+                Some(c) => {
+                    if mnemonic.is_none() {
+                        mnemonic = Some(c);
+                    }
+
+                    label.push(c);
+                }
+                None => {}
+            }
+        }
+
+        Self {
+            label,
+            mnemonic,
+            items: items.into_iter().collect(),
+        }
+    }
+
+    /// Sets the mnemonic of the [`Menu`] explicitly.
+    pub fn mnemonic(mut self, mnemonic: char) -> Self {
+        self.mnemonic = Some(mnemonic);
+        self
+    }
+
+    /// Returns the label of the [`Menu`].
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the items of the [`Menu`].
+    pub fn items(&self) -> &[Item<Message>] {
+        &self.items
+    }
+
+    fn matches_mnemonic(&self, c: &str) -> bool {
+        self.mnemonic.is_some_and(|mnemonic| {
+            c.chars()
+                .flat_map(char::to_lowercase)
+                .eq(mnemonic.to_lowercase())
+        })
+    }
+
+    /// Returns the index of the grapheme underlined as the mnemonic.
+    fn mnemonic_index(&self) -> Option<usize> {
+        let mnemonic = self.mnemonic?;
+
+        self.label
+            .chars()
+            .position(|c| c.to_lowercase().eq(mnemonic.to_lowercase()))
+    }
+}
+
+/// The local state of a [`MenuBar`].
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    menu: cascade::State,
+    active: usize,
+    titles: Vec<P>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<P: text::Paragraph> State<P> {
+    fn open<Message>(
+        &mut self,
+        index: usize,
+        menus: &[Menu<Message>],
+        highlight_first: bool,
+    ) {
+        self.active = index;
+
+        if highlight_first {
+            self.menu.open_first(&menus[index].items);
+        } else {
+            self.menu.open();
+        }
+    }
+}
+
+impl<P: text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self {
+            menu: cascade::State::new(),
+            active: 0,
+            titles: Vec::new(),
+            modifiers: keyboard::Modifiers::default(),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MenuBar<Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        state.titles.resize_with(self.menus.len(), Default::default);
+
+        for (menu, paragraph) in self.menus.iter().zip(&mut state.titles) {
+            paragraph.update(Text {
+                content: &menu.label,
+                size: text_size,
+                line_height: self.text_line_height,
+                font,
+                bounds: Size::INFINITY,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: self.text_shaping,
+                wrapping: text::Wrapping::default(),
+                letter_spacing: Pixels(0.0),
+                ellipsis: text::Ellipsis::None,
+                max_lines: None,
+            });
+        }
+
+        if state.active >= self.menus.len() {
+            state.active = 0;
+            state.menu.close();
+        }
+
+        let height = f32::from(self.text_line_height.to_absolute(text_size))
+            + self.padding.vertical();
+
+        let mut x = 0.0;
+
+        let titles = state
+            .titles
+            .iter()
+            .map(|title| {
+                let width = title.min_width() + self.padding.horizontal();
+
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size =
+            limits.resolve(self.width, Length::Shrink, Size::new(x, height));
+
+        layout::Node::with_children(size, titles)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let hovered_title = layout
+            .children()
+            .position(|title| cursor.is_over(title.bounds()));
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                ..
+            }) => {
+                if let Some(message) = self.accelerated(&key, modifiers) {
+                    shell.publish(message.clone());
+                    state.menu.close();
+
+                    return event::Status::Captured;
+                }
+
+                match key.as_ref() {
+                    keyboard::Key::Character(c) if modifiers.alt() => {
+                        if let Some(index) = self
+                            .menus
+                            .iter()
+                            .position(|menu| menu.matches_mnemonic(c))
+                        {
+                            state.open(index, &self.menus, true);
+
+                            return event::Status::Captured;
+                        }
+                    }
+                    keyboard::Key::Named(
+                        named
+                        @ (key::Named::ArrowLeft | key::Named::ArrowRight),
+                    ) if state.menu.is_open() => {
+                        let count = self.menus.len();
+                        let index = if named == key::Named::ArrowRight {
+                            (state.active + 1) % count
+                        } else {
+                            (state.active + count - 1) % count
+                        };
+
+                        state.open(index, &self.menus, true);
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) = hovered_title {
+                    if state.menu.is_open() && state.active == index {
+                        state.menu.close();
+                    } else {
+                        state.open(index, &self.menus, false);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(index) = hovered_title.filter(|index| {
+                    state.menu.is_open() && *index != state.active
+                }) {
+                    state.open(index, &self.menus, false);
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout
+            .children()
+            .any(|title| cursor.is_over(title.bounds()))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let appearance = theme.appearance(&self.style);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            appearance.background,
+        );
+
+        for (i, ((menu, title), layout)) in self
+            .menus
+            .iter()
+            .zip(&state.titles)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let bounds = layout.bounds();
+
+            let is_highlighted = (state.menu.is_open() && state.active == i)
+                || cursor.is_over(bounds);
+
+            let color = if is_highlighted {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border::with_radius(appearance.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    appearance.selected_background,
+                );
+
+                appearance.selected_text_color
+            } else {
+                appearance.text_color
+            };
+
+            let position =
+                Point::new(bounds.x + self.padding.left, bounds.center_y());
+
+            renderer.fill_paragraph(title, position, color, *viewport);
+
+            if !state.modifiers.alt() {
+                continue;
+            }
+
+            let Some(index) = menu.mnemonic_index() else {
+                continue;
+            };
+
+            let (Some(start), Some(end)) = (
+                title.grapheme_position(0, index),
+                title.grapheme_position(0, index + 1),
+            ) else {
+                continue;
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: position.x + start.x,
+                        y: bounds.y + bounds.height - self.padding.bottom,
+                        width: (end.x - start.x).max(1.0),
+                        height: 1.0,
+                    },
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if !state.menu.is_open() {
+            return None;
+        }
+
+        let title = layout.children().nth(state.active)?.bounds();
+        let items = &self.menus.get(state.active)?.items;
+
+        let mut menu = Cascade::new(&mut state.menu, items)
+            .width(self.menu_width)
+            .text_line_height(self.text_line_height)
+            .text_shaping(self.text_shaping)
+            .style(self.style.clone());
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        if let Some(font) = self.font {
+            menu = menu.font(font);
+        }
+
+        Some(overlay::Element::new(
+            title.position(),
+            Box::new(Overlay {
+                menu: menu.overlay(title.position(), title.height),
+                bar: layout.bounds(),
+                translation: Vector::ZERO,
+            }),
+        ))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MenuBar<Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(menu_bar: MenuBar<Message, Theme, Renderer>) -> Self {
+        Self::new(menu_bar)
+    }
+}
+
+/// The open menu of a [`MenuBar`].
+///
+/// Presses over the bar are left to the [`MenuBar`] itself, so that clicking
+/// a title can switch or close the open menu.
+struct Overlay<'a, Message, Theme, Renderer> {
+    menu: overlay::Element<'a, Message, Theme, Renderer>,
+    bar: Rectangle,
+    translation: Vector,
+}
+
+impl<'a, Message, Theme, Renderer>
+    crate::core::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+        translation: Vector,
+    ) -> layout::Node {
+        self.translation = translation;
+        self.menu.layout(renderer, bounds, translation)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Mouse(mouse::Event::ButtonPressed(_))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if cursor.is_over(self.bar + self.translation) {
+                return event::Status::Ignored;
+            }
+        }
+
+        self.menu
+            .on_event(event, layout, cursor, renderer, clipboard, shell)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.menu
+            .mouse_interaction(layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.menu.draw(renderer, theme, style, layout, cursor);
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        self.menu.is_over(layout, renderer, cursor_position)
+    }
+}
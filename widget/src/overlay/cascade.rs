@@ -0,0 +1,1054 @@
+//! Build and show cascading menus with nested submenus.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::{
+    Border, Clipboard, Color, Padding, Pixels, Point, Rectangle, Shell, Size,
+    Vector,
+};
+
+use std::fmt;
+use std::str::FromStr;
+
+pub use iced_style::menu::{Appearance, StyleSheet};
+
+/// An entry of a cascading menu.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item<Message> {
+    /// An item that produces a message when chosen.
+    Action {
+        /// The label of the item.
+        label: String,
+        /// The shortcut hint displayed next to the label.
+        shortcut: Option<String>,
+        /// The keyboard shortcut that chooses the item.
+        accelerator: Option<Accelerator>,
+        /// The message produced when the item is chosen.
+        ///
+        /// If `None`, the item is disabled.
+        on_press: Option<Message>,
+    },
+    /// A line separating groups of items.
+    Separator,
+    /// An item that opens a nested menu.
+    Submenu {
+        /// The label of the item.
+        label: String,
+        /// The items of the nested menu.
+        items: Vec<Item<Message>>,
+    },
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new [`Item::Action`] that produces the given message.
+    pub fn action(label: impl Into<String>, on_press: Message) -> Self {
+        Self::Action {
+            label: label.into(),
+            shortcut: None,
+            accelerator: None,
+            on_press: Some(on_press),
+        }
+    }
+
+    /// Creates a new disabled [`Item::Action`].
+    pub fn disabled(label: impl Into<String>) -> Self {
+        Self::Action {
+            label: label.into(),
+            shortcut: None,
+            accelerator: None,
+            on_press: None,
+        }
+    }
+
+    /// Creates a new [`Item::Separator`].
+    pub fn separator() -> Self {
+        Self::Separator
+    }
+
+    /// Creates a new [`Item::Submenu`] with the given items.
+    pub fn submenu(
+        label: impl Into<String>,
+        items: impl IntoIterator<Item = Item<Message>>,
+    ) -> Self {
+        Self::Submenu {
+            label: label.into(),
+            items: items.into_iter().collect(),
+        }
+    }
+
+    /// Sets the shortcut hint of an [`Item::Action`].
+    ///
+    /// It has no effect on other kinds of items.
+    pub fn shortcut(mut self, hint: impl Into<String>) -> Self {
+        if let Self::Action { shortcut, .. } = &mut self {
+            *shortcut = Some(hint.into());
+        }
+
+        self
+    }
+
+    /// Binds an [`Accelerator`] to an [`Item::Action`].
+    ///
+    /// Unless a shortcut hint has already been set, the accelerator is also
+    /// displayed next to the label.
+    ///
+    /// It has no effect on other kinds of items.
+    pub fn accelerator(mut self, accelerator: Accelerator) -> Self {
+        if let Self::Action {
+            shortcut,
+            accelerator: current,
+            ..
+        } = &mut self
+        {
+            if shortcut.is_none() {
+                *shortcut = Some(accelerator.to_string());
+            }
+
+            *current = Some(accelerator);
+        }
+
+        self
+    }
+
+    /// Returns the label of the [`Item`], if any.
+    pub fn label(&self) -> Option<&str> {
+        match self {
+            Self::Action { label, .. } | Self::Submenu { label, .. } => {
+                Some(label)
+            }
+            Self::Separator => None,
+        }
+    }
+
+    /// Returns whether the [`Item`] can be highlighted and chosen.
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            Self::Action { on_press, .. } => on_press.is_some(),
+            Self::Separator => false,
+            Self::Submenu { .. } => true,
+        }
+    }
+}
+
+/// Returns the message of the first enabled [`Item::Action`] bound to an
+/// [`Accelerator`] matching the given key and modifiers, searching nested
+/// menus as well.
+pub fn accelerated<'a, Message>(
+    items: &'a [Item<Message>],
+    key: &keyboard::Key,
+    modifiers: keyboard::Modifiers,
+) -> Option<&'a Message> {
+    items.iter().find_map(|item| match item {
+        Item::Action {
+            accelerator: Some(accelerator),
+            on_press: Some(on_press),
+            ..
+        } if accelerator.matches(key, modifiers) => Some(on_press),
+        Item::Submenu { items, .. } => accelerated(items, key, modifiers),
+        _ => None,
+    })
+}
+
+/// A keyboard shortcut, made of a key and some modifiers.
+///
+/// An [`Accelerator`] can be parsed from strings like `"Ctrl+Shift+S"`,
+/// `"Alt+F4"`, or `"Command+Q"`. Modifiers and key names are case-insensitive.
+/// `Command` stands for the platform command modifier (see
+/// [`keyboard::Modifiers::COMMAND`]), while `Logo`, `Super`, and `Cmd` stand
+/// for the logo key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accelerator {
+    /// The key of the [`Accelerator`].
+    pub key: keyboard::Key,
+    /// The modifiers that must be held along with the key.
+    pub modifiers: keyboard::Modifiers,
+}
+
+impl Accelerator {
+    /// Creates a new [`Accelerator`] from a key and some modifiers.
+    pub fn new(key: keyboard::Key, modifiers: keyboard::Modifiers) -> Self {
+        Self { key, modifiers }
+    }
+
+    /// Returns whether the given key and modifiers trigger the
+    /// [`Accelerator`].
+    ///
+    /// Character keys are compared case-insensitively, since holding `Shift`
+    /// usually changes the reported character. For the same reason, `Shift`
+    /// is ignored for characters other than letters, unless the
+    /// [`Accelerator`] requires it; so `"Ctrl++"` matches `Ctrl+Shift+=` on
+    /// most layouts.
+    pub fn matches(
+        &self,
+        key: &keyboard::Key,
+        modifiers: keyboard::Modifiers,
+    ) -> bool {
+        let mut modifiers = modifiers.intersection(
+            keyboard::Modifiers::CTRL
+                | keyboard::Modifiers::SHIFT
+                | keyboard::Modifiers::ALT
+                | keyboard::Modifiers::LOGO,
+        );
+
+        if let keyboard::Key::Character(expected) = self.key.as_ref() {
+            if !expected.chars().any(char::is_alphabetic)
+                && !self.modifiers.shift()
+            {
+                modifiers.remove(keyboard::Modifiers::SHIFT);
+            }
+        }
+
+        if modifiers != self.modifiers {
+            return false;
+        }
+
+        match (key.as_ref(), self.key.as_ref()) {
+            (
+                keyboard::Key::Character(pressed),
+                keyboard::Key::Character(expected),
+            ) => pressed.to_lowercase() == expected.to_lowercase(),
+            (pressed, expected) => pressed == expected,
+        }
+    }
+}
+
+/// The named keys understood by [`Accelerator::from_str`].
+const NAMED_KEYS: &[(&str, key::Named)] = &[
+    ("Enter", key::Named::Enter),
+    ("Tab", key::Named::Tab),
+    ("Space", key::Named::Space),
+    ("Backspace", key::Named::Backspace),
+    ("Delete", key::Named::Delete),
+    ("Insert", key::Named::Insert),
+    ("Escape", key::Named::Escape),
+    ("Home", key::Named::Home),
+    ("End", key::Named::End),
+    ("PageUp", key::Named::PageUp),
+    ("PageDown", key::Named::PageDown),
+    ("Up", key::Named::ArrowUp),
+    ("Down", key::Named::ArrowDown),
+    ("Left", key::Named::ArrowLeft),
+    ("Right", key::Named::ArrowRight),
+    ("F1", key::Named::F1),
+    ("F2", key::Named::F2),
+    ("F3", key::Named::F3),
+    ("F4", key::Named::F4),
+    ("F5", key::Named::F5),
+    ("F6", key::Named::F6),
+    ("F7", key::Named::F7),
+    ("F8", key::Named::F8),
+    ("F9", key::Named::F9),
+    ("F10", key::Named::F10),
+    ("F11", key::Named::F11),
+    ("F12", key::Named::F12),
+];
+
+/// An error produced when parsing an [`Accelerator`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseError {
+    /// The accelerator does not specify a key.
+    #[error("the accelerator has no key")]
+    MissingKey,
+    /// The accelerator contains an unknown modifier or key name.
+    #[error("unknown modifier or key: {0}")]
+    Unknown(String),
+}
+
+impl FromStr for Accelerator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        // A trailing `+` that follows another `+` (or nothing) is the key.
+        let (prefix, key) = match s.strip_suffix('+') {
+            Some(prefix) if prefix.is_empty() || prefix.ends_with('+') => {
+                (prefix.strip_suffix('+').unwrap_or(prefix), "+")
+            }
+            _ => s.rsplit_once('+').unwrap_or(("", s)),
+        };
+
+        let mut modifiers = keyboard::Modifiers::empty();
+
+        for name in prefix.split('+').filter(|name| !name.trim().is_empty()) {
+            modifiers |= match name.trim().to_lowercase().as_str() {
+                "ctrl" | "control" => keyboard::Modifiers::CTRL,
+                "shift" => keyboard::Modifiers::SHIFT,
+                "alt" | "option" => keyboard::Modifiers::ALT,
+                "logo" | "super" | "cmd" | "meta" => keyboard::Modifiers::LOGO,
+                "command" => keyboard::Modifiers::COMMAND,
+                _ => return Err(ParseError::Unknown(name.trim().to_owned())),
+            };
+        }
+
+        let key = key.trim();
+
+        let key = if key.is_empty() {
+            return Err(ParseError::MissingKey);
+        } else if key.chars().count() == 1 {
+            keyboard::Key::Character(key.to_lowercase().into())
+        } else {
+            NAMED_KEYS
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(key))
+                .map(|(_, named)| keyboard::Key::Named(*named))
+                .ok_or_else(|| ParseError::Unknown(key.to_owned()))?
+        };
+
+        Ok(Self { key, modifiers })
+    }
+}
+
+impl fmt::Display for Accelerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (keyboard::Modifiers::CTRL, "Ctrl"),
+            (keyboard::Modifiers::ALT, "Alt"),
+            (keyboard::Modifiers::SHIFT, "Shift"),
+            (
+                keyboard::Modifiers::LOGO,
+                if cfg!(target_os = "macos") {
+                    "Cmd"
+                } else {
+                    "Super"
+                },
+            ),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        match self.key.as_ref() {
+            keyboard::Key::Character(c) => write!(f, "{}", c.to_uppercase()),
+            keyboard::Key::Named(named) => {
+                match NAMED_KEYS.iter().find(|(_, key)| *key == named) {
+                    Some((name, _)) => write!(f, "{name}"),
+                    None => write!(f, "{named:?}"),
+                }
+            }
+            keyboard::Key::Unidentified => write!(f, "?"),
+        }
+    }
+}
+
+/// The local state of a cascading menu.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct State {
+    is_open: bool,
+    path: Vec<usize>,
+}
+
+impl State {
+    /// Creates a new closed [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the menu is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Opens the menu, with no item highlighted.
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.path.clear();
+    }
+
+    /// Opens the menu, with its first enabled item highlighted.
+    pub fn open_first<Message>(&mut self, items: &[Item<Message>]) {
+        self.open();
+        self.path.extend(next_enabled(items, None, 1));
+    }
+
+    /// Closes the menu.
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.path.clear();
+    }
+}
+
+/// A cascading menu of [`Item`]s, navigable with both the mouse and the
+/// keyboard.
+#[allow(missing_debug_implementations)]
+pub struct Cascade<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    state: &'a mut State,
+    items: &'a [Item<Message>],
+    width: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
+    style: Theme::Style,
+}
+
+/// The height of an [`Item::Separator`].
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+impl<'a, Message, Theme, Renderer> Cascade<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    /// Creates a new [`Cascade`] with the given [`State`] and items.
+    pub fn new(state: &'a mut State, items: &'a [Item<Message>]) -> Self {
+        Self {
+            state,
+            items,
+            width: 0.0,
+            padding: Padding::from([5, 10]),
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::Basic,
+            font: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the minimum width of each menu of the [`Cascade`].
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the items of the [`Cascade`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Cascade`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the [`Cascade`].
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the [`Cascade`].
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the font of the [`Cascade`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Cascade`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Turns the [`Cascade`] into an overlay [`Element`] at the given target
+    /// position.
+    ///
+    /// The root menu is displayed under the target, or on top of it if there
+    /// is not enough space below.
+    ///
+    /// [`Element`]: overlay::Element
+    pub fn overlay(
+        self,
+        position: Point,
+        target_height: f32,
+    ) -> overlay::Element<'a, Message, Theme, Renderer> {
+        overlay::Element::new(
+            position,
+            Box::new(Overlay {
+                cascade: self,
+                target_height,
+            }),
+        )
+    }
+
+    /// Returns the items of every open menu, starting from the root.
+    fn menus(&self) -> Vec<&'a [Item<Message>]> {
+        let mut menus = vec![self.items];
+
+        for (level, index) in self.state.path.iter().enumerate() {
+            match menus[level].get(*index) {
+                Some(Item::Submenu { items, .. }) => menus.push(items),
+                _ => break,
+            }
+        }
+
+        menus
+    }
+
+    fn item_height(&self, renderer: &Renderer) -> f32 {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        f32::from(self.text_line_height.to_absolute(text_size))
+            + self.padding.vertical()
+    }
+
+    fn text<'b>(
+        &self,
+        renderer: &Renderer,
+        content: &'b str,
+    ) -> Text<'b, Renderer::Font> {
+        Text {
+            content,
+            bounds: Size::INFINITY,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.text_line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: self.text_shaping,
+            wrapping: text::Wrapping::default(),
+            letter_spacing: Pixels(0.0),
+            ellipsis: text::Ellipsis::None,
+            max_lines: None,
+        }
+    }
+
+    fn layout_menu(
+        &self,
+        renderer: &Renderer,
+        items: &'a [Item<Message>],
+    ) -> layout::Node {
+        let item_height = self.item_height(renderer);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let width = items
+            .iter()
+            .map(|item| {
+                let measure = |content: &str| {
+                    Renderer::Paragraph::with_text(self.text(renderer, content))
+                        .min_width()
+                };
+
+                match item {
+                    Item::Action {
+                        label, shortcut, ..
+                    } => {
+                        measure(label)
+                            + shortcut.as_deref().map_or(0.0, |shortcut| {
+                                text_size.0 * 2.0 + measure(shortcut)
+                            })
+                    }
+                    Item::Submenu { label, .. } => {
+                        measure(label) + text_size.0 * 2.0
+                    }
+                    Item::Separator => 0.0,
+                }
+            })
+            .fold(self.width - self.padding.horizontal(), f32::max)
+            + self.padding.horizontal();
+
+        let mut y = 0.0;
+
+        let nodes = items
+            .iter()
+            .map(|item| {
+                let height = match item {
+                    Item::Separator => SEPARATOR_HEIGHT,
+                    _ => item_height,
+                };
+
+                let node = layout::Node::new(Size::new(width, height))
+                    .move_to(Point::new(0.0, y));
+
+                y += height;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(Size::new(width, y), nodes)
+    }
+
+    /// Returns the level and index of the item under the cursor, if any.
+    fn hovered_item(
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<(usize, Option<usize>)> {
+        layout
+            .children()
+            .enumerate()
+            .filter(|(_, menu)| cursor.is_over(menu.bounds()))
+            .last()
+            .map(|(level, menu)| {
+                (
+                    level,
+                    menu.children()
+                        .position(|item| cursor.is_over(item.bounds())),
+                )
+            })
+    }
+
+    /// Chooses the item at the end of the current path.
+    fn choose(&mut self, shell: &mut Shell<'_, Message>) {
+        let menus = self.menus();
+        let level = self.state.path.len().saturating_sub(1);
+
+        let Some(item) = self
+            .state
+            .path
+            .last()
+            .and_then(|index| menus[level].get(*index))
+        else {
+            return;
+        };
+
+        match item {
+            Item::Action {
+                on_press: Some(on_press),
+                ..
+            } => {
+                shell.publish(on_press.clone());
+                self.state.close();
+            }
+            Item::Submenu { items, .. } => {
+                if let Some(first) = next_enabled(items, None, 1) {
+                    self.state.path.push(first);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the index of the next enabled item after `current` in the given
+/// direction, wrapping around.
+fn next_enabled<Message>(
+    items: &[Item<Message>],
+    current: Option<usize>,
+    direction: isize,
+) -> Option<usize> {
+    let count = items.len() as isize;
+
+    let start = match current {
+        Some(index) => index as isize,
+        None if direction > 0 => -1,
+        None => count,
+    };
+
+    (1..=count)
+        .map(|step| (start + step * direction).rem_euclid(count) as usize)
+        .find(|index| items[*index].is_enabled())
+}
+
+struct Overlay<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    cascade: Cascade<'a, Message, Theme, Renderer>,
+    target_height: f32,
+}
+
+impl<'a, Message, Theme, Renderer>
+    crate::core::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+        _translation: Vector,
+    ) -> layout::Node {
+        let cascade = &self.cascade;
+        let menus = cascade.menus();
+        let mut nodes: Vec<layout::Node> = Vec::with_capacity(menus.len());
+
+        for (level, items) in menus.into_iter().enumerate() {
+            let node = cascade.layout_menu(renderer, items);
+            let size = node.size();
+
+            let (x, y) = if let Some(parent) = nodes.last() {
+                let parent_bounds = parent.bounds();
+                let item = &parent.children()[cascade.state.path[level - 1]];
+
+                let x = if parent_bounds.x + parent_bounds.width + size.width
+                    > bounds.width
+                {
+                    parent_bounds.x - size.width
+                } else {
+                    parent_bounds.x + parent_bounds.width
+                };
+
+                (x, parent_bounds.y + item.bounds().y - cascade.padding.top)
+            } else {
+                let space_below =
+                    bounds.height - (position.y + self.target_height);
+
+                let y = if size.height <= space_below
+                    || space_below >= position.y
+                {
+                    position.y + self.target_height
+                } else {
+                    position.y - size.height
+                };
+
+                (position.x, y)
+            };
+
+            let x = x.min(bounds.width - size.width).max(0.0);
+            let y = y.min(bounds.height - size.height).max(0.0);
+
+            nodes.push(node.move_to(Point::new(x, y)));
+        }
+
+        layout::Node::with_children(bounds, nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let cascade = &mut self.cascade;
+        let hovered =
+            Cascade::<Message, Theme, Renderer>::hovered_item(layout, cursor);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some((level, index)) = hovered {
+                    let menus = cascade.menus();
+
+                    cascade.state.path.truncate(level);
+
+                    if let Some(index) =
+                        index.filter(|index| menus[level][*index].is_enabled())
+                    {
+                        cascade.state.path.push(index);
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some((level, index)) = hovered else {
+                    cascade.state.close();
+
+                    return event::Status::Ignored;
+                };
+
+                if let Some(index) = index {
+                    let menus = cascade.menus();
+
+                    if menus[level][index].is_enabled() {
+                        cascade.state.path.truncate(level);
+                        cascade.state.path.push(index);
+                        cascade.choose(shell);
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(named),
+                ..
+            }) => {
+                let menus = cascade.menus();
+                let path = &mut cascade.state.path;
+                let level = path.len().saturating_sub(1);
+
+                match named {
+                    key::Named::ArrowDown | key::Named::ArrowUp => {
+                        let direction = if named == key::Named::ArrowDown {
+                            1
+                        } else {
+                            -1
+                        };
+
+                        if let Some(next) = next_enabled(
+                            menus[level],
+                            path.get(level).copied(),
+                            direction,
+                        ) {
+                            path.truncate(level);
+                            path.push(next);
+                        }
+                    }
+                    key::Named::ArrowRight => {
+                        if !matches!(
+                            path.last().and_then(|i| menus[level].get(*i)),
+                            Some(Item::Submenu { .. })
+                        ) {
+                            return event::Status::Ignored;
+                        }
+
+                        cascade.choose(shell);
+                    }
+                    key::Named::ArrowLeft => {
+                        if path.len() <= 1 {
+                            return event::Status::Ignored;
+                        }
+
+                        let _ = path.pop();
+                    }
+                    key::Named::Enter | key::Named::Space => {
+                        cascade.choose(shell);
+                    }
+                    key::Named::Escape => {
+                        if path.len() > 1 {
+                            let _ = path.pop();
+                        } else {
+                            cascade.state.close();
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        if hovered.is_some() {
+            event::Status::Captured
+        } else {
+            event::Status::Ignored
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let menus = self.cascade.menus();
+
+        match Cascade::<Message, Theme, Renderer>::hovered_item(layout, cursor)
+        {
+            Some((level, Some(index))) if menus[level][index].is_enabled() => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout
+            .children()
+            .any(|menu| menu.bounds().contains(cursor_position))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let cascade = &self.cascade;
+        let appearance = theme.appearance(&cascade.style);
+
+        for (level, (items, menu)) in cascade
+            .menus()
+            .into_iter()
+            .zip(layout.children())
+            .enumerate()
+        {
+            let bounds = menu.bounds();
+
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: appearance.border,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.background,
+                );
+
+                for (i, (item, layout)) in
+                    items.iter().zip(menu.children()).enumerate()
+                {
+                    let bounds = layout.bounds();
+
+                    let (label, hint) = match item {
+                        Item::Action {
+                            label, shortcut, ..
+                        } => (label, shortcut.as_deref()),
+                        Item::Submenu { label, .. } => (label, Some("›")),
+                        Item::Separator => {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        x: bounds.x + cascade.padding.left,
+                                        y: bounds.center_y().floor(),
+                                        width: bounds.width
+                                            - cascade.padding.horizontal(),
+                                        height: 1.0,
+                                    },
+                                    ..renderer::Quad::default()
+                                },
+                                appearance.border.color,
+                            );
+
+                            continue;
+                        }
+                    };
+
+                    let is_highlighted =
+                        cascade.state.path.get(level) == Some(&i);
+
+                    if is_highlighted {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x + appearance.border.width,
+                                    width: bounds.width
+                                        - appearance.border.width * 2.0,
+                                    ..bounds
+                                },
+                                border: Border::with_radius(
+                                    appearance.border.radius,
+                                ),
+                                ..renderer::Quad::default()
+                            },
+                            appearance.selected_background,
+                        );
+                    }
+
+                    let color = if !item.is_enabled() {
+                        Color {
+                            a: appearance.text_color.a * 0.5,
+                            ..appearance.text_color
+                        }
+                    } else if is_highlighted {
+                        appearance.selected_text_color
+                    } else {
+                        appearance.text_color
+                    };
+
+                    renderer.fill_text(
+                        Text {
+                            bounds: Size::new(bounds.width, bounds.height),
+                            ..cascade.text(renderer, label)
+                        },
+                        Point::new(
+                            bounds.x + cascade.padding.left,
+                            bounds.center_y(),
+                        ),
+                        color,
+                        bounds,
+                    );
+
+                    if let Some(hint) = hint {
+                        renderer.fill_text(
+                            Text {
+                                bounds: Size::new(bounds.width, bounds.height),
+                                horizontal_alignment:
+                                    alignment::Horizontal::Right,
+                                ..cascade.text(renderer, hint)
+                            },
+                            Point::new(
+                                bounds.x + bounds.width - cascade.padding.right,
+                                bounds.center_y(),
+                            ),
+                            Color {
+                                a: color.a * 0.7,
+                                ..color
+                            },
+                            bounds,
+                        );
+                    }
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accelerators_round_trip() {
+        let save: Accelerator = "ctrl+shift+s".parse().unwrap();
+
+        assert_eq!(
+            save.modifiers,
+            keyboard::Modifiers::CTRL | keyboard::Modifiers::SHIFT
+        );
+        assert_eq!(save.to_string(), "Ctrl+Shift+S");
+        assert!(save.matches(
+            &keyboard::Key::Character("S".into()),
+            keyboard::Modifiers::CTRL | keyboard::Modifiers::SHIFT
+        ));
+        assert!(!save.matches(
+            &keyboard::Key::Character("s".into()),
+            keyboard::Modifiers::CTRL
+        ));
+
+        let zoom: Accelerator = "Ctrl++".parse().unwrap();
+
+        assert_eq!(zoom.key, keyboard::Key::Character("+".into()));
+        assert!(zoom.matches(
+            &keyboard::Key::Character("+".into()),
+            keyboard::Modifiers::CTRL | keyboard::Modifiers::SHIFT
+        ));
+        assert_eq!(
+            "Command+Q".parse::<Accelerator>().unwrap().to_string(),
+            if cfg!(target_os = "macos") {
+                "Cmd+Q"
+            } else {
+                "Ctrl+Q"
+            }
+        );
+        assert_eq!(
+            "Alt+F4".parse::<Accelerator>().unwrap().to_string(),
+            "Alt+F4"
+        );
+        assert_eq!(
+            "Hyper+X".parse::<Accelerator>(),
+            Err(ParseError::Unknown("Hyper".to_owned()))
+        );
+        assert_eq!("Ctrl+".parse::<Accelerator>(), Err(ParseError::MissingKey));
+    }
+}
@@ -535,6 +535,10 @@ where
                     horizontal_alignment: alignment::Horizontal::Left,
                     vertical_alignment: alignment::Vertical::Center,
                     shaping: self.text_shaping,
+                    wrapping: text::Wrapping::default(),
+                    letter_spacing: Pixels(0.0),
+                    ellipsis: text::Ellipsis::None,
+                    max_lines: None,
                 },
                 Point::new(bounds.x + self.padding.left, bounds.center_y()),
                 if is_selected {
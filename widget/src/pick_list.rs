@@ -9,7 +9,9 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::text::{self, Paragraph as _, Text};
 use crate::core::touch;
+use crate::core::widget::operation::accessible::{self, Accessible};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
     Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
     Shell, Size, Widget,
@@ -196,6 +198,27 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let selected = self.selected.as_ref().map(T::to_string);
+        let mut accessible = Accessible::new(accessible::Role::ComboBox);
+
+        if let Some(placeholder) = &self.placeholder {
+            accessible = accessible.label(placeholder);
+        }
+
+        if let Some(selected) = &selected {
+            accessible = accessible.value(accessible::Value::Text(selected));
+        }
+
+        operation.accessible(None, layout.bounds(), accessible);
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -414,6 +437,10 @@ where
         horizontal_alignment: alignment::Horizontal::Left,
         vertical_alignment: alignment::Vertical::Center,
         shaping: text_shaping,
+        wrapping: text::Wrapping::default(),
+        letter_spacing: Pixels(0.0),
+        ellipsis: text::Ellipsis::None,
+        max_lines: None,
     };
 
     for (option, paragraph) in options.iter().zip(state.options.iter_mut()) {
@@ -717,6 +744,10 @@ pub fn draw<'a, T, Theme, Renderer>(
                 horizontal_alignment: alignment::Horizontal::Right,
                 vertical_alignment: alignment::Vertical::Center,
                 shaping,
+                wrapping: text::Wrapping::default(),
+                letter_spacing: Pixels(0.0),
+                ellipsis: text::Ellipsis::None,
+                max_lines: None,
             },
             Point::new(
                 bounds.x + bounds.width - padding.horizontal(),
@@ -745,6 +776,10 @@ pub fn draw<'a, T, Theme, Renderer>(
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Center,
                 shaping: text_shaping,
+                wrapping: text::Wrapping::default(),
+                letter_spacing: Pixels(0.0),
+                ellipsis: text::Ellipsis::None,
+                max_lines: None,
             },
             Point::new(bounds.x + padding.left, bounds.center_y()),
             if is_selected {
@@ -2,6 +2,8 @@
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
+use crate::core::widget::operation::accessible::{self, Accessible};
+use crate::core::widget::Operation;
 use crate::core::widget::Tree;
 use crate::core::{Border, Element, Layout, Length, Rectangle, Size, Widget};
 
@@ -100,6 +102,26 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.accessible(
+            None,
+            layout.bounds(),
+            Accessible::new(accessible::Role::ProgressBar).value(
+                accessible::Value::Numeric {
+                    value: f64::from(self.value),
+                    min: f64::from(*self.range.start()),
+                    max: f64::from(*self.range.end()),
+                },
+            ),
+        );
+    }
+
     fn draw(
         &self,
         _state: &Tree,
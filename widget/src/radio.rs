@@ -7,7 +7,9 @@ use crate::core::renderer;
 use crate::core::text;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::accessible::{self, Accessible};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
     Border, Clipboard, Element, Layout, Length, Pixels, Rectangle, Shell, Size,
     Widget,
@@ -234,11 +236,33 @@ where
                     alignment::Horizontal::Left,
                     alignment::Vertical::Top,
                     self.text_shaping,
+                    text::Wrapping::default(),
+                    Pixels(0.0),
+                    text::Ellipsis::None,
+                    None,
                 )
             },
         )
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.accessible(
+            None,
+            layout.bounds(),
+            Accessible::new(accessible::Role::RadioButton)
+                .label(&self.label)
+                .value(accessible::Value::Checked(self.is_selected)),
+        );
+
+        operation.activatable(None, &|| self.on_click.clone());
+    }
+
     fn on_event(
         &mut self,
         _state: &mut Tree,
@@ -347,6 +371,7 @@ where
                 tree.state.downcast_ref(),
                 crate::text::Appearance {
                     color: custom_style.text_color,
+                    selection: None,
                 },
                 viewport,
             );
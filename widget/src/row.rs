@@ -78,6 +78,17 @@ where
         self
     }
 
+    /// Turns the [`Row`] into a [`Wrapping`] row.
+    ///
+    /// A [`Wrapping`] row breaks its contents onto new lines whenever they
+    /// do not fit horizontally.
+    pub fn wrap(self) -> Wrapping<'a, Message, Theme, Renderer> {
+        Wrapping {
+            row: self,
+            line_spacing: None,
+        }
+    }
+
     /// Adds an [`Element`] to the [`Row`].
     pub fn push(
         mut self,
@@ -264,3 +275,192 @@ where
         Self::new(row)
     }
 }
+
+/// A [`Row`] that wraps its contents onto new lines.
+///
+/// The contents of every line are aligned vertically using the alignment
+/// of the [`Row`].
+#[allow(missing_debug_implementations)]
+pub struct Wrapping<
+    'a,
+    Message,
+    Theme = crate::Theme,
+    Renderer = crate::Renderer,
+> {
+    row: Row<'a, Message, Theme, Renderer>,
+    line_spacing: Option<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> Wrapping<'a, Message, Theme, Renderer> {
+    /// Sets the vertical spacing _between_ lines.
+    ///
+    /// By default, it is the same as the spacing of the [`Row`].
+    pub fn line_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.line_spacing = Some(amount.into().0);
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Wrapping<'a, Message, Theme, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.row.children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.row.diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.row.size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Row {
+            spacing,
+            padding,
+            width,
+            height,
+            align_items,
+            ref children,
+        } = self.row;
+
+        let line_spacing = self.line_spacing.unwrap_or(spacing);
+
+        let limits = limits.width(width).height(height).shrink(padding);
+        let child_limits = limits.loose();
+        let max_width = limits.max().width;
+
+        let mut nodes: Vec<layout::Node> = Vec::with_capacity(children.len());
+        let mut intrinsic_size = Size::ZERO;
+
+        let mut line_start = 0;
+        let mut line_height = 0.0f32;
+        let mut x = 0.0;
+        let mut y = 0.0;
+
+        let align_line = |nodes: &mut [layout::Node], line_height: f32| {
+            for node in nodes {
+                let space = Size::new(node.size().width, line_height);
+
+                node.align_mut(Alignment::Start, align_items, space);
+            }
+        };
+
+        for (i, (child, tree)) in
+            children.iter().zip(&mut tree.children).enumerate()
+        {
+            let node = child.as_widget().layout(tree, renderer, &child_limits);
+            let size = node.size();
+
+            if i > line_start && x + size.width > max_width {
+                align_line(&mut nodes[line_start..], line_height);
+
+                intrinsic_size.width = intrinsic_size.width.max(x - spacing);
+
+                y += line_height + line_spacing;
+                x = 0.0;
+                line_start = i;
+                line_height = 0.0;
+            }
+
+            line_height = line_height.max(size.height);
+
+            nodes.push(node.move_to((x + padding.left, y + padding.top)));
+
+            x += size.width + spacing;
+        }
+
+        if !nodes.is_empty() {
+            align_line(&mut nodes[line_start..], line_height);
+
+            intrinsic_size.width = intrinsic_size.width.max(x - spacing);
+            intrinsic_size.height = y + line_height;
+        }
+
+        let size = limits.resolve(width, height, intrinsic_size);
+
+        layout::Node::with_children(size.expand(padding), nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.row.operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.row.on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.row
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.row
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.row.overlay(tree, layout, renderer)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Wrapping<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(row: Wrapping<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(row)
+    }
+}
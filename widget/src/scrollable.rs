@@ -1,5 +1,6 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::core::event::{self, Event};
+use crate::core::input_method::InputMethod;
 use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
@@ -539,8 +540,9 @@ pub fn update<Message>(
         };
 
         let translation = state.translation(direction, bounds, content_bounds);
+        let had_input_method = shell.input_method().is_enabled();
 
-        update_content(
+        let event_status = update_content(
             event.clone(),
             content,
             cursor,
@@ -551,7 +553,17 @@ pub fn update<Message>(
                 x: bounds.x + translation.x,
                 ..bounds
             },
-        )
+        );
+
+        if !had_input_method {
+            if let InputMethod::Enabled { cursor, .. } =
+                shell.input_method_mut()
+            {
+                *cursor = *cursor - translation;
+            }
+        }
+
+        event_status
     };
 
     if let event::Status::Captured = event_status {
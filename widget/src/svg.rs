@@ -1,11 +1,13 @@
 //! Display vector graphics in your application.
+use crate::core::border;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::svg;
 use crate::core::widget::Tree;
 use crate::core::{
-    ContentFit, Element, Layout, Length, Rectangle, Size, Vector, Widget,
+    Color, ContentFit, Element, Layout, Length, Radians, Rectangle, Size,
+    Vector, Widget,
 };
 
 use std::path::PathBuf;
@@ -28,6 +30,10 @@ where
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    rotation: Radians,
+    opacity: f32,
+    tint: Option<Color>,
+    border_radius: border::Radius,
     style: <Theme as StyleSheet>::Style,
 }
 
@@ -42,6 +48,10 @@ where
             width: Length::Fill,
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
+            rotation: Radians(0.0),
+            opacity: 1.0,
+            tint: None,
+            border_radius: border::Radius::default(),
             style: Default::default(),
         }
     }
@@ -78,6 +88,45 @@ where
         }
     }
 
+    /// Sets the rotation of the [`Svg`] around its center.
+    ///
+    /// The rotation does not affect the layout of the [`Svg`].
+    #[must_use]
+    pub fn rotation(mut self, rotation: impl Into<Radians>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Svg`], from `0.0` (transparent) to `1.0`
+    /// (opaque).
+    ///
+    /// Defaults to `1.0`
+    #[must_use]
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Sets the [`Color`] multiplied with the pixels of the [`Svg`].
+    ///
+    /// Unlike the color of its [`Appearance`], the tint keeps the shading of
+    /// the [`Svg`].
+    #[must_use]
+    pub fn tint(mut self, tint: impl Into<Color>) -> Self {
+        self.tint = Some(tint.into());
+        self
+    }
+
+    /// Sets the border radius of the corners of the [`Svg`].
+    #[must_use]
+    pub fn border_radius(
+        mut self,
+        border_radius: impl Into<border::Radius>,
+    ) -> Self {
+        self.border_radius = border_radius.into();
+        self
+    }
+
     /// Sets the style variant of this [`Svg`].
     #[must_use]
     pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
@@ -164,10 +213,14 @@ where
                 theme.appearance(&self.style)
             };
 
-            renderer.draw(
+            renderer.draw_svg(
                 self.handle.clone(),
                 appearance.color,
                 drawing_bounds + offset,
+                self.rotation,
+                self.opacity,
+                self.tint,
+                self.border_radius,
             );
         };
 
@@ -0,0 +1,892 @@
+//! Display tabular data with sortable, resizable columns.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Background, Clipboard, Element, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+use crate::Space;
+
+pub use crate::style::table::{Appearance, StyleSheet};
+
+/// A widget that displays rows of data in a set of columns.
+///
+/// A [`Table`] has a header row, which stays pinned to the top of the
+/// viewport when the [`Table`] is placed inside a [`Scrollable`].
+///
+/// Columns have a fixed width that can be changed by dragging the resize
+/// handles in the header. Clicking a header cell produces a sort message, and
+/// clicking a row produces a selection message. The state of the sorting,
+/// the column widths, and the selection is owned by the application.
+///
+/// [`Scrollable`]: crate::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    columns: Vec<Column<'a, Message, Theme, Renderer>>,
+    rows: Vec<Vec<Element<'a, Message, Theme, Renderer>>>,
+    width: Length,
+    padding: Padding,
+    sort: Option<(usize, Order)>,
+    selected: Vec<usize>,
+    on_sort: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// A column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+{
+    header: Element<'a, Message, Theme, Renderer>,
+    width: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Column<'a, Message, Theme, Renderer> {
+    /// The default width of a [`Column`].
+    pub const DEFAULT_WIDTH: f32 = 150.0;
+
+    /// Creates a new [`Column`] with the given header.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            width: Self::DEFAULT_WIDTH,
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0.max(MIN_COLUMN_WIDTH);
+        self
+    }
+}
+
+/// The order of a sorted [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Order {
+    /// From the smallest value to the largest one.
+    #[default]
+    Ascending,
+    /// From the largest value to the smallest one.
+    Descending,
+}
+
+impl Order {
+    /// Returns the opposite [`Order`].
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+const MIN_COLUMN_WIDTH: f32 = 24.0;
+const HANDLE_WIDTH: f32 = 8.0;
+
+impl<'a, Message, Theme, Renderer> Table<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of the cells of a [`Table`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// Creates a new [`Table`] with the given columns.
+    pub fn new(
+        columns: impl IntoIterator<Item = Column<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            rows: Vec::new(),
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            sort: None,
+            selected: Vec::new(),
+            on_sort: None,
+            on_resize: None,
+            on_select: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a row of cells to the [`Table`].
+    ///
+    /// Extra cells are ignored, and missing cells are left empty.
+    pub fn push(
+        mut self,
+        cells: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self
+    where
+        Message: 'a,
+        Theme: 'a,
+        Renderer: 'a,
+    {
+        let mut row: Vec<_> =
+            cells.into_iter().take(self.columns.len()).collect();
+
+        row.resize_with(self.columns.len(), || {
+            Space::new(Length::Shrink, Length::Shrink).into()
+        });
+
+        self.rows.push(row);
+        self
+    }
+
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the cells of the [`Table`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Shows a sort indicator in the column with the given index.
+    pub fn sorted_by(mut self, column: usize, order: Order) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Marks the row with the given index as selected.
+    pub fn selected(mut self, row: usize) -> Self {
+        self.selected.push(row);
+        self
+    }
+
+    /// Sets the message that should be produced when a header cell is
+    /// clicked.
+    ///
+    /// The closure receives the index of the column.
+    pub fn on_sort(mut self, on_sort: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the message that should be produced when the resize handle of a
+    /// column is dragged, enabling resizing.
+    ///
+    /// The closure receives the index of the column and its new width.
+    pub fn on_resize(
+        mut self,
+        on_resize: impl Fn(usize, f32) -> Message + 'a,
+    ) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the message that should be produced when a row is clicked.
+    ///
+    /// The closure receives the index of the row.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn indicator_width(&self, renderer: &Renderer) -> f32 {
+        if self.on_sort.is_some() || self.sort.is_some() {
+            renderer.default_size().0
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the bounds of each cell of the given row.
+    fn cells(&self, row: Rectangle) -> impl Iterator<Item = Rectangle> + '_ {
+        let mut x = row.x;
+
+        self.columns.iter().map(move |column| {
+            let bounds = Rectangle {
+                x,
+                width: column.width,
+                ..row
+            };
+
+            x += column.width;
+
+            bounds
+        })
+    }
+
+    /// Returns the bounds of the resize handle of each column.
+    fn handles(
+        &self,
+        header: Rectangle,
+    ) -> impl Iterator<Item = Rectangle> + '_ {
+        self.cells(header).map(|cell| Rectangle {
+            x: cell.x + cell.width - HANDLE_WIDTH / 2.0,
+            width: HANDLE_WIDTH,
+            ..cell
+        })
+    }
+
+    fn hovered_handle(
+        &self,
+        header: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        self.on_resize.as_ref().and_then(|_| {
+            self.handles(header)
+                .position(|handle| cursor.is_over(handle))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Table<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.columns
+            .iter()
+            .map(|column| &column.header)
+            .chain(self.rows.iter().flatten())
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let cells: Vec<_> = self
+            .columns
+            .iter()
+            .map(|column| &column.header)
+            .chain(self.rows.iter().flatten())
+            .collect();
+
+        tree.diff_children(&cells);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let indicator_width = self.indicator_width(renderer);
+
+        let headers = self.columns.iter().map(|column| &column.header);
+        let rows = std::iter::once(headers.collect::<Vec<_>>())
+            .chain(self.rows.iter().map(|row| row.iter().collect()));
+
+        let mut trees = tree.children.iter_mut();
+        let mut y = 0.0;
+
+        let nodes = rows
+            .enumerate()
+            .map(|(i, row)| {
+                let reserved = if i == 0 { indicator_width } else { 0.0 };
+                let mut x = 0.0;
+                let mut height = 0.0f32;
+
+                let cells = row
+                    .into_iter()
+                    .zip(&self.columns)
+                    .zip(&mut trees)
+                    .map(|((cell, column), tree)| {
+                        let limits = layout::Limits::new(
+                            Size::ZERO,
+                            Size::new(
+                                (column.width
+                                    - self.padding.horizontal()
+                                    - reserved)
+                                    .max(0.0),
+                                f32::INFINITY,
+                            ),
+                        );
+
+                        let node =
+                            cell.as_widget().layout(tree, renderer, &limits);
+
+                        let node = node.move_to(Point::new(
+                            x + self.padding.left,
+                            self.padding.top,
+                        ));
+
+                        x += column.width;
+                        height = height.max(node.size().height);
+
+                        node
+                    })
+                    .collect();
+
+                let size = Size::new(x, height + self.padding.vertical());
+                let node = layout::Node::with_children(size, cells)
+                    .move_to(Point::new(0.0, y));
+
+                y += size.height;
+
+                node
+            })
+            .collect();
+
+        let intrinsic_size =
+            Size::new(self.columns.iter().map(|column| column.width).sum(), y);
+
+        let size = limits.width(self.width).resolve(
+            self.width,
+            Length::Shrink,
+            intrinsic_size,
+        );
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let cells = self
+            .columns
+            .iter()
+            .map(|column| &column.header)
+            .chain(self.rows.iter().flatten());
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            cells
+                .clone()
+                .zip(&mut tree.children)
+                .zip(layout.children().flat_map(Layout::children))
+                .for_each(|((cell, state), layout)| {
+                    cell.as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let (header, offset) = pinned_header(layout, viewport);
+        let header_bounds = header.bounds() + offset;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some((column, position)) = self
+                    .hovered_handle(header_bounds, cursor)
+                    .zip(cursor.position())
+                {
+                    state.drag = Some(Drag {
+                        column,
+                        origin: position.x,
+                        width: self.columns[column].width,
+                    });
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let (Some(drag), Some(on_resize)) =
+                    (state.drag, &self.on_resize)
+                {
+                    let width = (drag.width + position.x - drag.origin)
+                        .max(MIN_COLUMN_WIDTH);
+
+                    shell.publish(on_resize(drag.column, width));
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let header_cursor = translate(cursor, offset);
+        let header_viewport = *viewport - offset;
+        let body_cursor = body_cursor(cursor, header_bounds);
+
+        let (header_trees, body_trees) =
+            tree.children.split_at_mut(self.columns.len());
+
+        let header_status = self
+            .columns
+            .iter_mut()
+            .zip(header_trees)
+            .zip(header.children())
+            .map(|((column, state), layout)| {
+                column.header.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    header_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &header_viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let body_status = self
+            .rows
+            .iter_mut()
+            .flatten()
+            .zip(body_trees)
+            .zip(layout.children().skip(1).flat_map(Layout::children))
+            .map(|((cell, state), layout)| {
+                cell.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    body_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        let status = header_status.merge(body_status);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            if let Some((on_sort, column)) = self.on_sort.as_ref().zip(
+                self.cells(header_bounds)
+                    .position(|cell| cursor.is_over(cell)),
+            ) {
+                shell.publish(on_sort(column));
+
+                return event::Status::Captured;
+            }
+
+            if let Some((on_select, row)) = self.on_select.as_ref().zip(
+                layout
+                    .children()
+                    .skip(1)
+                    .position(|row| body_cursor.is_over(row.bounds())),
+            ) {
+                shell.publish(on_select(row));
+
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let (header, offset) = pinned_header(layout, viewport);
+        let header_bounds = header.bounds() + offset;
+
+        if state.drag.is_some()
+            || self.hovered_handle(header_bounds, cursor).is_some()
+        {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let (header_trees, body_trees) =
+            tree.children.split_at(self.columns.len());
+
+        if cursor.is_over(header_bounds) {
+            let header_cursor = translate(cursor, offset);
+            let header_viewport = *viewport - offset;
+
+            let interaction = self
+                .columns
+                .iter()
+                .zip(header_trees)
+                .zip(header.children())
+                .map(|((column, state), layout)| {
+                    column.header.as_widget().mouse_interaction(
+                        state,
+                        layout,
+                        header_cursor,
+                        &header_viewport,
+                        renderer,
+                    )
+                })
+                .max()
+                .unwrap_or_default();
+
+            if interaction == mouse::Interaction::default()
+                && self.on_sort.is_some()
+            {
+                return mouse::Interaction::Pointer;
+            }
+
+            return interaction;
+        }
+
+        let interaction = self
+            .rows
+            .iter()
+            .flatten()
+            .zip(body_trees)
+            .zip(layout.children().skip(1).flat_map(Layout::children))
+            .map(|((cell, state), layout)| {
+                cell.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::default()
+            && self.on_select.is_some()
+            && cursor.is_over(layout.bounds())
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        interaction
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let (header, offset) = pinned_header(layout, viewport);
+        let header_bounds = header.bounds() + offset;
+        let body_cursor = body_cursor(cursor, header_bounds);
+
+        let (header_trees, body_trees) =
+            tree.children.split_at(self.columns.len());
+
+        for (i, ((row, trees), layout)) in self
+            .rows
+            .iter()
+            .zip(body_trees.chunks(self.columns.len().max(1)))
+            .zip(layout.children().skip(1))
+            .enumerate()
+        {
+            let row_bounds = layout.bounds();
+
+            if !row_bounds.intersects(&clip) {
+                continue;
+            }
+
+            let is_selected = self.selected.contains(&i);
+            let is_hovered =
+                self.on_select.is_some() && body_cursor.is_over(row_bounds);
+
+            let background = if is_selected {
+                appearance.selected_row_background
+            } else if is_hovered {
+                appearance.hovered_row_background
+            } else if i % 2 == 1 {
+                appearance
+                    .alternate_row_background
+                    .or(appearance.row_background)
+            } else {
+                appearance.row_background
+            };
+
+            fill(renderer, row_bounds, background);
+
+            if let Some(color) = appearance.divider {
+                fill(renderer, bottom_line(row_bounds), Some(color.into()));
+            }
+
+            let style = if is_selected {
+                renderer::Style {
+                    text_color: appearance.selected_text_color,
+                }
+            } else {
+                *style
+            };
+
+            for ((cell, state), layout) in
+                row.iter().zip(trees).zip(layout.children())
+            {
+                cell.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    &style,
+                    layout,
+                    body_cursor,
+                    &clip,
+                );
+            }
+        }
+
+        if let Some(color) = appearance.divider {
+            for cell in self.cells(bounds) {
+                fill(renderer, right_line(cell), Some(color.into()));
+            }
+        }
+
+        let active_handle = state
+            .drag
+            .map(|drag| drag.column)
+            .or_else(|| self.hovered_handle(header_bounds, cursor));
+
+        let header_cursor = translate(cursor, offset);
+        let header_viewport = clip - offset;
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.with_translation(offset, |renderer| {
+                let header_bounds = header.bounds();
+
+                fill(renderer, header_bounds, appearance.header_background);
+
+                if let Some(color) = appearance.divider {
+                    fill(
+                        renderer,
+                        bottom_line(header_bounds),
+                        Some(color.into()),
+                    );
+
+                    for cell in self.cells(header_bounds) {
+                        fill(renderer, right_line(cell), Some(color.into()));
+                    }
+                }
+
+                let style = renderer::Style {
+                    text_color: appearance.header_text_color,
+                };
+
+                for ((column, state), layout) in
+                    self.columns.iter().zip(header_trees).zip(header.children())
+                {
+                    column.header.as_widget().draw(
+                        state,
+                        renderer,
+                        theme,
+                        &style,
+                        layout,
+                        header_cursor,
+                        &header_viewport,
+                    );
+                }
+
+                if let Some((cell, order)) = self.sort.and_then(|(i, order)| {
+                    Some((self.cells(header_bounds).nth(i)?, order))
+                }) {
+                    let size = renderer.default_size();
+
+                    renderer.fill_text(
+                        Text {
+                            content: match order {
+                                Order::Ascending => "▲",
+                                Order::Descending => "▼",
+                            },
+                            size: Pixels(size.0 * 0.7),
+                            line_height: text::LineHeight::default(),
+                            font: renderer.default_font(),
+                            bounds: Size::new(size.0, cell.height),
+                            horizontal_alignment: alignment::Horizontal::Right,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: text::Shaping::Advanced,
+                            wrapping: text::Wrapping::default(),
+                            letter_spacing: Pixels(0.0),
+                            ellipsis: text::Ellipsis::None,
+                            max_lines: None,
+                        },
+                        Point::new(
+                            cell.x + cell.width - self.padding.right,
+                            cell.center_y(),
+                        ),
+                        appearance.sort_indicator,
+                        header_viewport,
+                    );
+                }
+
+                if let Some(handle) = active_handle
+                    .and_then(|i| self.handles(header_bounds).nth(i))
+                {
+                    fill(
+                        renderer,
+                        Rectangle {
+                            x: handle.center_x() - 1.0,
+                            width: 2.0,
+                            ..handle
+                        },
+                        Some(appearance.resize_handle.into()),
+                    );
+                }
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Table<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(table: Table<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(table)
+    }
+}
+
+/// Returns the [`Layout`] of the header row, together with the translation
+/// that keeps it pinned to the top of the viewport while the rest of the
+/// [`Table`] is visible.
+fn pinned_header<'a>(
+    layout: Layout<'a>,
+    viewport: &Rectangle,
+) -> (Layout<'a>, Vector) {
+    let bounds = layout.bounds();
+
+    let header = layout
+        .children()
+        .next()
+        .expect("Table layout should have a header row");
+
+    let offset = (viewport.y - bounds.y)
+        .min(bounds.height - header.bounds().height)
+        .max(0.0);
+
+    (header, Vector::new(0.0, offset))
+}
+
+fn translate(cursor: mouse::Cursor, offset: Vector) -> mouse::Cursor {
+    match cursor {
+        mouse::Cursor::Available(position) => {
+            mouse::Cursor::Available(position - offset)
+        }
+        mouse::Cursor::Unavailable => mouse::Cursor::Unavailable,
+    }
+}
+
+/// Rows hidden behind the pinned header must not receive the cursor.
+fn body_cursor(cursor: mouse::Cursor, header: Rectangle) -> mouse::Cursor {
+    if cursor.is_over(header) {
+        mouse::Cursor::Unavailable
+    } else {
+        cursor
+    }
+}
+
+fn bottom_line(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        y: bounds.y + bounds.height - 1.0,
+        height: 1.0,
+        ..bounds
+    }
+}
+
+fn right_line(bounds: Rectangle) -> Rectangle {
+    Rectangle {
+        x: bounds.x + bounds.width - 1.0,
+        width: 1.0,
+        ..bounds
+    }
+}
+
+fn fill<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    background: Option<Background>,
+) where
+    Renderer: crate::core::Renderer,
+{
+    if let Some(background) = background {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            background,
+        );
+    }
+}
@@ -0,0 +1,816 @@
+//! Switch between different views with a bar of tabs.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+pub use crate::style::tabs::{Appearance, StyleSheet};
+
+/// A container with a bar of tabs on top of its content.
+///
+/// Only the content of the selected tab is part of a [`Tabs`] widget; the
+/// application decides what to show when a different tab is selected.
+///
+/// Tabs can show an icon and a close button, be reordered by dragging them
+/// along the bar, and be scrolled with the mouse wheel when they overflow.
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    tabs: Vec<Tab>,
+    selected: usize,
+    content: Element<'a, Message, Theme, Renderer>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    text_shaping: text::Shaping,
+    font: Option<Renderer::Font>,
+    icon_font: Option<Renderer::Font>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    style: <Theme as StyleSheet>::Style,
+}
+
+/// A tab of a [`Tabs`] widget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab {
+    label: String,
+    icon: Option<char>,
+}
+
+impl Tab {
+    /// Creates a new [`Tab`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+        }
+    }
+
+    /// Sets the icon of the [`Tab`], drawn with the icon font of its
+    /// [`Tabs`].
+    pub fn icon(mut self, code_point: char) -> Self {
+        self.icon = Some(code_point);
+        self
+    }
+}
+
+impl<T> From<T> for Tab
+where
+    T: Into<String>,
+{
+    fn from(label: T) -> Self {
+        Self::new(label)
+    }
+}
+
+/// The distance the cursor needs to travel before a tab starts being dragged.
+const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
+
+impl<'a, Message, Theme, Renderer> Tabs<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    /// The default [`Padding`] of a tab.
+    pub const DEFAULT_PADDING: Padding = Padding::new(8.0);
+
+    /// Creates a new [`Tabs`] widget with the index of the selected tab and
+    /// its content.
+    pub fn new(
+        selected: usize,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let content = content.into();
+        let size = content.as_widget().size_hint();
+
+        Self {
+            tabs: Vec::new(),
+            selected,
+            content,
+            width: size.width.fluid(),
+            height: size.height.fluid(),
+            padding: Self::DEFAULT_PADDING,
+            spacing: 8.0,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            text_shaping: text::Shaping::Basic,
+            font: None,
+            icon_font: None,
+            on_select: None,
+            on_close: None,
+            on_reorder: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Tab`] to the bar of the [`Tabs`].
+    pub fn push(mut self, tab: impl Into<Tab>) -> Self {
+        self.tabs.push(tab.into());
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each tab.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the icon, the label, and the close button of
+    /// each tab.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the text size of the tabs.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the text [`text::LineHeight`] of the tabs.
+    pub fn text_line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`text::Shaping`] strategy of the tabs.
+    pub fn text_shaping(mut self, shaping: text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the font of the labels of the tabs.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the font used to draw the icons of the tabs.
+    pub fn icon_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.icon_font = Some(font.into());
+        self
+    }
+
+    /// Sets the message that should be produced when a tab is clicked.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message that should be produced when the close button of a
+    /// tab is clicked, showing a close button in every tab.
+    pub fn on_close(
+        mut self,
+        on_close: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the message that should be produced when a tab is dragged to a
+    /// new position, enabling reordering.
+    ///
+    /// The closure receives the current index of the tab and the index it
+    /// should have after being moved.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn close_button(&self, tab: Rectangle, text_size: f32) -> Rectangle {
+        Rectangle {
+            x: tab.x + tab.width - self.padding.right - text_size,
+            width: text_size,
+            ..tab
+        }
+    }
+}
+
+/// The local state of a [`Tabs`] widget.
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    labels: Vec<P>,
+    scroll: f32,
+    drag: Option<Drag>,
+}
+
+impl<P: text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self {
+            labels: Vec::new(),
+            scroll: 0.0,
+            drag: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: Point,
+    is_dragging: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tabs<'a, Message, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        state.labels.resize_with(self.tabs.len(), Default::default);
+
+        for (tab, paragraph) in self.tabs.iter().zip(&mut state.labels) {
+            paragraph.update(Text {
+                content: &tab.label,
+                size: text_size,
+                line_height: self.text_line_height,
+                font,
+                bounds: Size::INFINITY,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: self.text_shaping,
+                wrapping: text::Wrapping::default(),
+                letter_spacing: Pixels(0.0),
+                ellipsis: text::Ellipsis::None,
+                max_lines: None,
+            });
+        }
+
+        let bar_height =
+            f32::from(self.text_line_height.to_absolute(text_size))
+                + self.padding.vertical();
+
+        let mut x = 0.0;
+
+        let tabs = self
+            .tabs
+            .iter()
+            .zip(&state.labels)
+            .map(|(tab, label)| {
+                let icon_width = if tab.icon.is_some() {
+                    text_size.0 + self.spacing
+                } else {
+                    0.0
+                };
+
+                let close_width = if self.on_close.is_some() {
+                    self.spacing + text_size.0
+                } else {
+                    0.0
+                };
+
+                let width = self.padding.horizontal()
+                    + icon_width
+                    + label.min_width()
+                    + close_width;
+
+                let node = layout::Node::new(Size::new(width, bar_height))
+                    .move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let limits = limits.width(self.width).height(self.height);
+
+        let content = self
+            .content
+            .as_widget()
+            .layout(
+                &mut tree.children[0],
+                renderer,
+                &limits.shrink(Size::new(0.0, bar_height)),
+            )
+            .move_to(Point::new(0.0, bar_height));
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(content.size().width, content.size().height + bar_height),
+        );
+
+        // Tabs may have been closed or the bar widened since the last scroll
+        state.scroll = state.scroll.min((x - size.width).max(0.0));
+
+        let bar = layout::Node::with_children(
+            Size::new(size.width, bar_height),
+            tabs,
+        );
+
+        layout::Node::with_children(size, vec![bar, content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().nth(1).unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let bar_bounds = bar.bounds();
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size()).0;
+
+        let hovered_tab = if cursor.is_over(bar_bounds) {
+            tab_bounds(bar, state.scroll).position(|tab| cursor.is_over(tab))
+        } else {
+            None
+        };
+
+        match (event.clone(), hovered_tab) {
+            (Event::Mouse(mouse::Event::WheelScrolled { delta }), _)
+                if cursor.is_over(bar_bounds) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        (if x == 0.0 { y } else { x }) * 60.0
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x == 0.0 {
+                            y
+                        } else {
+                            x
+                        }
+                    }
+                };
+
+                state.scroll =
+                    (state.scroll - delta).min(max_scroll(bar)).max(0.0);
+
+                return event::Status::Captured;
+            }
+            (
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. }),
+                Some(index),
+            ) => {
+                let tab = tab_bounds(bar, state.scroll).nth(index).unwrap();
+
+                if let Some(on_close) = &self.on_close {
+                    if cursor.is_over(self.close_button(tab, text_size)) {
+                        shell.publish(on_close(index));
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if let Some(origin) = cursor.position() {
+                    state.drag = Some(Drag {
+                        index,
+                        origin,
+                        is_dragging: false,
+                    });
+                }
+
+                return event::Status::Captured;
+            }
+            (
+                Event::Mouse(mouse::Event::CursorMoved { position })
+                | Event::Touch(touch::Event::FingerMoved { position, .. }),
+                _,
+            ) if state.drag.is_some() => {
+                if let Some(drag) = &mut state.drag {
+                    if !drag.is_dragging
+                        && self.on_reorder.is_some()
+                        && drag.origin.distance(position)
+                            > DRAG_DEADBAND_DISTANCE
+                    {
+                        drag.is_dragging = true;
+                    }
+
+                    if drag.is_dragging {
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            (
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. }),
+                _,
+            ) if state.drag.is_some() => {
+                let drag = state.drag.take().unwrap();
+
+                if !drag.is_dragging {
+                    // A tab is only selected by a click, not by a drag
+                    if let (Some(on_select), true) =
+                        (&self.on_select, hovered_tab == Some(drag.index))
+                    {
+                        shell.publish(on_select(drag.index));
+
+                        return event::Status::Captured;
+                    }
+                } else if let (Some(on_reorder), Some(position)) =
+                    (&self.on_reorder, cursor.position())
+                {
+                    let target =
+                        drop_target(bar, state.scroll, drag.index, position.x);
+
+                    if target != drag.index {
+                        shell.publish(on_reorder(drag.index, target));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            (Event::Touch(touch::Event::FingerLost { .. }), _)
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            content,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if cursor.is_over(bar.bounds()) {
+            let is_over_tab =
+                tab_bounds(bar, state.scroll).any(|tab| cursor.is_over(tab));
+
+            return if is_over_tab {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            };
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        let bar_bounds = bar.bounds();
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let icon_font =
+            self.icon_font.unwrap_or_else(|| renderer.default_font());
+
+        if let Some(background) = theme.bar(&self.style) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bar_bounds,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        if let Some(clip) = bar_bounds.intersection(viewport) {
+            renderer.with_layer(clip, |renderer| {
+                for (i, ((tab, label), bounds)) in self
+                    .tabs
+                    .iter()
+                    .zip(&state.labels)
+                    .zip(tab_bounds(bar, state.scroll))
+                    .enumerate()
+                {
+                    if !bounds.intersects(&clip) {
+                        continue;
+                    }
+
+                    let is_selected = i == self.selected;
+                    let is_dragged = state.drag.is_some_and(|drag| {
+                        drag.is_dragging && drag.index == i
+                    });
+
+                    let appearance = if is_dragged || cursor.is_over(bounds) {
+                        theme.hovered(&self.style, is_selected)
+                    } else {
+                        theme.active(&self.style, is_selected)
+                    };
+
+                    if let Some(background) = appearance.background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds,
+                                border: appearance.border,
+                                ..renderer::Quad::default()
+                            },
+                            background,
+                        );
+                    }
+
+                    let mut x = bounds.x + self.padding.left;
+
+                    if let Some(icon) = tab.icon {
+                        renderer.fill_text(
+                            Text {
+                                content: &icon.to_string(),
+                                size: text_size,
+                                line_height: self.text_line_height,
+                                font: icon_font,
+                                bounds: Size::new(text_size.0, bounds.height),
+                                horizontal_alignment:
+                                    alignment::Horizontal::Left,
+                                vertical_alignment: alignment::Vertical::Center,
+                                shaping: text::Shaping::Advanced,
+                                wrapping: text::Wrapping::default(),
+                                letter_spacing: Pixels(0.0),
+                                ellipsis: text::Ellipsis::None,
+                                max_lines: None,
+                            },
+                            Point::new(x, bounds.center_y()),
+                            appearance.text_color,
+                            clip,
+                        );
+
+                        x += text_size.0 + self.spacing;
+                    }
+
+                    renderer.fill_paragraph(
+                        label,
+                        Point::new(x, bounds.center_y()),
+                        appearance.text_color,
+                        clip,
+                    );
+
+                    if self.on_close.is_some() {
+                        let close = self.close_button(bounds, text_size.0);
+
+                        renderer.fill_text(
+                            Text {
+                                content: "×",
+                                size: text_size,
+                                line_height: self.text_line_height,
+                                font: renderer.default_font(),
+                                bounds: close.size(),
+                                horizontal_alignment:
+                                    alignment::Horizontal::Center,
+                                vertical_alignment: alignment::Vertical::Center,
+                                shaping: text::Shaping::Advanced,
+                                wrapping: text::Wrapping::default(),
+                                letter_spacing: Pixels(0.0),
+                                ellipsis: text::Ellipsis::None,
+                                max_lines: None,
+                            },
+                            close.center(),
+                            appearance.close_color,
+                            clip,
+                        );
+                    }
+                }
+
+                if let Some((drag, position)) = state
+                    .drag
+                    .filter(|drag| drag.is_dragging)
+                    .zip(cursor.position())
+                {
+                    let target =
+                        drop_target(bar, state.scroll, drag.index, position.x);
+
+                    // The dragged tab is inserted before the tab at this index
+                    let before = if target > drag.index {
+                        target + 1
+                    } else {
+                        target
+                    };
+
+                    let x = tab_bounds(bar, state.scroll)
+                        .nth(before)
+                        .map(|tab| tab.x)
+                        .or_else(|| {
+                            tab_bounds(bar, state.scroll)
+                                .last()
+                                .map(|tab| tab.x + tab.width)
+                        })
+                        .unwrap_or(bar_bounds.x);
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: x - 1.0,
+                                width: 2.0,
+                                ..bar_bounds
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        theme.drop_indicator(&self.style),
+                    );
+                }
+            });
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().nth(1).unwrap(),
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tabs<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: StyleSheet + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tabs: Tabs<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(tabs)
+    }
+}
+
+/// Returns the bounds of each tab in the bar, after scrolling.
+fn tab_bounds(
+    bar: Layout<'_>,
+    scroll: f32,
+) -> impl Iterator<Item = Rectangle> + '_ {
+    bar.children()
+        .map(move |tab| tab.bounds() - Vector::new(scroll, 0.0))
+}
+
+fn max_scroll(bar: Layout<'_>) -> f32 {
+    let bounds = bar.bounds();
+
+    let width = bar
+        .children()
+        .last()
+        .map(|tab| tab.bounds().x + tab.bounds().width - bounds.x)
+        .unwrap_or(0.0);
+
+    (width - bounds.width).max(0.0)
+}
+
+/// Returns the index the dragged tab would have if dropped at `x`.
+fn drop_target(bar: Layout<'_>, scroll: f32, dragged: usize, x: f32) -> usize {
+    tab_bounds(bar, scroll)
+        .enumerate()
+        .filter(|(i, tab)| *i != dragged && tab.center_x() < x)
+        .count()
+}
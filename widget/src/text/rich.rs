@@ -0,0 +1,451 @@
+use crate::core::alignment;
+use crate::core::event;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Paragraph, Shaping, Span};
+use crate::core::touch;
+use crate::core::widget::text::StyleSheet;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+
+/// A paragraph of rich text, made of [`Span`]s.
+///
+/// Clicking a [`Span`] with a link will produce its link as a message.
+#[allow(missing_debug_implementations)]
+pub struct Rich<'a, Link, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    spans: Vec<Span<'a, Link, Renderer::Font>>,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    width: Length,
+    height: Length,
+    font: Option<Renderer::Font>,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    shaping: Shaping,
+    style: Theme::Style,
+}
+
+impl<'a, Link, Theme, Renderer> Rich<'a, Link, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Rich`] text with the given [`Span`]s.
+    pub fn new(spans: impl Into<Vec<Span<'a, Link, Renderer::Font>>>) -> Self {
+        Self {
+            spans: spans.into(),
+            size: None,
+            line_height: LineHeight::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            font: None,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Advanced,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Span`] to the [`Rich`] text.
+    pub fn push(
+        mut self,
+        span: impl Into<Span<'a, Link, Renderer::Font>>,
+    ) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Sets the default size of the [`Rich`] text.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Rich`] text.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the default font of the [`Rich`] text.
+    ///
+    /// [`Span`]s without a font will use it.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Rich`] text.
+    pub fn style(mut self, style: impl Into<Theme::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the width of the [`Rich`] text boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Rich`] text boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`alignment::Horizontal`] of the [`Rich`] text.
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: alignment::Horizontal,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`alignment::Vertical`] of the [`Rich`] text.
+    pub fn vertical_alignment(
+        mut self,
+        alignment: alignment::Vertical,
+    ) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`Shaping`] strategy of the [`Rich`] text.
+    pub fn shaping(mut self, shaping: Shaping) -> Self {
+        self.shaping = shaping;
+        self
+    }
+}
+
+struct State<Link, P: Paragraph> {
+    spans: Vec<Span<'static, Link, P::Font>>,
+    span_pressed: Option<usize>,
+    paragraph: P,
+}
+
+impl<'a, Link, Theme, Renderer> Widget<Link, Theme, Renderer>
+    for Rich<'a, Link, Theme, Renderer>
+where
+    Link: Clone + PartialEq + 'static,
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Link, Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Link, Renderer::Paragraph> {
+            spans: Vec::new(),
+            span_pressed: None,
+            paragraph: Renderer::Paragraph::default(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree
+            .state
+            .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+        layout::sized(limits, self.width, self.height, |limits| {
+            let size = self.size.unwrap_or_else(|| renderer.default_size());
+            let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+            let text = text::Text {
+                content: "",
+                bounds: limits.max(),
+                size,
+                line_height: self.line_height,
+                font,
+                horizontal_alignment: self.horizontal_alignment,
+                vertical_alignment: self.vertical_alignment,
+                shaping: self.shaping,
+                wrapping: text::Wrapping::default(),
+                letter_spacing: Pixels(0.0),
+                ellipsis: text::Ellipsis::None,
+                max_lines: None,
+            };
+
+            if state.spans != self.spans {
+                state.paragraph =
+                    Renderer::Paragraph::with_spans(text, &self.spans);
+
+                state.spans =
+                    self.spans.iter().cloned().map(Span::to_static).collect();
+            } else {
+                match state.paragraph.compare(text) {
+                    text::Difference::None => {}
+                    text::Difference::Bounds => {
+                        state.paragraph.resize(text.bounds);
+                    }
+                    text::Difference::Shape => {
+                        state.paragraph =
+                            Renderer::Paragraph::with_spans(text, &self.spans);
+                    }
+                }
+            }
+
+            state.paragraph.min_bounds()
+        })
+    }
+
+    fn operate(
+        &self,
+        _state: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Link>,
+    ) {
+        let text: String =
+            self.spans.iter().map(|span| span.text.as_ref()).collect();
+
+        operation.text(None, layout.bounds(), &text);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Link>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree
+            .state
+            .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(span) = self.hovered_link(state, layout, cursor) {
+                    state.span_pressed = Some(span);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let Some(span_pressed) = state.span_pressed.take() else {
+                    return event::Status::Ignored;
+                };
+
+                if self.hovered_link(state, layout, cursor)
+                    == Some(span_pressed)
+                {
+                    if let Some(link) = self
+                        .spans
+                        .get(span_pressed)
+                        .and_then(|span| span.link.clone())
+                    {
+                        shell.publish(link);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.span_pressed = None;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree
+            .state
+            .downcast_ref::<State<Link, Renderer::Paragraph>>();
+
+        if self.hovered_link(state, layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree
+            .state
+            .downcast_ref::<State<Link, Renderer::Paragraph>>();
+
+        let color = theme
+            .appearance(self.style.clone())
+            .color
+            .unwrap_or(style.text_color);
+
+        let origin = origin(&state.paragraph, layout.bounds());
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        for (index, span) in self.spans.iter().enumerate() {
+            if !span.underline && !span.strikethrough {
+                continue;
+            }
+
+            let span_color = span.color.unwrap_or(color);
+            let size = span.size.unwrap_or(size);
+
+            for (bounds, baseline) in state
+                .paragraph
+                .span_bounds(index)
+                .into_iter()
+                .zip(state.paragraph.span_baselines(index))
+            {
+                let bounds = bounds + Vector::new(origin.x, origin.y);
+                let baseline = origin.y + baseline;
+
+                let mut draw_line = |y: f32| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x,
+                                y,
+                                width: bounds.width,
+                                height: 1.0,
+                            },
+                            ..Default::default()
+                        },
+                        span_color,
+                    );
+                };
+
+                if span.underline {
+                    draw_line(baseline + size.0 * 0.1);
+                }
+
+                if span.strikethrough {
+                    draw_line(baseline - size.0 * 0.3);
+                }
+            }
+        }
+
+        renderer.fill_paragraph(
+            &state.paragraph,
+            anchor(&state.paragraph, layout.bounds()),
+            color,
+            *viewport,
+        );
+    }
+}
+
+impl<'a, Link, Theme, Renderer> Rich<'a, Link, Theme, Renderer>
+where
+    Theme: StyleSheet,
+    Renderer: text::Renderer,
+{
+    /// Returns the index of the [`Span`] with a link under the cursor, if any.
+    fn hovered_link(
+        &self,
+        state: &State<Link, Renderer::Paragraph>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        let bounds = layout.bounds();
+        let position = cursor.position_over(bounds)?;
+        let origin = origin(&state.paragraph, bounds);
+
+        let span = state.paragraph.hit_span(Point::new(
+            position.x - origin.x,
+            position.y - origin.y,
+        ))?;
+
+        self.spans
+            .get(span)
+            .and_then(|span| span.link.as_ref())
+            .map(|_| span)
+    }
+}
+
+/// Returns the position where the [`Paragraph`] is anchored when drawn in
+/// the given bounds.
+fn anchor(paragraph: &impl Paragraph, bounds: Rectangle) -> Point {
+    let x = match paragraph.horizontal_alignment() {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => bounds.center_x(),
+        alignment::Horizontal::Right => bounds.x + bounds.width,
+    };
+
+    let y = match paragraph.vertical_alignment() {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => bounds.center_y(),
+        alignment::Vertical::Bottom => bounds.y + bounds.height,
+    };
+
+    Point::new(x, y)
+}
+
+/// Returns the top left corner of the [`Paragraph`] when drawn in the given
+/// bounds.
+fn origin(paragraph: &impl Paragraph, bounds: Rectangle) -> Point {
+    let anchor = anchor(paragraph, bounds);
+    let min_bounds = paragraph.min_bounds();
+
+    let x = match paragraph.horizontal_alignment() {
+        alignment::Horizontal::Left => anchor.x,
+        alignment::Horizontal::Center => anchor.x - min_bounds.width / 2.0,
+        alignment::Horizontal::Right => anchor.x - min_bounds.width,
+    };
+
+    let y = match paragraph.vertical_alignment() {
+        alignment::Vertical::Top => anchor.y,
+        alignment::Vertical::Center => anchor.y - min_bounds.height / 2.0,
+        alignment::Vertical::Bottom => anchor.y - min_bounds.height,
+    };
+
+    Point::new(x, y)
+}
+
+impl<'a, Link, Theme, Renderer> From<Rich<'a, Link, Theme, Renderer>>
+    for Element<'a, Link, Theme, Renderer>
+where
+    Link: Clone + PartialEq + 'static,
+    Theme: StyleSheet + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        text: Rich<'a, Link, Theme, Renderer>,
+    ) -> Element<'a, Link, Theme, Renderer> {
+        Element::new(text)
+    }
+}
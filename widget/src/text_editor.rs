@@ -1,5 +1,11 @@
 //! Display a multi-line text input for text editing.
+mod history;
+
+use history::History;
+
+use crate::core::alignment;
 use crate::core::event::{self, Event};
+use crate::core::input_method::{self, InputMethod};
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout::{self, Layout};
@@ -7,11 +13,14 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text::editor::{Cursor, Editor as _};
 use crate::core::text::highlighter::{self, Highlighter};
-use crate::core::text::{self, LineHeight};
+use crate::core::text::{self, LineHeight, Paragraph as _, Text, Wrapping};
 use crate::core::widget::{self, Widget};
+use crate::core::window;
 use crate::core::{
-    Clipboard, Element, Length, Padding, Pixels, Rectangle, Shell, Size, Vector,
+    Clipboard, Element, Length, Padding, Pixels, Point, Rectangle, Shell, Size,
+    Vector,
 };
+use crate::text_input;
 
 use std::cell::RefCell;
 use std::fmt;
@@ -38,6 +47,7 @@ pub struct TextEditor<
     font: Option<Renderer::Font>,
     text_size: Option<Pixels>,
     line_height: LineHeight,
+    wrapping: Wrapping,
     width: Length,
     height: Length,
     padding: Padding,
@@ -63,6 +73,7 @@ where
             font: None,
             text_size: None,
             line_height: LineHeight::default(),
+            wrapping: Wrapping::default(),
             width: Length::Fill,
             height: Length::Fill,
             padding: Padding::new(5.0),
@@ -103,6 +114,12 @@ where
         self
     }
 
+    /// Sets the [`Wrapping`] strategy of the [`TextEditor`].
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
     /// Sets the [`Padding`] of the [`TextEditor`].
     pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
         self.padding = padding.into();
@@ -124,6 +141,7 @@ where
             font: self.font,
             text_size: self.text_size,
             line_height: self.line_height,
+            wrapping: self.wrapping,
             width: self.width,
             height: self.height,
             padding: self.padding,
@@ -151,6 +169,7 @@ where
     R: text::Renderer,
 {
     editor: R::Editor,
+    history: History,
     is_dirty: bool,
 }
 
@@ -167,18 +186,74 @@ where
     pub fn with_text(text: &str) -> Self {
         Self(RefCell::new(Internal {
             editor: R::Editor::with_text(text),
+            history: History::new(),
             is_dirty: true,
         }))
     }
 
     /// Performs an [`Action`] on the [`Content`].
+    ///
+    /// Edits are recorded in the history of the [`Content`], grouping
+    /// consecutive typing and deletions together, so they can be reverted
+    /// with [`Action::Undo`] and reapplied with [`Action::Redo`].
     pub fn perform(&mut self, action: Action) {
         let internal = self.0.get_mut();
+        let editor = &mut internal.editor;
+
+        match action {
+            Action::Edit(edit) => {
+                let pending = history::Pending::new(editor);
+
+                editor.perform(Action::Edit(edit.clone()));
+
+                if let Some(change) = pending.finish(editor, &edit) {
+                    internal.history.record(change);
+                }
+            }
+            Action::Undo => {
+                if let Some(change) = internal.history.undo() {
+                    editor.select_range(change.start(), change.inserted_end());
+                    editor.perform(Action::Edit(Edit::Paste(Arc::new(
+                        change.removed().to_owned(),
+                    ))));
+
+                    let (cursor, anchor) = change.selection();
+                    editor.select_range(anchor.unwrap_or(cursor), cursor);
+                }
+            }
+            Action::Redo => {
+                if let Some(change) = internal.history.redo() {
+                    editor.select_range(change.start(), change.removed_end());
+                    editor.perform(Action::Edit(Edit::Paste(Arc::new(
+                        change.inserted().to_owned(),
+                    ))));
+                }
+            }
+            _ => {
+                internal.history.seal();
+                editor.perform(action);
+            }
+        }
 
-        internal.editor.perform(action);
         internal.is_dirty = true;
     }
 
+    /// Returns whether the [`Content`] has any edits that can be undone.
+    pub fn can_undo(&self) -> bool {
+        self.0.borrow().history.can_undo()
+    }
+
+    /// Returns whether the [`Content`] has any undone edits that can be
+    /// redone.
+    pub fn can_redo(&self) -> bool {
+        self.0.borrow().history.can_redo()
+    }
+
+    /// Clears the undo and redo history of the [`Content`].
+    pub fn clear_history(&mut self) {
+        self.0.get_mut().history.clear();
+    }
+
     /// Returns the amount of lines of the [`Content`].
     pub fn line_count(&self) -> usize {
         self.0.borrow().editor.line_count()
@@ -280,6 +355,7 @@ where
 
         f.debug_struct("Content")
             .field("editor", &internal.editor)
+            .field("history", &internal.history)
             .field("is_dirty", &internal.is_dirty)
             .finish()
     }
@@ -287,6 +363,7 @@ where
 
 struct State<Highlighter: text::Highlighter> {
     is_focused: bool,
+    preedit: Option<input_method::Preedit>,
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
     highlighter: RefCell<Highlighter>,
@@ -308,6 +385,7 @@ where
     fn state(&self) -> widget::tree::State {
         widget::tree::State::new(State {
             is_focused: false,
+            preedit: None,
             last_click: None,
             drag_click: None,
             highlighter: RefCell::new(Highlighter::new(
@@ -355,6 +433,7 @@ where
             self.font.unwrap_or_else(|| renderer.default_font()),
             self.text_size.unwrap_or_else(|| renderer.default_size()),
             self.line_height,
+            self.wrapping,
             state.highlighter.borrow_mut().deref_mut(),
         );
 
@@ -367,7 +446,7 @@ where
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
@@ -378,6 +457,33 @@ where
 
         let state = tree.state.downcast_mut::<State<Highlighter>>();
 
+        if let Event::Window(_, window::Event::RedrawRequested(_)) = event {
+            if state.is_focused {
+                if let Some(position) = head(&self.content.0.borrow().editor) {
+                    let bounds = layout.bounds();
+
+                    shell.request_input_method(InputMethod::Enabled {
+                        cursor: Rectangle {
+                            x: bounds.x + self.padding.left + position.x,
+                            y: bounds.y + self.padding.top + position.y,
+                            width: 1.0,
+                            height: self
+                                .line_height
+                                .to_absolute(
+                                    self.text_size.unwrap_or_else(|| {
+                                        renderer.default_size()
+                                    }),
+                                )
+                                .into(),
+                        },
+                        purpose: input_method::Purpose::Normal,
+                    });
+                }
+            }
+
+            return event::Status::Ignored;
+        }
+
         let Some(update) = Update::from_event(
             event,
             state,
@@ -407,6 +513,10 @@ where
             Update::Unfocus => {
                 state.is_focused = false;
                 state.drag_click = None;
+
+                if state.preedit.take().is_some() {
+                    self.content.0.borrow_mut().editor.set_preedit(None);
+                }
             }
             Update::Release => {
                 state.drag_click = None;
@@ -426,6 +536,34 @@ where
                     ))));
                 }
             }
+            Update::InputMethod(event) => match event {
+                input_method::Event::Opened => {}
+                input_method::Event::Preedit(content, selection) => {
+                    state.preedit =
+                        input_method::Preedit::new(content, selection);
+
+                    self.content.0.borrow_mut().editor.set_preedit(
+                        state
+                            .preedit
+                            .as_ref()
+                            .map(|preedit| preedit.content.as_str()),
+                    );
+                }
+                input_method::Event::Commit(content) => {
+                    state.preedit = None;
+                    self.content.0.borrow_mut().editor.set_preedit(None);
+
+                    if !content.is_empty() {
+                        shell.publish(on_edit(Action::Edit(Edit::Paste(
+                            Arc::new(content),
+                        ))));
+                    }
+                }
+                input_method::Event::Closed => {
+                    state.preedit = None;
+                    self.content.0.borrow_mut().editor.set_preedit(None);
+                }
+            },
         }
 
         event::Status::Captured
@@ -488,25 +626,46 @@ where
         );
 
         if state.is_focused {
-            match internal.editor.cursor() {
-                Cursor::Caret(position) => {
+            if let Some(preedit) = &state.preedit {
+                // The preedit is laid out by the editor itself, so only
+                // its underlines are left to draw
+                if let Some(position) = head(&internal.editor) {
                     let position = position + translation;
 
-                    if bounds.contains(position) {
+                    let text_size = self
+                        .text_size
+                        .unwrap_or_else(|| renderer.default_size());
+                    let line_height = self.line_height.to_absolute(text_size).0;
+
+                    let paragraph = Renderer::Paragraph::with_text(Text {
+                        content: &preedit.content,
+                        bounds: Size::new(f32::INFINITY, line_height),
+                        size: text_size,
+                        line_height: self.line_height,
+                        font: self
+                            .font
+                            .unwrap_or_else(|| renderer.default_font()),
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: text::Shaping::Advanced,
+                        wrapping: text::Wrapping::default(),
+                        letter_spacing: Pixels(0.0),
+                        ellipsis: text::Ellipsis::None,
+                        max_lines: None,
+                    });
+
+                    for (x, width, thickness) in text_input::preedit_underlines(
+                        &paragraph,
+                        preedit,
+                        paragraph.min_width(),
+                    ) {
                         renderer.fill_quad(
                             renderer::Quad {
                                 bounds: Rectangle {
-                                    x: position.x,
-                                    y: position.y,
-                                    width: 1.0,
-                                    height: self
-                                        .line_height
-                                        .to_absolute(
-                                            self.text_size.unwrap_or_else(
-                                                || renderer.default_size(),
-                                            ),
-                                        )
-                                        .into(),
+                                    x: position.x + x,
+                                    y: position.y + line_height - thickness,
+                                    width,
+                                    height: thickness,
                                 },
                                 ..renderer::Quad::default()
                             },
@@ -514,17 +673,45 @@ where
                         );
                     }
                 }
-                Cursor::Selection(ranges) => {
-                    for range in ranges.into_iter().filter_map(|range| {
-                        bounds.intersection(&(range + translation))
-                    }) {
-                        renderer.fill_quad(
-                            renderer::Quad {
-                                bounds: range,
-                                ..renderer::Quad::default()
-                            },
-                            theme.selection_color(&self.style),
-                        );
+            } else {
+                match internal.editor.cursor() {
+                    Cursor::Caret(position) => {
+                        let position = position + translation;
+
+                        if bounds.contains(position) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: Rectangle {
+                                        x: position.x,
+                                        y: position.y,
+                                        width: 1.0,
+                                        height: self
+                                            .line_height
+                                            .to_absolute(
+                                                self.text_size.unwrap_or_else(
+                                                    || renderer.default_size(),
+                                                ),
+                                            )
+                                            .into(),
+                                    },
+                                    ..renderer::Quad::default()
+                                },
+                                theme.value_color(&self.style),
+                            );
+                        }
+                    }
+                    Cursor::Selection(ranges) => {
+                        for range in ranges.into_iter().filter_map(|range| {
+                            bounds.intersection(&(range + translation))
+                        }) {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: range,
+                                    ..renderer::Quad::default()
+                                },
+                                theme.selection_color(&self.style),
+                            );
+                        }
                     }
                 }
             }
@@ -576,6 +763,7 @@ enum Update {
     Action(Action),
     Copy,
     Paste,
+    InputMethod(input_method::Event),
 }
 
 impl Update {
@@ -689,6 +877,20 @@ impl Update {
                         {
                             Some(Self::Paste)
                         }
+                        keyboard::Key::Character("z" | "Z")
+                            if modifiers.command() =>
+                        {
+                            action(if modifiers.shift() {
+                                Action::Redo
+                            } else {
+                                Action::Undo
+                            })
+                        }
+                        keyboard::Key::Character("y" | "Y")
+                            if modifiers.command() =>
+                        {
+                            action(Action::Redo)
+                        }
                         _ => {
                             let text = text?;
 
@@ -700,11 +902,37 @@ impl Update {
                 }
                 _ => None,
             },
+            Event::InputMethod(event) if state.is_focused => {
+                Some(Update::InputMethod(event))
+            }
             _ => None,
         }
     }
 }
 
+/// Returns the position of the head of the cursor of the given [`Editor`],
+/// where text is inserted.
+///
+/// [`Editor`]: text::Editor
+fn head(editor: &impl text::Editor) -> Option<Point> {
+    match editor.cursor() {
+        Cursor::Caret(position) => Some(position),
+        Cursor::Selection(ranges) => {
+            let is_backwards = editor
+                .selection_anchor()
+                .is_some_and(|anchor| anchor > editor.cursor_position());
+
+            if is_backwards {
+                ranges.first().map(Rectangle::position)
+            } else {
+                ranges
+                    .last()
+                    .map(|range| Point::new(range.x + range.width, range.y))
+            }
+        }
+    }
+}
+
 fn motion(key: key::Named) -> Option<Motion> {
     match key {
         key::Named::ArrowLeft => Some(Motion::Left),
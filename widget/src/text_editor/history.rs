@@ -0,0 +1,382 @@
+use crate::core::text::editor::{Edit, Editor};
+
+/// A position in an [`Editor`].
+///
+/// Line and column, respectively.
+pub type Position = (usize, usize);
+
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: Vec<Change>,
+    redo: Vec<Change>,
+    is_sealed: bool,
+}
+
+impl History {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.is_sealed = false;
+    }
+
+    /// Stops the last recorded [`Change`] from absorbing new ones.
+    pub fn seal(&mut self) {
+        self.is_sealed = true;
+    }
+
+    pub fn record(&mut self, change: Change) {
+        self.redo.clear();
+
+        let is_merged = !self.is_sealed
+            && self
+                .undo
+                .last_mut()
+                .map(|last| last.merge(&change))
+                .unwrap_or(false);
+
+        if !is_merged {
+            self.undo.push(change);
+        }
+
+        self.is_sealed = false;
+    }
+
+    pub fn undo(&mut self) -> Option<&Change> {
+        let change = self.undo.pop()?;
+
+        self.redo.push(change);
+        self.is_sealed = true;
+
+        self.redo.last()
+    }
+
+    pub fn redo(&mut self) -> Option<&Change> {
+        let change = self.redo.pop()?;
+
+        self.undo.push(change);
+        self.is_sealed = true;
+
+        self.undo.last()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    kind: Kind,
+    start: Position,
+    removed: String,
+    inserted: String,
+    cursor: Position,
+    anchor: Option<Position>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Typing,
+    Backspace,
+    Delete,
+    Other,
+}
+
+impl Change {
+    /// Returns where the [`Change`] starts.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the text removed by the [`Change`].
+    pub fn removed(&self) -> &str {
+        &self.removed
+    }
+
+    /// Returns the text inserted by the [`Change`].
+    pub fn inserted(&self) -> &str {
+        &self.inserted
+    }
+
+    /// Returns the cursor and selection anchor before the [`Change`].
+    pub fn selection(&self) -> (Position, Option<Position>) {
+        (self.cursor, self.anchor)
+    }
+
+    /// Returns where the removed text ended before the [`Change`].
+    pub fn removed_end(&self) -> Position {
+        end_of(self.start, &self.removed)
+    }
+
+    /// Returns where the inserted text ends after the [`Change`].
+    pub fn inserted_end(&self) -> Position {
+        end_of(self.start, &self.inserted)
+    }
+
+    fn merge(&mut self, next: &Change) -> bool {
+        if self.kind != next.kind {
+            return false;
+        }
+
+        match next.kind {
+            Kind::Typing => {
+                let is_word_boundary =
+                    next.inserted.starts_with(char::is_whitespace)
+                        && !self.inserted.ends_with(char::is_whitespace);
+
+                if next.start != self.inserted_end() || is_word_boundary {
+                    return false;
+                }
+
+                self.inserted.push_str(&next.inserted);
+            }
+            Kind::Backspace => {
+                if next.removed_end() != self.start {
+                    return false;
+                }
+
+                self.start = next.start;
+                self.removed.insert_str(0, &next.removed);
+            }
+            Kind::Delete => {
+                if next.start != self.start {
+                    return false;
+                }
+
+                self.removed.push_str(&next.removed);
+            }
+            Kind::Other => return false,
+        }
+
+        true
+    }
+}
+
+/// The state of an [`Editor`] right before an [`Edit`] is performed.
+#[derive(Debug)]
+pub struct Pending {
+    cursor: Position,
+    anchor: Option<Position>,
+    selection: Option<String>,
+    line: String,
+    line_count: usize,
+}
+
+impl Pending {
+    pub fn new(editor: &impl Editor) -> Self {
+        let cursor = editor.cursor_position();
+
+        Self {
+            cursor,
+            anchor: editor.selection_anchor(),
+            selection: editor.selection(),
+            line: editor.line(cursor.0).unwrap_or_default().to_owned(),
+            line_count: editor.line_count(),
+        }
+    }
+
+    /// Computes the [`Change`] produced by the given [`Edit`], which must
+    /// have been performed after the [`Pending`] change was created.
+    ///
+    /// Returns `None` if the [`Edit`] did not change anything.
+    pub fn finish(self, editor: &impl Editor, edit: &Edit) -> Option<Change> {
+        let cursor = editor.cursor_position();
+        let has_joined_lines = editor.line_count() < self.line_count;
+
+        let (kind, start, removed, inserted) =
+            match (self.anchor, self.selection) {
+                (Some(anchor), Some(selection)) => {
+                    let start = self.cursor.min(anchor);
+
+                    (
+                        Kind::Other,
+                        start,
+                        selection,
+                        text_between(editor, start, cursor),
+                    )
+                }
+                _ => match edit {
+                    Edit::Backspace => {
+                        let removed = if has_joined_lines {
+                            String::from("\n")
+                        } else {
+                            self.line
+                                .get(cursor.1..self.cursor.1)
+                                .unwrap_or_default()
+                                .to_owned()
+                        };
+
+                        (Kind::Backspace, cursor, removed, String::new())
+                    }
+                    Edit::Delete => {
+                        let removed = if has_joined_lines {
+                            String::from("\n")
+                        } else {
+                            let length = editor
+                                .line(cursor.0)
+                                .map(str::len)
+                                .unwrap_or_default();
+
+                            let end = cursor.1
+                                + self.line.len().saturating_sub(length);
+
+                            self.line
+                                .get(cursor.1..end)
+                                .unwrap_or_default()
+                                .to_owned()
+                        };
+
+                        (Kind::Delete, cursor, removed, String::new())
+                    }
+                    Edit::Insert(c) if *c != '\n' => (
+                        Kind::Typing,
+                        self.cursor,
+                        String::new(),
+                        text_between(editor, self.cursor, cursor),
+                    ),
+                    Edit::Insert(_) | Edit::Paste(_) | Edit::Enter => (
+                        Kind::Other,
+                        self.cursor,
+                        String::new(),
+                        text_between(editor, self.cursor, cursor),
+                    ),
+                },
+            };
+
+        if removed.is_empty() && inserted.is_empty() {
+            return None;
+        }
+
+        Some(Change {
+            kind,
+            start,
+            removed,
+            inserted,
+            cursor: self.cursor,
+            anchor: self.anchor,
+        })
+    }
+}
+
+fn text_between(editor: &impl Editor, from: Position, to: Position) -> String {
+    if to <= from {
+        return String::new();
+    }
+
+    let mut text = String::new();
+
+    for line in from.0..=to.0 {
+        let Some(content) = editor.line(line) else {
+            break;
+        };
+
+        let start = if line == from.0 { from.1 } else { 0 };
+        let end = if line == to.0 { to.1 } else { content.len() };
+
+        text.push_str(content.get(start..end).unwrap_or_default());
+
+        if line != to.0 {
+            text.push('\n');
+        }
+    }
+
+    text
+}
+
+fn end_of(start: Position, text: &str) -> Position {
+    match text.rsplit_once('\n') {
+        Some((before, last)) => {
+            (start.0 + before.matches('\n').count() + 1, last.len())
+        }
+        None => (start.0, start.1 + text.len()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typing(start: Position, text: &str) -> Change {
+        Change {
+            kind: Kind::Typing,
+            start,
+            removed: String::new(),
+            inserted: text.to_owned(),
+            cursor: start,
+            anchor: None,
+        }
+    }
+
+    fn backspace(start: Position, text: &str) -> Change {
+        Change {
+            kind: Kind::Backspace,
+            start,
+            removed: text.to_owned(),
+            inserted: String::new(),
+            cursor: end_of(start, text),
+            anchor: None,
+        }
+    }
+
+    #[test]
+    fn typing_is_grouped_by_words() {
+        let mut history = History::new();
+
+        for (i, c) in "hello world".chars().enumerate() {
+            history.record(typing((0, i), &c.to_string()));
+        }
+
+        assert_eq!(history.undo().map(Change::inserted), Some(" world"));
+        assert_eq!(history.undo().map(Change::inserted), Some("hello"));
+        assert!(!history.can_undo());
+        assert!(history.can_redo());
+    }
+
+    #[test]
+    fn sealing_breaks_groups() {
+        let mut history = History::new();
+
+        history.record(typing((0, 0), "a"));
+        history.seal();
+        history.record(typing((0, 1), "b"));
+
+        assert_eq!(history.undo().map(Change::inserted), Some("b"));
+        assert_eq!(history.undo().map(Change::inserted), Some("a"));
+    }
+
+    #[test]
+    fn backspaces_are_grouped() {
+        let mut history = History::new();
+
+        history.record(backspace((1, 2), "c"));
+        history.record(backspace((1, 1), "b"));
+        history.record(backspace((1, 0), "a"));
+        history.record(backspace((0, 3), "\n"));
+
+        let change = history.undo().expect("Undo backspaces");
+
+        assert_eq!(change.start(), (0, 3));
+        assert_eq!(change.removed(), "\nabc");
+        assert_eq!(change.removed_end(), (1, 3));
+    }
+
+    #[test]
+    fn recording_clears_redo() {
+        let mut history = History::new();
+
+        history.record(typing((0, 0), "a"));
+        let _ = history.undo();
+        assert!(history.can_redo());
+
+        history.record(typing((0, 0), "b"));
+        assert!(!history.can_redo());
+    }
+}
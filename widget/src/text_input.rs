@@ -13,6 +13,7 @@ use editor::Editor;
 
 use crate::core::alignment;
 use crate::core::event::{self, Event};
+use crate::core::input_method::{self, InputMethod};
 use crate::core::keyboard;
 use crate::core::keyboard::key;
 use crate::core::layout;
@@ -22,6 +23,7 @@ use crate::core::text::{self, Paragraph as _, Text};
 use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::accessible::{self, Accessible};
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
@@ -282,6 +284,7 @@ where
             state.is_focused = None;
             state.is_pasting = None;
             state.is_dragging = false;
+            state.preedit = None;
         }
     }
 
@@ -317,14 +320,33 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let id = self.id.as_ref().map(|id| &id.0);
+
+        let value = if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        }
+        .to_string();
+
+        operation.accessible(
+            id,
+            layout.bounds(),
+            Accessible::new(accessible::Role::TextInput)
+                .label(&self.placeholder)
+                .value(accessible::Value::Text(&value))
+                .focused(state.is_focused())
+                .disabled(self.on_input.is_none()),
+        );
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
-        operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation.focusable(state, id);
+        operation.text_input(state, id);
+        operation.text(id, layout.bounds(), &value);
     }
 
     fn on_event(
@@ -522,6 +544,10 @@ where
         horizontal_alignment: alignment::Horizontal::Left,
         vertical_alignment: alignment::Vertical::Center,
         shaping: text::Shaping::Advanced,
+        wrapping: text::Wrapping::default(),
+        letter_spacing: Pixels(0.0),
+        ellipsis: text::Ellipsis::None,
+        max_lines: None,
     };
 
     state.placeholder.update(placeholder_text);
@@ -544,6 +570,10 @@ where
             horizontal_alignment: alignment::Horizontal::Center,
             vertical_alignment: alignment::Vertical::Center,
             shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::default(),
+            letter_spacing: Pixels(0.0),
+            ellipsis: text::Ellipsis::None,
+            max_lines: None,
         };
 
         state.icon.update(icon_text);
@@ -648,6 +678,10 @@ where
                 None
             };
 
+            if state.is_focused.is_none() {
+                state.preedit = None;
+            }
+
             if let Some(cursor_position) = click_position {
                 let text_layout = layout.children().next().unwrap();
                 let target = cursor_position.x - text_layout.bounds().x;
@@ -928,6 +962,7 @@ where
                         state.is_focused = None;
                         state.is_dragging = false;
                         state.is_pasting = None;
+                        state.preedit = None;
 
                         state.keyboard_modifiers =
                             keyboard::Modifiers::default();
@@ -995,6 +1030,76 @@ where
 
             state.keyboard_modifiers = modifiers;
         }
+        Event::InputMethod(event) => {
+            let state = state();
+
+            let Some(focus) = &mut state.is_focused else {
+                return event::Status::Ignored;
+            };
+
+            let Some(on_input) = on_input else {
+                return event::Status::Ignored;
+            };
+
+            match event {
+                input_method::Event::Opened => {}
+                input_method::Event::Preedit(content, selection) => {
+                    let font = font.unwrap_or_else(|| renderer.default_font());
+                    let size = size.unwrap_or_else(|| renderer.default_size());
+                    let text_bounds =
+                        layout.children().next().unwrap().bounds();
+
+                    state.preedit = input_method::Preedit::new(
+                        content, selection,
+                    )
+                    .map(|raw| Preedit {
+                        paragraph: Renderer::Paragraph::with_text(Text {
+                            content: &raw.content,
+                            bounds: Size::new(
+                                f32::INFINITY,
+                                text_bounds.height,
+                            ),
+                            size,
+                            line_height,
+                            font,
+                            horizontal_alignment: alignment::Horizontal::Left,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: text::Shaping::Advanced,
+                            wrapping: text::Wrapping::default(),
+                            letter_spacing: Pixels(0.0),
+                            ellipsis: text::Ellipsis::None,
+                            max_lines: None,
+                        }),
+                        raw,
+                    });
+                }
+                input_method::Event::Commit(content) => {
+                    state.preedit = None;
+
+                    let content: String =
+                        content.chars().filter(|c| !c.is_control()).collect();
+
+                    if content.is_empty() {
+                        return event::Status::Captured;
+                    }
+
+                    let mut editor = Editor::new(value, &mut state.cursor);
+                    editor.paste(Value::new(&content));
+
+                    let message = (on_input)(editor.contents());
+                    shell.publish(message);
+
+                    focus.updated_at = Instant::now();
+
+                    update_cache(state, value);
+                }
+                input_method::Event::Closed => {
+                    state.preedit = None;
+                }
+            }
+
+            return event::Status::Captured;
+        }
         Event::Window(_, window::Event::Unfocused) => {
             let state = state();
 
@@ -1017,6 +1122,36 @@ where
 
             if let Some(focus) = &mut state.is_focused {
                 if focus.is_window_focused {
+                    if on_input.is_some() {
+                        let text_bounds =
+                            layout.children().next().unwrap().bounds();
+
+                        let caret = match state.cursor.state(value) {
+                            cursor::State::Index(position) => position,
+                            cursor::State::Selection { end, .. } => end,
+                        };
+
+                        let (x, offset) = measure_cursor_and_scroll_offset(
+                            &state.value,
+                            text_bounds,
+                            caret,
+                        );
+
+                        shell.request_input_method(InputMethod::Enabled {
+                            cursor: Rectangle {
+                                x: text_bounds.x + x - offset,
+                                y: text_bounds.y,
+                                width: 1.0,
+                                height: text_bounds.height,
+                            },
+                            purpose: if is_secure {
+                                input_method::Purpose::Secure
+                            } else {
+                                input_method::Purpose::Normal
+                            },
+                        });
+                    }
+
                     focus.now = now;
 
                     let millis_until_redraw = CURSOR_BLINK_INTERVAL_MILLIS
@@ -1111,11 +1246,11 @@ pub fn draw<Theme, Renderer>(
                         position,
                     );
 
-                let is_cursor_visible = ((focus.now - focus.updated_at)
-                    .as_millis()
-                    / CURSOR_BLINK_INTERVAL_MILLIS)
-                    % 2
-                    == 0;
+                let is_cursor_visible = state.preedit.is_none()
+                    && ((focus.now - focus.updated_at).as_millis()
+                        / CURSOR_BLINK_INTERVAL_MILLIS)
+                        % 2
+                        == 0;
 
                 let cursor = if is_cursor_visible {
                     Some((
@@ -1181,6 +1316,26 @@ pub fn draw<Theme, Renderer>(
         (None, 0.0)
     };
 
+    let preedit =
+        state
+            .preedit
+            .as_ref()
+            .filter(|_| state.is_focused())
+            .map(|preedit| {
+                let caret = match state.cursor.state(value) {
+                    cursor::State::Index(position) => position,
+                    cursor::State::Selection { end, .. } => end,
+                };
+
+                let (x, _) = measure_cursor_and_scroll_offset(
+                    &state.value,
+                    text_bounds,
+                    caret,
+                );
+
+                (preedit, text_bounds.x + x)
+            });
+
     let draw = |renderer: &mut Renderer, viewport| {
         if let Some((cursor, color)) = cursor {
             renderer.with_translation(Vector::new(-offset, 0.0), |renderer| {
@@ -1207,6 +1362,48 @@ pub fn draw<Theme, Renderer>(
             },
             viewport,
         );
+
+        if let Some((preedit, x)) = preedit {
+            let position = Point::new(x - offset, text_bounds.y);
+            let width = preedit.paragraph.min_width();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: position.x,
+                        y: position.y,
+                        width,
+                        height: text_bounds.height,
+                    },
+                    ..renderer::Quad::default()
+                },
+                appearance.background,
+            );
+
+            renderer.fill_paragraph(
+                &preedit.paragraph,
+                Point::new(position.x, text_bounds.center_y()),
+                theme.value_color(style),
+                viewport,
+            );
+
+            for (x, width, thickness) in
+                preedit_underlines(&preedit.paragraph, &preedit.raw, width)
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: position.x + x,
+                            y: position.y + text_bounds.height - thickness,
+                            width,
+                            height: thickness,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    theme.value_color(style),
+                );
+            }
+        }
     };
 
     if cursor.is_some() {
@@ -1239,6 +1436,7 @@ pub struct State<P: text::Paragraph> {
     value: P,
     placeholder: P,
     icon: P,
+    preedit: Option<Preedit<P>>,
     is_focused: Option<Focus>,
     is_dragging: bool,
     is_pasting: Option<Value>,
@@ -1248,6 +1446,12 @@ pub struct State<P: text::Paragraph> {
     // TODO: Add stateful horizontal scrolling offset
 }
 
+#[derive(Debug, Clone)]
+struct Preedit<P> {
+    raw: input_method::Preedit,
+    paragraph: P,
+}
+
 #[derive(Debug, Clone, Copy)]
 struct Focus {
     updated_at: Instant,
@@ -1267,6 +1471,7 @@ impl<P: text::Paragraph> State<P> {
             value: P::default(),
             placeholder: P::default(),
             icon: P::default(),
+            preedit: None,
             is_focused: None,
             is_dragging: false,
             is_pasting: None,
@@ -1302,6 +1507,7 @@ impl<P: text::Paragraph> State<P> {
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = None;
+        self.preedit = None;
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -1408,6 +1614,47 @@ fn measure_cursor_and_scroll_offset(
     (grapheme_position.x, offset)
 }
 
+/// Computes the underlines of a [`input_method::Preedit`] drawn with the given
+/// [`text::Paragraph`], as a list of horizontal offsets, widths and
+/// thicknesses.
+///
+/// The whole preedit is underlined, while its selection—if any—is underlined
+/// with a thicker line.
+pub(crate) fn preedit_underlines(
+    paragraph: &impl text::Paragraph,
+    preedit: &input_method::Preedit,
+    width: f32,
+) -> Vec<(f32, f32, f32)> {
+    let mut underlines = vec![(0.0, width, 1.0)];
+
+    if let Some(selection) = preedit
+        .selection
+        .as_ref()
+        .filter(|selection| !selection.is_empty())
+    {
+        let position = |index: usize| {
+            let graphemes =
+                unicode_segmentation::UnicodeSegmentation::graphemes(
+                    preedit.content.get(..index).unwrap_or(&preedit.content),
+                    true,
+                )
+                .count();
+
+            paragraph
+                .grapheme_position(0, graphemes)
+                .map(|position| position.x)
+                .unwrap_or(width)
+        };
+
+        let start = position(selection.start);
+        let end = position(selection.end);
+
+        underlines.push((start, end - start, 2.0));
+    }
+
+    underlines
+}
+
 /// Computes the position of the text cursor at the given X coordinate of
 /// a [`TextInput`].
 fn find_cursor_position<P: text::Paragraph>(
@@ -1459,6 +1706,10 @@ fn replace_paragraph<Renderer>(
         horizontal_alignment: alignment::Horizontal::Left,
         vertical_alignment: alignment::Vertical::Top,
         shaping: text::Shaping::Advanced,
+        wrapping: text::Wrapping::default(),
+        letter_spacing: Pixels(0.0),
+        ellipsis: text::Ellipsis::None,
+        max_lines: None,
     });
 }
 
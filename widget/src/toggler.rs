@@ -1,13 +1,18 @@
 //! Show toggle controls using togglers.
 use crate::core::alignment;
+use crate::core::animation::{Animation, Interpolable};
 use crate::core::event;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget;
+use crate::core::widget::operation::accessible::{self, Accessible};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::window;
 use crate::core::{
     Border, Clipboard, Element, Event, Layout, Length, Pixels, Rectangle,
     Shell, Size, Widget,
@@ -162,11 +167,20 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph>::new(self.is_toggled))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let target = if self.is_toggled { 1.0 } else { 0.0 };
+
+        if *state.toggle.target() != target {
+            state.toggle.go(target, Instant::now());
+        }
     }
 
     fn size(&self) -> Size<Length> {
@@ -190,12 +204,11 @@ where
             |_| layout::Node::new(Size::new(2.0 * self.size, self.size)),
             |limits| {
                 if let Some(label) = self.label.as_deref() {
-                    let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                    let state =
+                        tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                     widget::text::layout(
-                        state,
+                        &mut state.label,
                         renderer,
                         limits,
                         self.width,
@@ -207,6 +220,10 @@ where
                         self.text_alignment,
                         alignment::Vertical::Top,
                         self.text_shaping,
+                        text::Wrapping::default(),
+                        Pixels(0.0),
+                        text::Ellipsis::None,
+                        None,
                     )
                 } else {
                     layout::Node::new(Size::ZERO)
@@ -215,9 +232,31 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let accessible = Accessible::new(accessible::Role::Switch)
+            .value(accessible::Value::Checked(self.is_toggled));
+
+        operation.accessible(
+            None,
+            layout.bounds(),
+            match self.label.as_deref() {
+                Some(label) => accessible.label(label),
+                None => accessible,
+            },
+        );
+
+        operation.activatable(None, &|| (self.on_toggle)(!self.is_toggled));
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -226,11 +265,12 @@ where
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> event::Status {
-        match event {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let mouse_over = cursor.is_over(layout.bounds());
+
+        let status = match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                let mouse_over = cursor.is_over(layout.bounds());
-
                 if mouse_over {
                     shell.publish((self.on_toggle)(!self.is_toggled));
 
@@ -239,8 +279,27 @@ where
                     event::Status::Ignored
                 }
             }
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                state.now = now;
+
+                if state.is_animating(now) {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                return event::Status::Ignored;
+            }
             _ => event::Status::Ignored,
+        };
+
+        let target = if mouse_over { 1.0 } else { 0.0 };
+
+        if *state.hover.target() != target {
+            state.hover.go(target, Instant::now());
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
         }
+
+        status
     }
 
     fn mouse_interaction(
@@ -265,7 +324,7 @@ where
         theme: &Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor: mouse::Cursor,
+        _cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         /// Makes sure that the border radius of the toggler looks good at every size.
@@ -275,6 +334,8 @@ where
         /// between the background Quad and foreground Quad.
         const SPACE_RATIO: f32 = 0.05;
 
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
         let mut children = layout.children();
         let toggler_layout = children.next().unwrap();
 
@@ -285,21 +346,25 @@ where
                 renderer,
                 style,
                 label_layout,
-                tree.state.downcast_ref(),
+                &state.label,
                 crate::text::Appearance::default(),
                 viewport,
             );
         }
 
         let bounds = toggler_layout.bounds();
-        let is_mouse_over = cursor.is_over(layout.bounds());
 
-        let style = if is_mouse_over {
-            theme.hovered(&self.style, self.is_toggled)
-        } else {
-            theme.active(&self.style, self.is_toggled)
+        let hover = state.hover.value(state.now);
+        let toggle = state.toggle.value(state.now);
+
+        let appearance = |is_active| {
+            theme
+                .active(&self.style, is_active)
+                .interpolate(&theme.hovered(&self.style, is_active), hover)
         };
 
+        let style = appearance(false).interpolate(&appearance(true), toggle);
+
         let border_radius = bounds.height / BORDER_RADIUS_RATIO;
         let space = SPACE_RATIO * bounds.height;
 
@@ -325,11 +390,12 @@ where
 
         let toggler_foreground_bounds = Rectangle {
             x: bounds.x
-                + if self.is_toggled {
-                    bounds.width - 2.0 * space - (bounds.height - (4.0 * space))
-                } else {
-                    2.0 * space
-                },
+                + (2.0 * space).interpolate(
+                    &(bounds.width
+                        - 2.0 * space
+                        - (bounds.height - (4.0 * space))),
+                    toggle,
+                ),
             y: bounds.y + (2.0 * space),
             width: bounds.height - (4.0 * space),
             height: bounds.height - (4.0 * space),
@@ -363,3 +429,27 @@ where
         Element::new(toggler)
     }
 }
+
+/// The local state of a [`Toggler`].
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    hover: Animation<f32>,
+    toggle: Animation<f32>,
+    now: Instant,
+}
+
+impl<P: text::Paragraph> State<P> {
+    fn new(is_toggled: bool) -> Self {
+        Self {
+            label: widget::text::State::default(),
+            hover: Animation::new(0.0),
+            toggle: Animation::new(if is_toggled { 1.0 } else { 0.0 }),
+            now: Instant::now(),
+        }
+    }
+
+    fn is_animating(&self, now: Instant) -> bool {
+        self.hover.is_animating(now) || self.toggle.is_animating(now)
+    }
+}
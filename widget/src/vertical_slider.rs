@@ -11,7 +11,9 @@ use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
+use crate::core::widget::operation::accessible::{self, Accessible};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
     Border, Clipboard, Element, Length, Pixels, Point, Rectangle, Shell, Size,
     Widget,
@@ -167,6 +169,26 @@ where
         layout::atomic(limits, self.width, self.height)
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.accessible(
+            None,
+            layout.bounds(),
+            Accessible::new(accessible::Role::Slider).value(
+                accessible::Value::Numeric {
+                    value: self.value.into(),
+                    min: (*self.range.start()).into(),
+                    max: (*self.range.end()).into(),
+                },
+            ),
+        );
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
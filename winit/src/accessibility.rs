@@ -0,0 +1,606 @@
+//! Expose the widget tree to assistive technology through [AccessKit].
+//!
+//! [AccessKit]: https://accesskit.dev
+mod adapter;
+
+pub use accesskit;
+
+use crate::core::widget::operation::accessible::{self, Accessible};
+use crate::core::widget::operation::{
+    activatable, focusable, Focusable, Outcome,
+};
+use crate::core::widget::{self, Operation};
+use crate::core::Rectangle;
+use crate::runtime::UserInterface;
+
+use accesskit::{
+    Action, ActionHandler, ActionRequest, Affine, Checked, DefaultActionVerb,
+    NodeBuilder, NodeClassSet, NodeId, Rect, Role, Tree, TreeUpdate,
+};
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// The [`NodeId`] of the root node of a window.
+pub const ROOT: NodeId = NodeId(0);
+
+/// The accessibility tree of a window, kept in sync with assistive
+/// technology.
+pub struct Accessibility {
+    adapter: adapter::Adapter,
+    requests: mpsc::Receiver<ActionRequest>,
+}
+
+impl Accessibility {
+    /// Creates a new [`Accessibility`] tree for the given window.
+    ///
+    /// This must be called before the window is shown for the first time.
+    pub fn new(window: &Arc<winit::window::Window>, title: &str) -> Self {
+        let (sender, requests) = mpsc::channel();
+
+        let initial = Builder::new().build(title, 1.0);
+
+        let adapter = adapter::Adapter::new(
+            window,
+            move || initial,
+            Box::new(Handler {
+                sender,
+                window: window.clone(),
+            }),
+        );
+
+        Self { adapter, requests }
+    }
+
+    /// Lets assistive technology react to the given window event.
+    pub fn process_event(
+        &self,
+        window: &winit::window::Window,
+        event: &winit::event::WindowEvent,
+    ) {
+        self.adapter.process_event(window, event);
+    }
+
+    /// Rebuilds the accessibility tree from the given [`UserInterface`], if
+    /// any assistive technology is listening.
+    pub fn update<Message, Theme, Renderer>(
+        &mut self,
+        user_interface: &mut UserInterface<'_, Message, Theme, Renderer>,
+        renderer: &Renderer,
+        title: &str,
+        scale_factor: f64,
+    ) where
+        Renderer: crate::core::Renderer,
+    {
+        self.adapter.update_if_active(|| {
+            let mut builder = Builder::new();
+
+            user_interface.operate(renderer, &mut builder);
+
+            builder.build(title, scale_factor)
+        });
+    }
+
+    /// Returns the operations that perform the actions requested by assistive
+    /// technology since the last call.
+    ///
+    /// The [`Outcome`] of an [`Operation`] that activates a widget is the
+    /// message the widget publishes.
+    pub fn operations<T>(&mut self) -> Vec<Box<dyn Operation<T>>>
+    where
+        T: 'static,
+    {
+        self.requests
+            .try_iter()
+            .filter_map(|request| {
+                let operation: Box<dyn Operation<T>> = match request.action {
+                    Action::Focus => Box::new(Target::new(
+                        request.target,
+                        focusable::focus(target_id()),
+                    )),
+                    Action::Default => Box::new(Target::new(
+                        request.target,
+                        activatable::activate(target_id()),
+                    )),
+                    _ => return None,
+                };
+
+                Some(operation)
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Debug for Accessibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Accessibility").finish_non_exhaustive()
+    }
+}
+
+struct Handler {
+    sender: mpsc::Sender<ActionRequest>,
+    window: Arc<winit::window::Window>,
+}
+
+impl ActionHandler for Handler {
+    fn do_action(&mut self, request: ActionRequest) {
+        if self.sender.send(request).is_ok() {
+            self.window.request_redraw();
+        }
+    }
+}
+
+/// An [`Operation`] that builds the accessibility tree of a user interface.
+#[derive(Debug, Default)]
+pub struct Builder {
+    cursor: Cursor,
+    nodes: Vec<Node>,
+    indices: HashMap<NodeId, usize>,
+    children: Vec<NodeId>,
+    focus: Option<NodeId>,
+}
+
+#[derive(Debug)]
+struct Node {
+    id: NodeId,
+    builder: NodeBuilder,
+    /// The label computed from the text of the children, if needed.
+    label: Option<String>,
+    children: Vec<NodeId>,
+}
+
+impl Builder {
+    /// Creates a new empty [`Builder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Finishes the [`Builder`] and returns the resulting [`TreeUpdate`].
+    pub fn build(self, title: &str, scale_factor: f64) -> TreeUpdate {
+        let mut classes = NodeClassSet::lock_global();
+
+        let mut root = NodeBuilder::new(Role::Window);
+        root.set_name(title);
+        root.set_transform(Affine::scale(scale_factor));
+        root.set_children(self.children);
+
+        let mut nodes = vec![(ROOT, root.build(&mut classes))];
+
+        for node in self.nodes {
+            let mut builder = node.builder;
+
+            if let Some(label) = node.label.filter(|label| !label.is_empty()) {
+                builder.set_name(label);
+            }
+
+            builder.set_children(node.children);
+
+            nodes.push((node.id, builder.build(&mut classes)));
+        }
+
+        TreeUpdate {
+            nodes,
+            tree: Some(Tree::new(ROOT)),
+            focus: self.focus.unwrap_or(ROOT),
+        }
+    }
+
+    fn push(
+        &mut self,
+        id: NodeId,
+        builder: NodeBuilder,
+        label: Option<String>,
+    ) {
+        match self.indices.get(&self.cursor.parent()) {
+            Some(parent) => self.nodes[*parent].children.push(id),
+            None => self.children.push(id),
+        }
+
+        let _ = self.indices.insert(id, self.nodes.len());
+
+        self.nodes.push(Node {
+            id,
+            builder,
+            label,
+            children: Vec::new(),
+        });
+    }
+
+    fn current(&mut self) -> Option<&mut NodeBuilder> {
+        let index = self.indices.get(&self.cursor.current?)?;
+
+        Some(&mut self.nodes[*index].builder)
+    }
+}
+
+impl<T> Operation<T> for Builder {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.cursor.enter(bounds) {
+            operate_on_children(self);
+            self.cursor.leave();
+        } else {
+            operate_on_children(self);
+        }
+    }
+
+    fn focusable(
+        &mut self,
+        _state: &mut dyn Focusable,
+        _id: Option<&widget::Id>,
+    ) {
+        if let Some(node) = self.current() {
+            node.add_action(Action::Focus);
+        }
+    }
+
+    fn activatable(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _activate: &dyn Fn() -> T,
+    ) {
+        if let Some(node) = self.current() {
+            node.add_action(Action::Default);
+            node.set_default_action_verb(DefaultActionVerb::Click);
+        }
+    }
+
+    fn text(&mut self, id: Option<&widget::Id>, bounds: Rectangle, text: &str) {
+        let Some(node) = self.cursor.text(id, bounds, text) else {
+            return;
+        };
+
+        if let Some(parent) = self.indices.get(&self.cursor.parent()) {
+            if let Some(label) = &mut self.nodes[*parent].label {
+                if !label.is_empty() {
+                    label.push(' ');
+                }
+
+                label.push_str(text);
+            }
+        }
+
+        let mut builder = NodeBuilder::new(Role::StaticText);
+        builder.set_name(text);
+        builder.set_bounds(rect(bounds));
+
+        self.push(node, builder, None);
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        accessible: Accessible<'_>,
+    ) {
+        let mut builder = NodeBuilder::new(role(accessible.role));
+        builder.set_bounds(rect(bounds));
+
+        match accessible.value {
+            Some(accessible::Value::Text(text)) => {
+                builder.set_value(text);
+            }
+            Some(accessible::Value::Checked(is_checked)) => {
+                builder.set_checked(if is_checked {
+                    Checked::True
+                } else {
+                    Checked::False
+                });
+            }
+            Some(accessible::Value::Numeric { value, min, max }) => {
+                builder.set_numeric_value(value);
+                builder.set_min_numeric_value(min);
+                builder.set_max_numeric_value(max);
+            }
+            None => {}
+        }
+
+        if accessible.is_disabled {
+            builder.set_disabled();
+        }
+
+        // Widgets without a label are named after the text they contain
+        let label = match accessible.label {
+            Some(label) => {
+                builder.set_name(label);
+
+                None
+            }
+            None => Some(String::new()),
+        };
+
+        let node = self.cursor.accessible(id, bounds);
+
+        self.push(node, builder, label);
+
+        if accessible.is_focused {
+            self.focus = Some(node);
+        }
+    }
+}
+
+/// An [`Operation`] that applies another [`Operation`] only to the widget
+/// of the node with the given [`NodeId`].
+///
+/// The widget is presented to the inner [`Operation`] with the [`Id`] returned
+/// by [`target_id`], while any other widget is presented without one.
+///
+/// [`Id`]: widget::Id
+struct Target<O> {
+    node: NodeId,
+    id: widget::Id,
+    cursor: Cursor,
+    operation: O,
+}
+
+impl<O> Target<O> {
+    fn new(node: NodeId, operation: O) -> Self {
+        Self {
+            node,
+            id: target_id(),
+            cursor: Cursor::default(),
+            operation,
+        }
+    }
+}
+
+fn target_id() -> widget::Id {
+    widget::Id::new("iced_winit::accessibility::target")
+}
+
+impl<T, O> Operation<T> for Target<O>
+where
+    O: Operation<T>,
+{
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.cursor.enter(bounds) {
+            operate_on_children(self);
+            self.cursor.leave();
+        } else {
+            operate_on_children(self);
+        }
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn Focusable,
+        _id: Option<&widget::Id>,
+    ) {
+        let id = (self.cursor.current == Some(self.node)).then_some(&self.id);
+
+        self.operation.focusable(state, id);
+    }
+
+    fn activatable(
+        &mut self,
+        _id: Option<&widget::Id>,
+        activate: &dyn Fn() -> T,
+    ) {
+        let id = (self.cursor.current == Some(self.node)).then_some(&self.id);
+
+        self.operation.activatable(id, activate);
+    }
+
+    fn text(&mut self, id: Option<&widget::Id>, bounds: Rectangle, text: &str) {
+        let _ = self.cursor.text(id, bounds, text);
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        _accessible: Accessible<'_>,
+    ) {
+        let _ = self.cursor.accessible(id, bounds);
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        self.operation.finish()
+    }
+}
+
+/// The position of an [`Operation`] in the accessibility tree.
+///
+/// The [`NodeId`] of a widget is derived from its [`widget::Id`], if it has
+/// one, or from its position in the tree otherwise; so it stays the same
+/// between rebuilds.
+#[derive(Debug)]
+struct Cursor {
+    /// The ancestors of the current position, with their amount of children.
+    parents: Vec<(NodeId, usize)>,
+    /// The last accessible widget, which may operate on its contents next.
+    pending: Option<(NodeId, Rectangle)>,
+    /// The accessible widget being operated on, if any.
+    current: Option<NodeId>,
+}
+
+impl Default for Cursor {
+    fn default() -> Self {
+        Self {
+            parents: vec![(ROOT, 0)],
+            pending: None,
+            current: None,
+        }
+    }
+}
+
+impl Cursor {
+    fn parent(&self) -> NodeId {
+        self.parents.last().map_or(ROOT, |(parent, _)| *parent)
+    }
+
+    fn accessible(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+    ) -> NodeId {
+        let node = self.child(id);
+
+        self.pending = Some((node, bounds));
+        self.current = Some(node);
+
+        node
+    }
+
+    fn text(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        text: &str,
+    ) -> Option<NodeId> {
+        // Accessible widgets may also expose the text they display
+        if text.is_empty()
+            || matches!(self.pending, Some((_, pending)) if pending == bounds)
+        {
+            return None;
+        }
+
+        self.current = None;
+
+        Some(self.child(id))
+    }
+
+    /// Enters a container, returning whether it is the contents of the last
+    /// accessible widget.
+    fn enter(&mut self, bounds: Rectangle) -> bool {
+        self.current = None;
+
+        match self.pending.take() {
+            Some((node, pending)) if pending == bounds => {
+                self.parents.push((node, 0));
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn leave(&mut self) {
+        let _ = self.parents.pop();
+    }
+
+    fn child(&mut self, id: Option<&widget::Id>) -> NodeId {
+        let (parent, children) =
+            self.parents.last_mut().expect("The root is never left");
+
+        let mut hasher = DefaultHasher::new();
+
+        match id {
+            Some(id) => id.hash(&mut hasher),
+            None => (parent.0, *children).hash(&mut hasher),
+        }
+
+        *children += 1;
+
+        NodeId(hasher.finish())
+    }
+}
+
+fn rect(bounds: Rectangle) -> Rect {
+    Rect {
+        x0: f64::from(bounds.x),
+        y0: f64::from(bounds.y),
+        x1: f64::from(bounds.x + bounds.width),
+        y1: f64::from(bounds.y + bounds.height),
+    }
+}
+
+fn role(role: accessible::Role) -> Role {
+    match role {
+        accessible::Role::Button => Role::Button,
+        accessible::Role::CheckBox => Role::CheckBox,
+        accessible::Role::Switch => Role::Switch,
+        accessible::Role::RadioButton => Role::RadioButton,
+        accessible::Role::TextInput => Role::TextInput,
+        accessible::Role::Slider => Role::Slider,
+        accessible::Role::ProgressBar => Role::ProgressIndicator,
+        accessible::Role::ComboBox => Role::ComboBox,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Point, Size};
+
+    fn counter(operation: &mut dyn Operation<&'static str>) {
+        let button = Rectangle::new(Point::ORIGIN, Size::new(100.0, 40.0));
+        let checkbox = Rectangle::new(Point::new(0.0, 50.0), button.size());
+
+        operation.accessible(
+            None,
+            button,
+            Accessible::new(accessible::Role::Button),
+        );
+        operation.activatable(None, &|| "increment");
+        operation.container(None, button, &mut |operation| {
+            operation.text(None, button, "Increment");
+        });
+        operation.accessible(
+            None,
+            checkbox,
+            Accessible::new(accessible::Role::CheckBox)
+                .label("Dark mode")
+                .value(accessible::Value::Checked(true))
+                .focused(true),
+        );
+        operation.activatable(None, &|| "toggle");
+    }
+
+    #[test]
+    fn widgets_are_named_after_their_contents() {
+        let mut builder = Builder::new();
+        counter(&mut builder);
+
+        let update = builder.build("Counter", 2.0);
+        let id = |index: usize| update.nodes[index].0;
+        let node = |index: usize| &update.nodes[index].1;
+
+        assert_eq!(update.focus, id(3));
+        assert_eq!(node(0).children(), [id(1), id(3)]);
+
+        assert_eq!(node(1).role(), Role::Button);
+        assert_eq!(node(1).name(), Some("Increment"));
+        assert_eq!(node(1).children(), [id(2)]);
+        assert!(node(1).supports_action(Action::Default));
+
+        assert_eq!(node(3).role(), Role::CheckBox);
+        assert_eq!(node(3).name(), Some("Dark mode"));
+        assert_eq!(node(3).checked(), Some(Checked::True));
+    }
+
+    #[test]
+    fn actions_are_performed_on_their_target() {
+        let mut builder = Builder::new();
+        counter(&mut builder);
+
+        let update = builder.build("Counter", 1.0);
+
+        let mut rebuilt = Builder::new();
+        counter(&mut rebuilt);
+
+        let ids = |update: &TreeUpdate| -> Vec<NodeId> {
+            update.nodes.iter().map(|(id, _)| *id).collect()
+        };
+
+        assert_eq!(ids(&update), ids(&rebuilt.build("Counter", 1.0)));
+
+        let mut target =
+            Target::new(update.nodes[3].0, activatable::activate(target_id()));
+        counter(&mut target);
+
+        assert!(matches!(target.finish(), Outcome::Some("toggle")));
+    }
+}
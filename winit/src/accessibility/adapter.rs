@@ -0,0 +1,183 @@
+//! Connect an accessibility tree to the platform adapters of AccessKit.
+use accesskit::{ActionHandler, TreeUpdate};
+use winit::event::WindowEvent;
+use winit::window::Window;
+
+pub use platform::Adapter;
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::*;
+
+    use accesskit_windows::{SubclassingAdapter, HWND};
+    use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+    pub struct Adapter(SubclassingAdapter);
+
+    impl Adapter {
+        pub fn new(
+            window: &Window,
+            source: impl FnOnce() -> TreeUpdate + 'static,
+            handler: Box<dyn ActionHandler + Send>,
+        ) -> Self {
+            let hwnd =
+                match window.window_handle().map(|handle| handle.as_raw()) {
+                    Ok(RawWindowHandle::Win32(handle)) => handle.hwnd.get(),
+                    _ => unreachable!("Windows only provides Win32 handles"),
+                };
+
+            Self(SubclassingAdapter::new(HWND(hwnd), source, handler))
+        }
+
+        pub fn update_if_active(&self, update: impl FnOnce() -> TreeUpdate) {
+            if let Some(events) = self.0.update_if_active(update) {
+                events.raise();
+            }
+        }
+
+        pub fn process_event(&self, _window: &Window, _event: &WindowEvent) {}
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::*;
+
+    use accesskit_macos::SubclassingAdapter;
+    use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+
+    pub struct Adapter(SubclassingAdapter);
+
+    impl Adapter {
+        pub fn new(
+            window: &Window,
+            source: impl FnOnce() -> TreeUpdate + 'static,
+            handler: Box<dyn ActionHandler + Send>,
+        ) -> Self {
+            let view = match window
+                .window_handle()
+                .map(|handle| handle.as_raw())
+            {
+                Ok(RawWindowHandle::AppKit(handle)) => handle.ns_view.as_ptr(),
+                _ => unreachable!("macOS only provides AppKit handles"),
+            };
+
+            // SAFETY: The view is owned by the window, which outlives the
+            // adapter.
+            Self(unsafe { SubclassingAdapter::new(view, source, handler) })
+        }
+
+        pub fn update_if_active(&self, update: impl FnOnce() -> TreeUpdate) {
+            if let Some(events) = self.0.update_if_active(update) {
+                events.raise();
+            }
+        }
+
+        pub fn process_event(&self, _window: &Window, event: &WindowEvent) {
+            if let WindowEvent::Focused(is_focused) = event {
+                if let Some(events) =
+                    self.0.update_view_focus_state(*is_focused)
+                {
+                    events.raise();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+mod platform {
+    use super::*;
+
+    use accesskit::Rect;
+
+    /// The AT-SPI bus may be unavailable, in which case nothing is exposed.
+    pub struct Adapter(Option<accesskit_unix::Adapter>);
+
+    impl Adapter {
+        pub fn new(
+            _window: &Window,
+            source: impl FnOnce() -> TreeUpdate + 'static,
+            handler: Box<dyn ActionHandler + Send>,
+        ) -> Self {
+            Self(accesskit_unix::Adapter::new(source, false, handler))
+        }
+
+        pub fn update_if_active(&self, update: impl FnOnce() -> TreeUpdate) {
+            if let Some(adapter) = &self.0 {
+                adapter.update(update());
+            }
+        }
+
+        pub fn process_event(&self, window: &Window, event: &WindowEvent) {
+            let Some(adapter) = &self.0 else {
+                return;
+            };
+
+            match event {
+                WindowEvent::Moved(_) | WindowEvent::Resized(_) => {
+                    let outer_position = window
+                        .outer_position()
+                        .unwrap_or_default()
+                        .cast::<f64>();
+                    let outer_size = window.outer_size().cast::<f64>();
+                    let inner_position = window
+                        .inner_position()
+                        .unwrap_or_default()
+                        .cast::<f64>();
+                    let inner_size = window.inner_size().cast::<f64>();
+
+                    adapter.set_root_window_bounds(
+                        Rect::from_origin_size(
+                            (outer_position.x, outer_position.y),
+                            (outer_size.width, outer_size.height),
+                        ),
+                        Rect::from_origin_size(
+                            (inner_position.x, inner_position.y),
+                            (inner_size.width, inner_size.height),
+                        ),
+                    );
+                }
+                WindowEvent::Focused(is_focused) => {
+                    adapter.update_window_focus_state(*is_focused);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+)))]
+mod platform {
+    use super::*;
+
+    pub struct Adapter;
+
+    impl Adapter {
+        pub fn new(
+            _window: &Window,
+            _source: impl FnOnce() -> TreeUpdate + 'static,
+            _handler: Box<dyn ActionHandler + Send>,
+        ) -> Self {
+            Self
+        }
+
+        pub fn update_if_active(&self, _update: impl FnOnce() -> TreeUpdate) {}
+
+        pub fn process_event(&self, _window: &Window, _event: &WindowEvent) {}
+    }
+}
@@ -190,7 +190,9 @@ where
     for font in settings.fonts {
         use crate::core::text::Renderer;
 
-        renderer.load_font(font);
+        if let Err(error) = renderer.load_font(font) {
+            log::warn!("Failed to load font: {error}");
+        }
     }
 
     let (mut event_sender, event_receiver) = mpsc::unbounded();
@@ -275,6 +277,10 @@ async fn run_instance<A, E, C>(
     );
     let mut should_exit = false;
 
+    #[cfg(feature = "accessibility")]
+    let mut accessibility =
+        crate::accessibility::Accessibility::new(&window, &application.title());
+
     if should_be_visible {
         window.set_visible(true);
     }
@@ -383,9 +389,16 @@ async fn run_instance<A, E, C>(
                     &mut messages,
                 );
 
+                if let user_interface::State::Updated { input_method, .. } =
+                    interface_state
+                {
+                    state.request_input_method(&window, input_method);
+                }
+
                 let _ = control_sender.start_send(match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
+                        ..
                     } => match redraw_request {
                         window::RedrawRequest::NextFrame => {
                             window.request_redraw();
@@ -413,6 +426,14 @@ async fn run_instance<A, E, C>(
                 redraw_pending = false;
                 debug.draw_finished();
 
+                #[cfg(feature = "accessibility")]
+                accessibility.update(
+                    &mut user_interface,
+                    &renderer,
+                    &application.title(),
+                    state.scale_factor(),
+                );
+
                 if new_mouse_interaction != mouse_interaction {
                     window.set_cursor_icon(conversion::mouse_interaction(
                         new_mouse_interaction,
@@ -459,6 +480,9 @@ async fn run_instance<A, E, C>(
                     break;
                 }
 
+                #[cfg(feature = "accessibility")]
+                accessibility.process_event(&window, &window_event);
+
                 state.update(&window, &window_event, &mut debug);
 
                 if let Some(event) = conversion::window_event(
@@ -471,6 +495,17 @@ async fn run_instance<A, E, C>(
                 }
             }
             event::Event::AboutToWait => {
+                #[cfg(feature = "accessibility")]
+                for mut operation in accessibility.operations() {
+                    user_interface.operate(&renderer, operation.as_mut());
+
+                    if let operation::Outcome::Some(message) =
+                        operation.finish()
+                    {
+                        messages.push(message);
+                    }
+                }
+
                 if events.is_empty() && messages.is_empty() {
                     continue;
                 }
@@ -854,11 +889,20 @@ pub fn run_command<A, C, E>(
             command::Action::LoadFont { bytes, tagger } => {
                 use crate::core::text::Renderer;
 
-                // TODO: Error handling (?)
-                renderer.load_font(bytes);
+                let result = renderer.load_font(bytes);
+
+                proxy
+                    .send_event(tagger(result))
+                    .expect("Send message to event loop");
+            }
+            command::Action::ListFonts { tagger } => {
+                let faces = crate::graphics::text::font_system()
+                    .read()
+                    .expect("Read font system")
+                    .faces();
 
                 proxy
-                    .send_event(tagger(Ok(())))
+                    .send_event(tagger(faces))
                     .expect("Send message to event loop");
             }
         }
@@ -2,6 +2,7 @@
 //!
 //! [`winit`]: https://github.com/rust-windowing/winit
 //! [`iced_runtime`]: https://github.com/iced-rs/iced/tree/0.10/runtime
+use crate::core::input_method;
 use crate::core::keyboard;
 use crate::core::mouse;
 use crate::core::touch;
@@ -240,6 +241,9 @@ pub fn window_event(
                 }
             }
         })),
+        WindowEvent::Ime(ime) => {
+            Some(Event::InputMethod(input_method_event(ime)))
+        }
         WindowEvent::ModifiersChanged(new_modifiers) => {
             Some(Event::Keyboard(keyboard::Event::ModifiersChanged(
                 self::modifiers(new_modifiers.state()),
@@ -275,6 +279,33 @@ pub fn window_event(
     }
 }
 
+/// Converts a `winit` IME event into an [`input_method::Event`].
+pub fn input_method_event(ime: winit::event::Ime) -> input_method::Event {
+    match ime {
+        winit::event::Ime::Enabled => input_method::Event::Opened,
+        winit::event::Ime::Preedit(content, selection) => {
+            input_method::Event::Preedit(
+                content,
+                selection.map(|(start, end)| start..end),
+            )
+        }
+        winit::event::Ime::Commit(content) => {
+            input_method::Event::Commit(content)
+        }
+        winit::event::Ime::Disabled => input_method::Event::Closed,
+    }
+}
+
+/// Converts an [`input_method::Purpose`] into a `winit` IME purpose.
+pub fn ime_purpose(
+    purpose: input_method::Purpose,
+) -> winit::window::ImePurpose {
+    match purpose {
+        input_method::Purpose::Normal => winit::window::ImePurpose::Normal,
+        input_method::Purpose::Secure => winit::window::ImePurpose::Password,
+    }
+}
+
 /// Converts a [`window::Level`] to a [`winit`] window level.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -38,6 +38,9 @@ pub mod multi_window;
 
 #[cfg(feature = "application")]
 pub mod application;
+
+#[cfg(feature = "accessibility")]
+pub mod accessibility;
 pub mod clipboard;
 pub mod conversion;
 pub mod settings;
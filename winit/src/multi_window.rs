@@ -251,18 +251,29 @@ where
                         } => {
                             let exit_on_close_request =
                                 settings.exit_on_close_request;
+                            #[cfg(feature = "accessibility")]
+                            let visible = settings.visible;
 
-                            let window = conversion::window_settings(
+                            let builder = conversion::window_settings(
                                 settings, &title, monitor, None,
-                            )
-                            .build(event_loop)
-                            .expect("Failed to build window");
+                            );
+
+                            // AccessKit must be attached before the window
+                            // is shown
+                            #[cfg(feature = "accessibility")]
+                            let builder = builder.with_visible(false);
+
+                            let window = builder
+                                .build(event_loop)
+                                .expect("Failed to build window");
 
                             event_sender
                                 .start_send(Event::WindowCreated {
                                     id,
                                     window,
                                     exit_on_close_request,
+                                    #[cfg(feature = "accessibility")]
+                                    visible,
                                 })
                                 .expect("Send event");
                         }
@@ -290,6 +301,8 @@ enum Event<Message: 'static> {
         id: window::Id,
         window: winit::window::Window,
         exit_on_close_request: bool,
+        #[cfg(feature = "accessibility")]
+        visible: bool,
     },
     EventLoopAwakened(winit::event::Event<Message>),
 }
@@ -383,6 +396,8 @@ async fn run_instance<A, E, C>(
                 id,
                 window,
                 exit_on_close_request,
+                #[cfg(feature = "accessibility")]
+                visible,
             } => {
                 let window = window_manager.insert(
                     id,
@@ -392,6 +407,11 @@ async fn run_instance<A, E, C>(
                     exit_on_close_request,
                 );
 
+                #[cfg(feature = "accessibility")]
+                if visible {
+                    window.raw.set_visible(true);
+                }
+
                 let logical_size = window.state.logical_size();
 
                 let _ = user_interfaces.insert(
@@ -495,6 +515,14 @@ async fn run_instance<A, E, C>(
                         );
                         debug.draw_finished();
 
+                        #[cfg(feature = "accessibility")]
+                        window.accessibility.update(
+                            ui,
+                            &window.renderer,
+                            &application.title(id),
+                            window.state.scale_factor(),
+                        );
+
                         if new_mouse_interaction != window.mouse_interaction {
                             window.raw.set_cursor_icon(
                                 conversion::mouse_interaction(
@@ -510,10 +538,22 @@ async fn run_instance<A, E, C>(
                             core::event::Status::Ignored,
                         );
 
+                        if let user_interface::State::Updated {
+                            input_method,
+                            ..
+                        } = ui_state
+                        {
+                            window.state.request_input_method(
+                                &window.raw,
+                                input_method,
+                            );
+                        }
+
                         let _ = control_sender.start_send(Control::ChangeFlow(
                             match ui_state {
                                 user_interface::State::Updated {
                                     redraw_request: Some(redraw_request),
+                                    ..
                                 } => match redraw_request {
                                     window::RedrawRequest::NextFrame => {
                                         window.raw.request_redraw();
@@ -652,6 +692,11 @@ async fn run_instance<A, E, C>(
                                 break 'main;
                             }
                         } else {
+                            #[cfg(feature = "accessibility")]
+                            window
+                                .accessibility
+                                .process_event(&window.raw, &window_event);
+
                             window.state.update(
                                 &window.raw,
                                 &window_event,
@@ -669,6 +714,28 @@ async fn run_instance<A, E, C>(
                         }
                     }
                     event::Event::AboutToWait => {
+                        #[cfg(feature = "accessibility")]
+                        for (id, window) in window_manager.iter_mut() {
+                            let Some(ui) = user_interfaces.get_mut(&id) else {
+                                continue;
+                            };
+
+                            for mut operation in
+                                window.accessibility.operations()
+                            {
+                                ui.operate(
+                                    &window.renderer,
+                                    operation.as_mut(),
+                                );
+
+                                if let operation::Outcome::Some(message) =
+                                    operation.finish()
+                                {
+                                    messages.push(message);
+                                }
+                            }
+                        }
+
                         if events.is_empty() && messages.is_empty() {
                             continue;
                         }
@@ -1118,13 +1185,24 @@ fn run_command<A, C, E>(
                 use crate::core::text::Renderer;
 
                 // TODO change this once we change each renderer to having a single backend reference.. :pain:
-                // TODO: Error handling (?)
+                let mut result = Ok(());
+
                 for (_, window) in window_manager.iter_mut() {
-                    window.renderer.load_font(bytes.clone());
+                    result = window.renderer.load_font(bytes.clone());
                 }
 
                 proxy
-                    .send_event(tagger(Ok(())))
+                    .send_event(tagger(result))
+                    .expect("Send message to event loop");
+            }
+            command::Action::ListFonts { tagger } => {
+                let faces = crate::graphics::text::font_system()
+                    .read()
+                    .expect("Read font system")
+                    .faces();
+
+                proxy
+                    .send_event(tagger(faces))
                     .expect("Send message to event loop");
             }
         }
@@ -1,4 +1,5 @@
 use crate::conversion;
+use crate::core::input_method::InputMethod;
 use crate::core::{mouse, window};
 use crate::core::{Color, Size};
 use crate::graphics::Viewport;
@@ -21,6 +22,7 @@ where
     viewport_version: u64,
     cursor_position: Option<winit::dpi::PhysicalPosition<f64>>,
     modifiers: winit::keyboard::ModifiersState,
+    input_method: InputMethod,
     theme: A::Theme,
     appearance: application::Appearance,
 }
@@ -72,6 +74,7 @@ where
             viewport_version: 0,
             cursor_position: None,
             modifiers: winit::keyboard::ModifiersState::default(),
+            input_method: InputMethod::Disabled,
             theme,
             appearance,
         }
@@ -137,6 +140,46 @@ where
         self.appearance.text_color
     }
 
+    /// Requests the given [`InputMethod`] for the window of the [`State`].
+    ///
+    /// The window is only updated when the request changes.
+    pub fn request_input_method(
+        &mut self,
+        window: &Window,
+        input_method: InputMethod,
+    ) {
+        if self.input_method == input_method {
+            return;
+        }
+
+        match input_method {
+            InputMethod::Disabled => {
+                window.set_ime_allowed(false);
+            }
+            InputMethod::Enabled { cursor, purpose } => {
+                if !self.input_method.is_enabled() {
+                    window.set_ime_allowed(true);
+                }
+
+                let scale_factor = self.viewport.scale_factor();
+
+                window.set_ime_purpose(conversion::ime_purpose(purpose));
+                window.set_ime_cursor_area(
+                    winit::dpi::PhysicalPosition::new(
+                        f64::from(cursor.x) * scale_factor,
+                        f64::from(cursor.y) * scale_factor,
+                    ),
+                    winit::dpi::PhysicalSize::new(
+                        f64::from(cursor.width) * scale_factor,
+                        f64::from(cursor.height) * scale_factor,
+                    ),
+                );
+            }
+        }
+
+        self.input_method = input_method;
+    }
+
     /// Processes the provided window event and updates the [`State`] accordingly.
     pub fn update(
         &mut self,
@@ -50,6 +50,12 @@ where
         );
         let renderer = compositor.create_renderer();
 
+        #[cfg(feature = "accessibility")]
+        let accessibility = crate::accessibility::Accessibility::new(
+            &window,
+            &application.title(id),
+        );
+
         let _ = self.aliases.insert(window.id(), id);
 
         let _ = self.entries.insert(
@@ -62,6 +68,8 @@ where
                 surface,
                 renderer,
                 mouse_interaction: mouse::Interaction::Idle,
+                #[cfg(feature = "accessibility")]
+                accessibility,
             },
         );
 
@@ -130,6 +138,8 @@ where
     pub mouse_interaction: mouse::Interaction,
     pub surface: C::Surface,
     pub renderer: A::Renderer,
+    #[cfg(feature = "accessibility")]
+    pub accessibility: crate::accessibility::Accessibility,
 }
 
 impl<A, C> Window<A, C>